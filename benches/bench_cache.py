@@ -0,0 +1,36 @@
+"""Compares the first call of the values an ``AtomicClock`` caches with repeated calls on
+the same clock, which read the cache.  ``clone()`` is measured alone and subtracted, so
+the first calls are on fresh clocks.
+
+Run against a release build: ``make bench``.
+"""
+import timeit
+
+from atomic_clock import AtomicClock
+
+
+NUMBER = 200_000
+GLOBALS = {
+    "CLOCK": AtomicClock(2022, 3, 15, 10, 30, 45, 123456, "Asia/Shanghai"),
+}
+
+CASES = [
+    ("timestamp", "CLOCK.timestamp()", "CLOCK.clone().timestamp()"),
+    ("isocalendar", "CLOCK.isocalendar()", "CLOCK.clone().isocalendar()"),
+]
+
+
+def bench(stmt: str) -> float:
+    """The best time of a call in nanoseconds."""
+    times = timeit.repeat(stmt, globals=GLOBALS, number=NUMBER)
+    return min(times) / NUMBER * 1e9
+
+
+if __name__ == "__main__":
+    clone_ns = bench("CLOCK.clone()")
+    for name, cached, first in CASES:
+        cached_ns, first_ns = bench(cached), bench(first) - clone_ns
+        print(
+            f"{name:<12} cached {cached_ns:7.1f} ns  first {first_ns:7.1f} ns  "
+            f"x{first_ns / cached_ns:.2f}"
+        )
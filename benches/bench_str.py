@@ -0,0 +1,49 @@
+"""Compares ``str()`` and ``isoformat()`` with ``to_rfc3339()``, which is still formatted
+by chrono as they were before.  ``str()`` of a clock is only formatted once, so it's
+measured on the first call as well as on repeated ones.
+
+Run against a release build: ``make bench``.
+"""
+import timeit
+
+from atomic_clock import AtomicClock
+
+
+NUMBER = 200_000
+GLOBALS = {
+    "AtomicClock": AtomicClock,
+    "CLOCK": AtomicClock(2022, 3, 15, 10, 30, 45, 123456, "Asia/Shanghai"),
+}
+
+CASES = [
+    (
+        "str repeated",
+        "str(CLOCK)",
+        "CLOCK.to_rfc3339()",
+    ),
+    (
+        "str first",
+        "str(CLOCK.clone())",
+        "CLOCK.clone().to_rfc3339()",
+    ),
+    (
+        "isoformat",
+        "CLOCK.isoformat()",
+        "CLOCK.to_rfc3339()",
+    ),
+]
+
+
+def bench(stmt: str) -> float:
+    """The best time of a call in nanoseconds."""
+    times = timeit.repeat(stmt, globals=GLOBALS, number=NUMBER)
+    return min(times) / NUMBER * 1e9
+
+
+if __name__ == "__main__":
+    for name, fast, general in CASES:
+        fast_ns, general_ns = bench(fast), bench(general)
+        print(
+            f"{name:<14} new {fast_ns:7.1f} ns  chrono {general_ns:7.1f} ns  "
+            f"x{general_ns / fast_ns:.2f}"
+        )
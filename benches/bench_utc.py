@@ -0,0 +1,38 @@
+"""Compares the UTC fast path of ``now`` with the general path, which a fixed ``+00:00``
+offset still takes.  The zones are built once, so that parsing their names isn't
+measured.
+
+Run against a release build: ``make bench``.
+"""
+import timeit
+
+from atomic_clock import UTC
+from atomic_clock import AtomicClock
+from atomic_clock import Tz
+
+
+NUMBER = 200_000
+GLOBALS = {"AtomicClock": AtomicClock, "UTC": UTC, "OFFSET": Tz("+00:00")}
+
+CASES = [
+    (
+        "now",
+        "AtomicClock.now(UTC)",
+        "AtomicClock.now(OFFSET)",
+    ),
+]
+
+
+def bench(stmt: str) -> float:
+    """The best time of a call in nanoseconds."""
+    times = timeit.repeat(stmt, globals=GLOBALS, number=NUMBER)
+    return min(times) / NUMBER * 1e9
+
+
+if __name__ == "__main__":
+    for name, fast, general in CASES:
+        fast_ns, general_ns = bench(fast), bench(general)
+        print(
+            f"{name:<14} utc {fast_ns:7.1f} ns  +00:00 {general_ns:7.1f} ns  "
+            f"x{general_ns / fast_ns:.2f}"
+        )
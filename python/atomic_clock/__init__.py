@@ -1,14 +1,42 @@
+from datetime import tzinfo
 from enum import IntEnum
+from typing import Union
 
+from .atomic_clock import UTC
 from .atomic_clock import AtomicClock
+from .atomic_clock import AtomicClockError
+from .atomic_clock import ParserError
+from .atomic_clock import Period
+from .atomic_clock import RangeError
 from .atomic_clock import RelativeDelta
+from .atomic_clock import Stopwatch
+from .atomic_clock import TimezoneError
 from .atomic_clock import Tz
 from .atomic_clock import __version__
+from .atomic_clock import custom_timezones
 from .atomic_clock import get
+from .atomic_clock import get_safe
+from .atomic_clock import locales
+from .atomic_clock import monotonic
 from .atomic_clock import now
+from .atomic_clock import parse_interval
+from .atomic_clock import register_timezone
+from .atomic_clock import try_get
+from .atomic_clock import tzdata_version
+from .atomic_clock import unregister_timezone
 from .atomic_clock import utcnow
 
 
+TzLike = Union[str, tzinfo, Tz]
+
+
+def __getattr__(name: str) -> Tz:
+    # the local offset is looked up on access, so it follows DST and `time.tzset`
+    if name == "LOCAL":
+        return Tz("local")
+    raise AttributeError(f"module {__name__!r} has no attribute {name!r}")
+
+
 class Weekday(IntEnum):
     Mon = 0
     Tue = 1
@@ -20,12 +48,30 @@ class Weekday(IntEnum):
 
 
 __all__ = [
+    "LOCAL",
+    "UTC",
     "AtomicClock",
+    "AtomicClockError",
+    "ParserError",
+    "Period",
+    "RangeError",
     "RelativeDelta",
+    "Stopwatch",
+    "TimezoneError",
     "Tz",
+    "TzLike",
     "Weekday",
+    "custom_timezones",
     "get",
+    "get_safe",
+    "locales",
+    "monotonic",
     "now",
+    "parse_interval",
+    "register_timezone",
+    "try_get",
+    "tzdata_version",
+    "unregister_timezone",
     "utcnow",
     "__version__",
 ]
@@ -5,14 +5,33 @@ import datetime as dt
 from enum import IntEnum
 from time import struct_time
 from typing import Any
-from typing import Generator
-from typing import Iterable
+from typing import ClassVar
+from typing import Iterator
+from typing import List
 from typing import Literal
 from typing import Optional
 from typing import Tuple
 from typing import Union
 from typing import overload
 
+from typing_extensions import TypeAlias
+
+__version__: str
+
+class AtomicClockError(Exception):
+    """Base class of the errors raised by atomic_clock."""
+
+class ParserError(AtomicClockError, ValueError):
+    """Raised when a string or other input can't be parsed into a datetime, e.g. by
+    :func:`get` or :meth:`AtomicClock.strptime <atomic_clock.AtomicClock.strptime>`."""
+
+class TimezoneError(AtomicClockError, ValueError):
+    """Raised when a :ref:`timezone expression <tz-expr>` is unknown or invalid."""
+
+class RangeError(AtomicClockError, OverflowError, ValueError):
+    """Raised when a datetime, ordinal or timestamp is outside of the supported range.  It's
+    also a ``ValueError``, which out of range inputs raised before."""
+
 class Weekday(IntEnum):
     Mon = 0
     Tue = 1
@@ -22,6 +41,52 @@ class Weekday(IntEnum):
     Sat = 5
     Sun = 6
 
+Frame: TypeAlias = Literal[
+    "year",
+    "years",
+    "yr",
+    "yrs",
+    "quarter",
+    "quarters",
+    "qtr",
+    "qtrs",
+    "month",
+    "months",
+    "mon",
+    "mons",
+    "week",
+    "weeks",
+    "wk",
+    "wks",
+    "isoweek",
+    "isoweeks",
+    "day",
+    "days",
+    "hour",
+    "hours",
+    "hr",
+    "hrs",
+    "minute",
+    "minutes",
+    "min",
+    "mins",
+    "second",
+    "seconds",
+    "sec",
+    "secs",
+    "microsecond",
+    "microseconds",
+    "us",
+    "usec",
+    "usecs",
+]
+"""A timeframe, or one of its :ref:`aliases <frame-alias>`."""
+
+Bounds: TypeAlias = Literal[
+    "[]", "()", "[)", "(]", "both", "neither", "left", "start", "right", "end"
+]
+"""Whether the start and end of a range are included, see :ref:`bounds <bounds>`."""
+
 class AtomicClock:
     """An :class:`AtomicClock <atomic_clock.AtomicClock>` object.
 
@@ -36,6 +101,10 @@ class AtomicClock:
     :param second: (optional) the second, Defaults to 0.
     :param microsecond: (optional) the microsecond. Defaults to 0.
     :param tzinfo: (optional) A timezone expression.  Defaults to UTC.
+    :param fold: (optional) ``0`` or ``1``, picks the earlier or the later of two ambiguous wall
+        times during a DST transition. Defaults to 0.
+    :param nanosecond: (optional) the fraction of the second in nanoseconds, instead of
+        ``microsecond``.  Giving both is an error.
 
     .. _tz-expr:
 
@@ -43,16 +112,46 @@ class AtomicClock:
         - A ``tzinfo`` object (note: very slow).
         - A ``atomic_clock.Tz`` object.
         - A ``str`` describing a timezone, similar to 'US/Pacific', or 'Asia/Shanghai'.
-        - A ``str`` in ISO 8601 style, as in '+07:00'.
+        - A ``str`` in ISO 8601 style, as in '+07', '+0700' or '+07:00', within ±24 hours.
+        - A POSIX TZ ``str`` with DST rules, as in 'EST5EDT,M3.2.0,M11.1.0'. Note that
+          POSIX offsets are positive west of UTC, 'EST5' is '-05:00'.
         - A ``str``, one of the following:  'local', 'utc', 'UTC'.
 
+    .. _frame-alias:
+
+    Timeframe arguments also accept plurals and abbreviations of the frame names:
+        - year: 'years', 'yr', 'yrs'
+        - quarter: 'quarters', 'qtr', 'qtrs'
+        - month: 'months', 'mon', 'mons'
+        - week: 'weeks', 'wk', 'wks'
+        - isoweek: 'isoweeks'
+        - day: 'days'
+        - hour: 'hours', 'hr', 'hrs'
+        - minute: 'minutes', 'min', 'mins'
+        - second: 'seconds', 'sec', 'secs'
+        - microsecond: 'microseconds', 'us', 'usec', 'usecs'
+
+    The 'isoweek' timeframe is a week starting on Monday 00:00, as in ``isocalendar()``,
+    whatever the ``week_start``.  Its ranges start at the Monday of the first ISO week.
+
     Usage::
         >>> import atomic_clock
         >>> atomic_clock.AtomicClock(2022, 3, 20, 10, 30, 45)
         <AtomicClock [2022-03-20T10:30:45+00:00]>
 
+    The repr names the zone of clocks in a named timezone other than UTC, and the class of
+    subclass instances::
+        >>> atomic_clock.AtomicClock(2022, 3, 15, 10, tzinfo='Asia/Shanghai')
+        <AtomicClock [2022-03-15T10:00:00+08:00 Asia/Shanghai]>
+
+    :class:`AtomicClock <atomic_clock.AtomicClock>` objects are immutable, assigning an attribute
+    raises ``AttributeError``; use :meth:`replace` or :meth:`shift` to get an updated copy.
     """
 
+    min: ClassVar[AtomicClock]
+    """The earliest clock :meth:`shift` can reach, 0001-01-01T00:00:00 UTC."""
+    max: ClassVar[AtomicClock]
+    """The latest clock :meth:`shift` can reach, 9999-12-31T23:59:59.999999 UTC."""
     def __init__(
         self,
         year: int,
@@ -62,10 +161,13 @@ class AtomicClock:
         minute: int = 0,
         second: int = 0,
         microsecond: int = 0,
-        tzinfo: str | dt.tzinfo | Tz = "UTC",
+        tzinfo: TzLike = "UTC",
+        *,
+        fold: Literal[0, 1] = 0,
+        nanosecond: int | None = None,
     ) -> None: ...
     @staticmethod
-    def now(tzinfo: str | dt.tzinfo | Tz = "local") -> AtomicClock:
+    def now(tzinfo: TzLike = "local") -> AtomicClock:
         """Constructs an :class:`AtomicClock <atomic_clock.AtomicClock>` object, representing "now" in the given
         timezone.
 
@@ -77,7 +179,9 @@ class AtomicClock:
             - A ``tzinfo`` object (note: very slow).
             - A ``atomic_clock.Tz`` object.
             - A ``str`` describing a timezone, similar to 'US/Pacific', or 'Asia/Shanghai'.
-            - A ``str`` in ISO 8601 style, as in '+07:00'.
+            - A ``str`` in ISO 8601 style, as in '+07', '+0700' or '+07:00', within ±24 hours.
+            - A POSIX TZ ``str`` with DST rules, as in 'EST5EDT,M3.2.0,M11.1.0'. Note that
+              POSIX offsets are positive west of UTC, 'EST5' is '-05:00'.
             - A ``str``, one of the following:  'local', 'utc', 'UTC'.
 
         Usage::
@@ -95,7 +199,7 @@ class AtomicClock:
         """
     @staticmethod
     def fromtimestamp(
-        timestamp: float, tzinfo: str | dt.tzinfo | Tz = "local"
+        timestamp: float, tzinfo: TzLike = "local"
     ) -> AtomicClock:
         """Constructs an :class:`AtomicClock <atomic_clock.AtomicClock>` object from a timestamp, converted to
         the given timezone.
@@ -109,10 +213,48 @@ class AtomicClock:
             - A ``tzinfo`` object (note: very slow).
             - A ``atomic_clock.Tz`` object.
             - A ``str`` describing a timezone, similar to 'US/Pacific', or 'Asia/Shanghai'.
-            - A ``str`` in ISO 8601 style, as in '+07:00'.
+            - A ``str`` in ISO 8601 style, as in '+07', '+0700' or '+07:00', within ±24 hours.
+            - A POSIX TZ ``str`` with DST rules, as in 'EST5EDT,M3.2.0,M11.1.0'. Note that
+              POSIX offsets are positive west of UTC, 'EST5' is '-05:00'.
             - A ``str``, one of the following:  'local', 'utc', 'UTC'.
         """
     @staticmethod
+    def fromstructtime(
+        st: struct_time | Tuple[int, int, int, int, int, int, int, int, int],
+        tzinfo: TzLike = "local",
+    ) -> AtomicClock:
+        """Constructs an :class:`AtomicClock <atomic_clock.AtomicClock>` object from a
+        ``time.struct_time`` or any 9-tuple, read as a wall time in the given timezone.
+        The inverse of :meth:`timetuple <atomic_clock.AtomicClock.timetuple>`.
+
+        ``tm_isdst`` picks between the two instants of a wall time repeated by a DST
+        transition, 1 for the DST one and 0 for the standard one, while -1 leaves it to the
+        zone, as ``time.mktime`` does.  ``tm_wday`` and ``tm_yday`` are ignored.
+
+        :param st: a ``time.struct_time`` or a 9-tuple.
+        :param tzinfo: (optional) A :ref:`timezone expression <tz-expr>`. Defaults to local time.
+
+        Usage::
+            >>> AtomicClock.fromstructtime(time.gmtime(0), "utc")
+            <AtomicClock [1970-01-01T00:00:00+00:00]>
+            >>> AtomicClock.fromstructtime((2022, 11, 6, 1, 30, 0, 6, 310, 1), "US/Eastern")
+            <AtomicClock [2022-11-06T01:30:00-04:00]>
+        """
+    @staticmethod
+    def fromtimestamp_ns(ns: int, tzinfo: TzLike = "local") -> AtomicClock:
+        """Constructs an :class:`AtomicClock <atomic_clock.AtomicClock>` object from an integer
+        timestamp in nanoseconds, converted to the given timezone.  Unlike
+        :func:`fromtimestamp <atomic_clock.AtomicClock.fromtimestamp>` no float is involved,
+        so the result is exact.
+
+        :param ns: an ``int`` of nanoseconds since the epoch.
+        :param tzinfo: (optional) A :ref:`timezone expression <tz-expr>`. Defaults to local time.
+
+        Usage::
+            >>> AtomicClock.fromtimestamp_ns(1647924832531622123, 'UTC')
+            <AtomicClock [2022-03-22T04:53:52.531622123+00:00]>
+        """
+    @staticmethod
     def utcfromtimestamp(timestamp: float) -> AtomicClock:
         """Constructs an :class:`AtomicClock <atomic_clock.AtomicClock>` object from a timestamp in UTC time
 
@@ -120,7 +262,7 @@ class AtomicClock:
         """
     @staticmethod
     def fromdatetime(
-        dt: dt.datetime, tzinfo: str | dt.tzinfo | Tz = "utc"
+        dt: dt.datetime, tzinfo: TzLike = "utc"
     ) -> AtomicClock:
         """Constructs an :class:`atomic_clock <atomic_clock.AtomicClock>` object from a ``datetime`` and
         optional replacement timezone.
@@ -135,7 +277,9 @@ class AtomicClock:
             - A ``tzinfo`` object (note: very slow).
             - A ``atomic_clock.Tz`` object.
             - A ``str`` describing a timezone, similar to 'US/Pacific', or 'Asia/Shanghai'.
-            - A ``str`` in ISO 8601 style, as in '+07:00'.
+            - A ``str`` in ISO 8601 style, as in '+07', '+0700' or '+07:00', within ±24 hours.
+            - A POSIX TZ ``str`` with DST rules, as in 'EST5EDT,M3.2.0,M11.1.0'. Note that
+              POSIX offsets are positive west of UTC, 'EST5' is '-05:00'.
             - A ``str``, one of the following:  'local', 'utc', 'UTC'.
 
         Usage::
@@ -145,7 +289,7 @@ class AtomicClock:
             <AtomicClock [2022-03-22T00:39:02.316809+08:00]>
         """
     @staticmethod
-    def fromdate(dt: dt.date, tzinfo: str | dt.tzinfo | Tz = "utc") -> AtomicClock:
+    def fromdate(dt: dt.date, tzinfo: TzLike = "utc") -> AtomicClock:
         """Constructs an :class:`AtomicClock <atomic_clock.AtomicClock>` object from a ``date`` and optional
         replacement timezone.  All time values are set to 0.
 
@@ -158,24 +302,154 @@ class AtomicClock:
             - A ``tzinfo`` object (note: very slow).
             - A ``atomic_clock.Tz`` object.
             - A ``str`` describing a timezone, similar to 'US/Pacific', or 'Asia/Shanghai'.
-            - A ``str`` in ISO 8601 style, as in '+07:00'.
+            - A ``str`` in ISO 8601 style, as in '+07', '+0700' or '+07:00', within ±24 hours.
+            - A POSIX TZ ``str`` with DST rules, as in 'EST5EDT,M3.2.0,M11.1.0'. Note that
+              POSIX offsets are positive west of UTC, 'EST5' is '-05:00'.
             - A ``str``, one of the following:  'local', 'utc', 'UTC'.
         """
     @staticmethod
+    def combine(
+        date: dt.date, time: dt.time, tzinfo: TzLike | None = None
+    ) -> AtomicClock:
+        """Constructs an :class:`AtomicClock <atomic_clock.AtomicClock>` object from a ``date``
+        and a ``time`` on the wall clock of a timezone, like ``datetime.combine``.
+
+        :param date: the ``date``
+        :param time: the ``time``, its ``fold`` picks between the instants of an ambiguous
+            wall time.
+        :param tzinfo: (optional) A :ref:`timezone expression <tz-expr>`.  Defaults to the
+            timezone of ``time``, or UTC if naive.
+        :raises ValueError: if ``time`` is aware and the wall time is a different instant in
+            its timezone than in ``tzinfo``.
+
+        Usage::
+            >>> AtomicClock.combine(date(2022, 1, 1), time(10, 30), 'Europe/Paris')
+            <AtomicClock [2022-01-01T10:30:00+01:00 Europe/Paris]>
+        """
+    @staticmethod
+    def resolve(
+        naive: dt.datetime | tuple[int, ...],
+        tz: TzLike,
+        nonexistent: Literal["raise", "shift_forward", "shift_backward"] = "shift_forward",
+        ambiguous: Literal["raise", "earlier", "later"] = "earlier",
+    ) -> AtomicClock:
+        """Constructs an :class:`AtomicClock <atomic_clock.AtomicClock>` object from a wall
+        time in a timezone, with explicit policies for the wall times DST transitions skip
+        or repeat.  This is the DST-safe localization entry point.
+
+        :param naive: a naive ``datetime``, or a tuple of ``(year, month, day[, hour, minute,
+            second, microsecond])``.
+        :param tz: A :ref:`timezone expression <tz-expr>`.
+        :param nonexistent: (optional) for a wall time skipped by a transition,
+            ``'shift_forward'`` to the first instant after the gap, ``'shift_backward'`` to
+            the last nanosecond before it, or ``'raise'``.  Defaults to ``'shift_forward'``.
+        :param ambiguous: (optional) for a wall time repeated by a transition, the
+            ``'earlier'`` or ``'later'`` of its instants, or ``'raise'``.  Defaults to
+            ``'earlier'``.
+        :raises ValueError: if ``naive`` is aware or not a valid date and time, or for a
+            skipped or repeated wall time with the ``'raise'`` policy.
+
+        Usage::
+            >>> AtomicClock.resolve((2022, 3, 13, 2, 30), 'America/New_York')
+            <AtomicClock [2022-03-13T03:00:00-04:00 America/New_York]>
+            >>> AtomicClock.resolve((2022, 11, 6, 1, 30), 'America/New_York', ambiguous='later')
+            <AtomicClock [2022-11-06T01:30:00-05:00 America/New_York]>
+        """
+    @staticmethod
+    def fromdict(d: dict[str, Any]) -> AtomicClock:
+        """Constructs an :class:`AtomicClock <atomic_clock.AtomicClock>` object from a dict of
+        the constructor's fields, as deserialized from a JSON config.
+
+        :param d: a dict with a ``year`` and any of ``month``, ``day``, ``hour``, ``minute``,
+            ``second``, ``microsecond``, ``nanosecond``, ``tzinfo`` and ``fold``.  Missing
+            fields default as for the constructor: January 1st, midnight in UTC.
+        :raises TypeError: if ``year`` is missing or any other key is present.
+
+        Usage::
+            >>> AtomicClock.fromdict({'year': 2022, 'month': 3, 'tzinfo': 'Asia/Tokyo'})
+            <AtomicClock [2022-03-01T00:00:00+09:00 Asia/Tokyo]>
+        """
+    @staticmethod
     def strptime(
-        date_str: str, fmt: str, tzinfo: str | dt.tzinfo | Tz | None = None
+        date_str: str, fmt: str, tzinfo: TzLike | None = None
     ) -> AtomicClock:
         """Constructs an :class:`AtomicClock <atomic_clock.AtomiClock>` object from a date string and format,
         in the style of ``datetime.strptime``.  Optionally replaces the parsed timezone.
 
         :param date_str: the date string.
         :param fmt: the format string using datetime format codes.
-        :param tzinfo: (optional) A :ref:`timezone expression <tz-expr>`.  Defaults to the parsed
-            timezone if ``fmt`` contains a timezone directive, otherwise UTC.
+        :param tzinfo: (optional) A :ref:`timezone expression <tz-expr>`.  If ``fmt`` contains an
+            offset directive the parsed datetime is converted to it, otherwise the parsed wall
+            time is interpreted in it. Defaults to the parsed timezone if ``fmt`` contains an
+            offset directive, otherwise UTC.
+
+        A parsed offset always defines the instant, ``tzinfo`` only picks the zone it's shown in:
+
+        ==================  ===============  ===========================================
+        Offset in string    ``tzinfo``       Result
+        ==================  ===============  ===========================================
+        yes                 given            the parsed instant, converted to ``tzinfo``
+        yes                 ``None``         the parsed instant, at the parsed offset
+        no                  given            the parsed wall time in ``tzinfo``
+        no                  ``None``         the parsed wall time in UTC
+        ==================  ===============  ===========================================
+
+        The ``%z`` and ``%:z`` directives accept ``Z``, ``+08``, ``+0800`` and ``+08:00``.
+
+        Fractional seconds with more than 9 digits are truncated to nanoseconds.
+
+        A leap second, ``:60`` as logged by GPS and NTP sources, is kept: its :attr:`second`
+        is 60 and it's formatted back as ``:60``.  It has the :meth:`timestamp` of the next
+        second, as POSIX time has no leap seconds, and is folded into ``:59.999999`` when
+        converted to a ``datetime`` or ``time``, which can't represent it.
+
+        ``%p`` matches ``AM``/``PM`` and ``a.m.``/``p.m.`` in any case, with ``12 AM`` being
+        midnight and ``12 PM`` noon.  A 12-hour ``%I`` without ``%p`` or ``%H`` is ambiguous and
+        raises a :class:`ParserError <atomic_clock.ParserError>`.
 
         Usage::
             >>> AtomicClock.strptime('20-01-2019 15:49:10', '%d-%m-%Y %H:%M:%S')
             <AtomicClock [2019-01-20T15:49:10+00:00]>
+            >>> AtomicClock.strptime('20-01-2019 15:49:10', '%d-%m-%Y %H:%M:%S', 'Asia/Tokyo')
+            <AtomicClock [2019-01-20T15:49:10+09:00]>
+            >>> AtomicClock.strptime('2019-01-20T15:49:10Z', '%Y-%m-%dT%H:%M:%S%z')
+            <AtomicClock [2019-01-20T15:49:10+00:00]>
+            >>> AtomicClock.strptime('2022-03-15 10:00 +0200', '%Y-%m-%d %H:%M %z', 'Asia/Tokyo')
+            <AtomicClock [2022-03-15T17:00:00+09:00 Asia/Tokyo]>
+            >>> AtomicClock.strptime('2019-01-20 15:49:10.123456789012', '%Y-%m-%d %H:%M:%S%.f')
+            <AtomicClock [2019-01-20T15:49:10.123456789+00:00]>
+        """
+    @staticmethod
+    def fromrfc2822(date_str: str) -> AtomicClock:
+        """Constructs an :class:`AtomicClock <atomic_clock.AtomicClock>` object from an RFC 2822
+        formatted string, as used in email and HTTP headers.
+
+        The obsolete zone names (``UT``, ``GMT``, ``EST``, ``PDT``...) and the two-digit year
+        forms are accepted. Unknown zone names and ``-0000`` are treated as UTC.
+
+        :param date_str: the RFC 2822 date string.
+
+        Usage::
+            >>> AtomicClock.fromrfc2822('Tue, 15 Mar 2022 10:30:00 +0800')
+            <AtomicClock [2022-03-15T10:30:00+08:00]>
+        """
+    @staticmethod
+    def fromhttpdate(date_str: str) -> AtomicClock:
+        """Constructs an :class:`AtomicClock <atomic_clock.AtomicClock>` object from an HTTP-date
+        string in UTC time.
+
+        All three formats required by RFC 7231 are accepted: IMF-fixdate, the obsolete RFC 850
+        format and ANSI C's asctime() format. Zones other than ``GMT`` are rejected.
+
+        :param date_str: the HTTP-date string.
+
+        Usage::
+            >>> AtomicClock.fromhttpdate('Sun, 06 Nov 1994 08:49:37 GMT')
+            <AtomicClock [1994-11-06T08:49:37+00:00]>
+            >>> AtomicClock.fromhttpdate('Sunday, 06-Nov-94 08:49:37 GMT')
+            <AtomicClock [1994-11-06T08:49:37+00:00]>
+            >>> AtomicClock.fromhttpdate('Sun Nov  6 08:49:37 1994')
+            <AtomicClock [1994-11-06T08:49:37+00:00]>
         """
     @staticmethod
     def fromordinal(ordinal) -> AtomicClock:
@@ -189,32 +463,94 @@ class AtomicClock:
             <AtomicClock [2022-03-22T00:00:00+00:00]>
         """
     @staticmethod
+    def from_julian_day(jd: float, tzinfo: TzLike = "UTC") -> AtomicClock:
+        """Constructs an :class:`AtomicClock <atomic_clock.AtomicClock>` object from a
+        Julian Date, the days since -4713-11-24T12:00:00 UTC in the proleptic Gregorian
+        calendar.
+
+        The exact value of the ``float`` is converted, rounded to the nearest nanosecond.
+        A ``float`` Julian Date near the present only resolves about 40 microseconds, use
+        :meth:`from_mjd` when finer precision matters.
+
+        :param jd: a ``float`` Julian Date.
+        :param tzinfo: (optional) a ``TzLike`` timezone for the result.  Defaults to UTC.
+
+        Usage::
+            >>> AtomicClock.from_julian_day(2451545.0)
+            <AtomicClock [2000-01-01T12:00:00+00:00]>
+        """
+    @staticmethod
+    def from_mjd(mjd: float, tzinfo: TzLike = "UTC") -> AtomicClock:
+        """Constructs an :class:`AtomicClock <atomic_clock.AtomicClock>` object from a
+        Modified Julian Date, the days since 1858-11-17T00:00:00 UTC.
+
+        The exact value of the ``float`` is converted, rounded to the nearest nanosecond.
+
+        :param mjd: a ``float`` Modified Julian Date.
+        :param tzinfo: (optional) a ``TzLike`` timezone for the result.  Defaults to UTC.
+
+        Usage::
+            >>> AtomicClock.from_mjd(59580.5)
+            <AtomicClock [2022-01-01T12:00:00+00:00]>
+        """
+    @staticmethod
+    def from_gps_seconds(seconds: float, tzinfo: TzLike = "UTC") -> AtomicClock:
+        """Constructs an :class:`AtomicClock <atomic_clock.AtomicClock>` object from GPS
+        seconds, the seconds since 1980-01-06T00:00:00 UTC, the inverse of :meth:`gps_seconds`.
+
+        Leap seconds are ignored, as for Unix timestamps, so this is
+        ``fromtimestamp(seconds + 315964800)``.  Actual GPS time is ahead of UTC by the
+        leap seconds since its epoch, 18 since 2017.
+
+        The exact value of the ``float`` is converted, rounded to the nearest nanosecond.
+
+        :param seconds: a ``float`` of GPS seconds.
+        :param tzinfo: (optional) a ``TzLike`` timezone for the result.  Defaults to UTC.
+
+        Usage::
+            >>> AtomicClock.from_gps_seconds(1325030418.0)
+            <AtomicClock [2022-01-01T00:00:18+00:00]>
+        """
+    @overload
+    @staticmethod
     def range(
-        frame: Literal[
-            "year", "month", "day", "hour", "minute", "second", "microsecond"
-        ],
-        start: AtomicClock | dt.datetime,
+        frame: Frame,
+        start: AtomicClock | dt.datetime | str,
         end: AtomicClock | dt.datetime | None = None,
         *,
-        tz: str | dt.tzinfo | Tz | None = None,
+        tz: TzLike | None = None,
+        wall_time: bool = False,
         limit: int | None = None,
-    ) -> Generator[AtomicClock, None, None]:
+        chunk_size: None = None,
+    ) -> DatetimeRangeIter:
         """Returns an iterator of :class:`AtomicClock <atomic_clock.AtomicClock>` objects, representing
         points in time between two inputs.
 
-        :param frame: The timeframe.  Can be any ``datetime`` property (day, hour, minute...).
-        :param start:  the start of the range.
+        :param frame: The timeframe.  Can be any ``datetime`` property (day, hour, minute...),
+            or one of its :ref:`aliases <frame-alias>`.
+        :param start:  the start of the range, or an ISO 8601 interval string for both ends,
+            see :func:`parse_interval <atomic_clock.parse_interval>`.
         :param end: (optional) the end of the range.
         :param tz: (optional) A :ref:`timezone expression <tz-expr>`.  Defaults to
             ``start``'s timezone.
+        :param wall_time: (optional) if ``True``, ``tz`` replaces the timezone of ``start`` and
+            ``end`` keeping their wall-clock fields; otherwise they are converted to ``tz``
+            keeping the same instant.  Defaults to ``False``.
         :param limit: (optional) A maximum number of tuples to return.
+        :param chunk_size: (optional) if given, each step yields a ``list`` of up to
+            ``chunk_size`` clocks instead of one at a time, computed with the GIL released.
 
         **NOTE**: The ``end`` or ``limit`` must be provided.  Call with ``end`` alone to
         return the entire range.  Call with ``limit`` alone to return a maximum # of results from
         the start.  Call with both to cap a range at a maximum # of results.
 
-        **NOTE**: ``tz`` internally **replaces** the timezones of both ``start`` and ``end`` before
-        iterating.
+        **NOTE**: ``tz`` internally **converts** both ``start`` and ``end`` before iterating, or
+        **replaces** their timezones when ``wall_time`` is ``True``::
+            >>> start = AtomicClock(2022, 1, 1, 9, tzinfo='+09:00')
+            >>> next(AtomicClock.range('hour', start, tz='UTC'))
+            <AtomicClock [2022-01-01T00:00:00+00:00]>
+            >>> next(AtomicClock.range('hour', start, tz='UTC', wall_time=True))
+            <AtomicClock [2022-01-01T09:00:00+00:00]>
 
         Usage::
             >>> start = AtomicClock(2013, 5, 5, 12, 30)
@@ -236,49 +572,80 @@ class AtomicClock:
             ...
             <AtomicClock [2013-05-05T12:30:00+00:00]>
             <AtomicClock [2013-05-05T13:30:00+00:00]>
+
+        **NOTE**: The 'isoweek' timeframe starts at the Monday of the ISO week of ``start``::
+            >>> start = AtomicClock(2021, 1, 1, 12)
+            >>> next(AtomicClock.range('isoweek', start))
+            <AtomicClock [2020-12-28T00:00:00+00:00]>
         """
+    @overload
+    @staticmethod
+    def range(
+        frame: Frame,
+        start: AtomicClock | dt.datetime | str,
+        end: AtomicClock | dt.datetime | None = None,
+        *,
+        tz: TzLike | None = None,
+        wall_time: bool = False,
+        limit: int | None = None,
+        chunk_size: int,
+    ) -> Iterator[List[AtomicClock]]: ...
+    @overload
     @staticmethod
     def span_range(
-        frame: Literal[
-            "year",
-            "month",
-            "day",
-            "hour",
-            "minute",
-            "second",
-        ],
-        start: AtomicClock | dt.datetime,
-        end: AtomicClock | dt.datetime,
+        frame: Frame,
+        start: AtomicClock | dt.datetime | str,
+        end: AtomicClock | dt.datetime | None = None,
         *,
-        tz: str | dt.tzinfo | Tz | None = None,
+        tz: TzLike | None = None,
+        wall_time: bool = False,
         limit: int | None = None,
-        bounds: Literal["[]", "()", "[)", "(]"] = "[)",
+        chunk_size: None = None,
+        n: int = 1,
+        bounds: Bounds = "[)",
         exact: bool = False,
-    ) -> Iterable[Tuple[AtomicClock, AtomicClock]]:
+        week_start: int = 1,
+        anchor: AtomicClock | dt.datetime | None = None,
+    ) -> Iterator[Tuple[AtomicClock, AtomicClock]]:
         """Returns an iterator of tuples, each :class:`AtomicClock <atomic_clock.AtomicClock>` objects,
         representing a series of timespans between two inputs.
 
-        :param frame: The timeframe.  Can be any ``datetime`` property (day, hour, minute...).
-        :param start: A datetime expression, the start of the range.
-        :param end: (optional) A datetime expression, the end of the range.
+        :param frame: The timeframe.  Can be any ``datetime`` property (day, hour, minute...),
+            or one of its :ref:`aliases <frame-alias>`.
+        :param start: A datetime expression, the start of the range, or an ISO 8601 interval
+            string for both ends, see :func:`parse_interval <atomic_clock.parse_interval>`.
+        :param end: (optional) A datetime expression, the end of the range.  Required unless
+            ``start`` is an interval.
         :param tz: (optional) A :ref:`timezone expression <tz-expr>`.  Defaults to
             ``start``'s timezone, or UTC if ``start`` is naive.
+        :param wall_time: (optional) if ``True``, ``tz`` replaces the timezone of ``start`` and
+            ``end`` keeping their wall-clock fields; otherwise they are converted to ``tz``
+            keeping the same instant.  Defaults to ``False``.
         :param limit: (optional) A maximum number of tuples to return.
+        :param chunk_size: (optional) if given, each step yields a ``list`` of up to
+            ``chunk_size`` spans instead of one at a time, computed with the GIL released.
+        :param n: (optional) spans of ``n`` frames aligned to the start of the parent frame,
+            e.g. ``'hour'`` with ``n=6`` iterates over 00-06-12-18 buckets.  See
+            :func:`span <atomic_clock.AtomicClock.span>` for the accepted values.
         :param bounds: (optional) a ``str`` of either '()', '(]', '[)', or '[]' that specifies
             whether to include or exclude the start and end values in each span in the range. '(' excludes
             the start, '[' includes the start, ')' excludes the end, and ']' includes the end.
-            If the bounds are not specified, the default bound '[)' is used.
+            If the bounds are not specified, the default bound '[)' is used.  The bounds may also
+            be one of their :ref:`aliases <bounds>`.
         :param exact: (optional) whether to have the first timespan start exactly
             at the time specified by ``start`` and the final span truncated
             so as not to extend beyond ``end``.
+        :param week_start: (optional) only used in combination with the week timeframe. Follows isoweekday() where
+            Monday is 1 and Sunday is 7.
+        :param anchor: (optional) counts the spans of ``n`` frames from this datetime instead
+            of the calendar, see :func:`span <atomic_clock.AtomicClock.span>`.
 
         **NOTE**: The ``end`` or ``limit`` must be provided.  Call with ``end`` alone to
         return the entire range.  Call with ``limit`` alone to return a maximum # of results from
         the start.  Call with both to cap a range at a maximum # of results.
 
-        **NOTE**: ``tz`` internally **replaces** the timezones of both ``start`` and ``end`` before
-        iterating.  As such, either call with naive objects and ``tz``, or aware objects from the
-        same timezone and no ``tz``.
+        **NOTE**: ``tz`` internally **converts** both ``start`` and ``end`` before iterating, or
+        **replaces** their timezones when ``wall_time`` is ``True``.
 
         Supported frame values: year, quarter, month, week, day, hour, minute, second.
 
@@ -299,40 +666,68 @@ class AtomicClock:
             (<AtomicClock [2013-05-05T16:00:00+00:00]>, <AtomicClock [2013-05-05T16:59:59.999999+00:00]>)
             (<AtomicClock [2013-05-05T17:00:00+00:00]>, <AtomicClock [2013-05-05T17:59:59.999999+00:00]>)
         """
+    @overload
+    @staticmethod
+    def span_range(
+        frame: Frame,
+        start: AtomicClock | dt.datetime | str,
+        end: AtomicClock | dt.datetime | None = None,
+        *,
+        tz: TzLike | None = None,
+        wall_time: bool = False,
+        limit: int | None = None,
+        chunk_size: int,
+        n: int = 1,
+        bounds: Bounds = "[)",
+        exact: bool = False,
+        week_start: int = 1,
+        anchor: AtomicClock | dt.datetime | None = None,
+    ) -> Iterator[List[Tuple[AtomicClock, AtomicClock]]]: ...
+    @overload
     @staticmethod
     def interval(
-        frame: Literal[
-            "year",
-            "month",
-            "day",
-            "hour",
-            "minute",
-            "second",
-        ],
-        start: AtomicClock | dt.datetime,
-        end: AtomicClock | dt.datetime,
+        frame: Frame,
+        start: AtomicClock | dt.datetime | str,
+        end: AtomicClock | dt.datetime | None = None,
         *,
         interval: int = 1,
-        tz: str | dt.tzinfo | Tz | None = None,
+        tz: TzLike | None = None,
+        wall_time: bool = False,
         limit: int | None = None,
-        bounds: Literal["[]", "()", "[)", "(]"] = "[)",
+        chunk_size: None = None,
+        bounds: Bounds = "[)",
         exact: bool = False,
-    ) -> Iterable[Tuple[AtomicClock, AtomicClock]]:
+        week_start: int = 1,
+        starts_only: Literal[False] = False,
+    ) -> Iterator[Tuple[AtomicClock, AtomicClock]]:
         """Returns an iterator of tuples, each :class:`AtomicClock <atomic_clock.AtomicClock>` objects,
         representing a series of intervals between two inputs.
 
-        :param frame: The timeframe.  Can be any ``datetime`` property (day, hour, minute...).
-        :param start: A datetime expression, the start of the range.
-        :param end: (optional) A datetime expression, the end of the range.
+        :param frame: The timeframe.  Can be any ``datetime`` property (day, hour, minute...),
+            or one of its :ref:`aliases <frame-alias>`.
+        :param start: A datetime expression, the start of the range, or an ISO 8601 interval
+            string for both ends, see :func:`parse_interval <atomic_clock.parse_interval>`.
+        :param end: (optional) A datetime expression, the end of the range.  Required unless
+            ``start`` is an interval.
         :param interval: (optional) Time interval for the given time frame.
         :param tz: (optional) A timezone expression.  Defaults to UTC.
+        :param wall_time: (optional) if ``True``, ``tz`` replaces the timezone of ``start`` and
+            ``end`` keeping their wall-clock fields; otherwise they are converted to ``tz``
+            keeping the same instant.  Defaults to ``False``.
+        :param chunk_size: (optional) if given, each step yields a ``list`` of up to
+            ``chunk_size`` intervals instead of one at a time, computed with the GIL released.
         :param bounds: (optional) a ``str`` of either '()', '(]', '[)', or '[]' that specifies
             whether to include or exclude the start and end values in the intervals. '(' excludes
             the start, '[' includes the start, ')' excludes the end, and ']' includes the end.
-            If the bounds are not specified, the default bound '[)' is used.
+            If the bounds are not specified, the default bound '[)' is used.  The bounds may also
+            be one of their :ref:`aliases <bounds>`.
         :param exact: (optional) whether to have the first timespan start exactly
             at the time specified by ``start`` and the final interval truncated
             so as not to extend beyond ``end``.
+        :param week_start: (optional) only used in combination with the week timeframe. Follows isoweekday() where
+            Monday is 1 and Sunday is 7.
+        :param starts_only: (optional) if ``True``, yields the first element of each interval
+            alone, like ``range`` stepping several frames at once.
 
         Supported frame values: year, quarter, month, week, day, hour, minute, second
 
@@ -349,6 +744,70 @@ class AtomicClock:
             (<AtomicClock [2013-05-05T12:00:00+00:00]>, <AtomicClock [2013-05-05T13:59:59.999999+00:00]>)
             (<AtomicClock [2013-05-05T14:00:00+00:00]>, <AtomicClock [2013-05-05T15:59:59.999999+00:00]>)
             (<AtomicClock [2013-05-05T16:00:00+00:00]>, <AtomicClock [2013-05-05T17:59:59.999999+00:0]>)
+            >>> list(atomic_clock.AtomicClock.interval('hour', start, end, interval=2, starts_only=True))
+            [<AtomicClock [2013-05-05T12:00:00+00:00]>, <AtomicClock [2013-05-05T14:00:00+00:00]>, <AtomicClock [2013-05-05T16:00:00+00:00]>]
+        """
+    @overload
+    @staticmethod
+    def interval(
+        frame: Frame,
+        start: AtomicClock | dt.datetime | str,
+        end: AtomicClock | dt.datetime | None = None,
+        *,
+        interval: int = 1,
+        tz: TzLike | None = None,
+        wall_time: bool = False,
+        limit: int | None = None,
+        chunk_size: int,
+        bounds: Bounds = "[)",
+        exact: bool = False,
+        week_start: int = 1,
+        starts_only: Literal[False] = False,
+    ) -> Iterator[List[Tuple[AtomicClock, AtomicClock]]]: ...
+    @overload
+    @staticmethod
+    def interval(
+        frame: Frame,
+        start: AtomicClock | dt.datetime | str,
+        end: AtomicClock | dt.datetime | None = None,
+        *,
+        interval: int = 1,
+        tz: TzLike | None = None,
+        wall_time: bool = False,
+        limit: int | None = None,
+        chunk_size: None = None,
+        bounds: Bounds = "[)",
+        exact: bool = False,
+        week_start: int = 1,
+        starts_only: Literal[True],
+    ) -> Iterator[AtomicClock]: ...
+    @overload
+    @staticmethod
+    def interval(
+        frame: Frame,
+        start: AtomicClock | dt.datetime | str,
+        end: AtomicClock | dt.datetime | None = None,
+        *,
+        interval: int = 1,
+        tz: TzLike | None = None,
+        wall_time: bool = False,
+        limit: int | None = None,
+        chunk_size: int,
+        bounds: Bounds = "[)",
+        exact: bool = False,
+        week_start: int = 1,
+        starts_only: Literal[True],
+    ) -> Iterator[List[AtomicClock]]: ...
+    @staticmethod
+    def midpoint(a: AtomicClock, b: AtomicClock) -> AtomicClock:
+        """Returns a new :class:`AtomicClock <atomic_clock.AtomicClock>` object exactly halfway
+        between ``a`` and ``b``, in ``a``'s timezone, in either order.  An odd number of
+        nanoseconds between them rounds down to the earlier instant.
+
+        Usage::
+            >>> a = AtomicClock(2022, 1, 1, tzinfo='+08:00')
+            >>> AtomicClock.midpoint(a, AtomicClock(2022, 1, 2))
+            <AtomicClock [2022-01-01T16:00:00+08:00]>
         """
     def date(self) -> dt.date:
         """Returns a ``date`` object with the same year, month and day.
@@ -371,7 +830,10 @@ class AtomicClock:
             >>> AtomicClock.utcnow().timetz()
             datetime.time(13, 54, 18, 886227, tzinfo=<Tz [UTC]>)
         """
-    def astimezone(self, tz: str | dt.tzinfo | Tz | None = None) -> dt.datetime:
+    def timez(self) -> dt.time:
+        """An alias of :meth:`timetz <atomic_clock.AtomicClock.timetz>`, kept for
+        compatibility."""
+    def astimezone(self, tz: TzLike | None = None) -> dt.datetime:
         """Returns a ``datetime`` object, converted to the specified timezone.
 
         :param tz: A :ref:`timezone expression <tz-expr>`.
@@ -390,6 +852,21 @@ class AtomicClock:
             >>> AtomicClock.now('US/Pacific').utcoffset()
             datetime.timedelta(days=-1, seconds=61200)
         """
+    def offset_string(self, colon: bool = True, *, utc_as_z: bool = False) -> str:
+        """Returns the UTC offset of the :class:`AtomicClock <atomic_clock.AtomicClock>` object
+        as a signed ``HH:MM`` string, with the seconds appended for the offsets that have them.
+
+        :param colon: (optional) ``False`` to omit the colons, as in ``+0530``.
+        :param utc_as_z: (optional) ``True`` to return ``Z`` for a zero offset.
+
+        Usage::
+            >>> AtomicClock(2022, 1, 1, tzinfo='Asia/Kolkata').offset_string()
+            '+05:30'
+            >>> AtomicClock(2022, 1, 1, tzinfo='US/Pacific').offset_string(colon=False)
+            '-0800'
+            >>> AtomicClock(2022, 1, 1).offset_string(utc_as_z=True)
+            'Z'
+        """
     def dst(self) -> Optional[dt.timedelta]:
         """Returns the daylight savings time adjustment.
 
@@ -418,6 +895,33 @@ class AtomicClock:
             >>> AtomicClock.utcnow().toordinal()
             738237
         """
+    def julian_day(self) -> float:
+        """Returns the Julian Date, the days since -4713-11-24T12:00:00 UTC in the
+        proleptic Gregorian calendar.  The Unix epoch is Julian Date 2440587.5, so it's
+        ``timestamp() / 86400 + 2440587.5``.
+
+        Usage::
+            >>> AtomicClock(2000, 1, 1, 12).julian_day()
+            2451545.0
+        """
+    def mjd(self) -> float:
+        """Returns the Modified Julian Date, the days since 1858-11-17T00:00:00 UTC.
+
+        Usage::
+            >>> AtomicClock(2022, 1, 1, 12).mjd()
+            59580.5
+        """
+    def gps_seconds(self) -> float:
+        """Returns the GPS seconds, the seconds since 1980-01-06T00:00:00 UTC.
+
+        Leap seconds are ignored, as for :meth:`timestamp`, so this is
+        ``timestamp() - 315964800``.  Actual GPS time is ahead of UTC by the leap seconds
+        since its epoch, 18 since 2017.
+
+        Usage::
+            >>> AtomicClock(2022, 1, 1).gps_seconds()
+            1325030400.0
+        """
     def weekday(self) -> int:
         """Returns the day of the week as an integer (0-6).
 
@@ -434,29 +938,77 @@ class AtomicClock:
         """
     def isocalendar(self) -> Tuple[int, int, int]:
         """Returns an IsoCalendarDate namedtuple, (ISO year, ISO week number, ISO weekday).
+        It unpacks, indexes and has a ``len`` like a 3-tuple.
+
         Usage::
             >>> AtomicClock.utcnow().isocalendar()
             IsoCalendarDate(year=2022, week=12, weekday=3)
+            >>> AtomicClock.utcnow().isocalendar()[1]
+            12
+        """
+    def week_of_year(self, convention: Literal["iso", "sunday", "monday"] = "iso") -> int:
+        """Returns the week number of the date.
+
+        :param convention: (optional) ``'iso'`` for the ISO week (1-53), as the ``week``
+            attribute.  ``'sunday'`` and ``'monday'`` number weeks starting on that day as
+            ``strftime``'s ``%U`` and ``%W`` do (0-53): days before the year's first Sunday,
+            respectively Monday, are in week 0.  Defaults to ``'iso'``.
+
+        Usage::
+            >>> AtomicClock(2022, 1, 1).week_of_year()
+            52
+            >>> AtomicClock(2022, 1, 2).week_of_year('sunday')
+            1
+            >>> AtomicClock(2022, 1, 2).week_of_year('monday')
+            0
+        """
+    def format_isoweek(self, with_day: bool = False) -> str:
+        """Returns the ISO week of the date as ``YYYY-Www``, or ``YYYY-Www-D`` with the
+        ISO weekday (1-7) appended.
+
+        :param with_day: (optional) append the ISO weekday. Defaults to False.
+
+        Usage::
+            >>> AtomicClock(2021, 1, 1).format_isoweek()
+            '2020-W53'
+            >>> AtomicClock(2021, 1, 1).format_isoweek(with_day=True)
+            '2020-W53-5'
         """
     def isoformat(
         self,
         sep: str = "T",
         timespec: Literal[
-            "auto", "hours", "minutes", "seconds", "milliseconds", "microseconds"
+            "auto",
+            "hours",
+            "minutes",
+            "seconds",
+            "milliseconds",
+            "microseconds",
+            "nanoseconds",
         ] = "auto",
     ) -> str:
         """Returns an ISO 8601 formatted representation of the date and time.
 
+        ``timespec="nanoseconds"`` always writes 9 fractional digits, so the string
+        round-trips losslessly through :func:`get`.
+
         Usage::
             >>> AtomicClock.utcnow().isoformat()
             '2022-03-23T16:43:23.314834+00:00'
+            >>> atomic_clock.get('2022-03-23T16:43:23.314834567').isoformat(timespec='nanoseconds')
+            '2022-03-23T16:43:23.314834567+00:00'
         """
     def ctime(self) -> str:
         """Returns a ctime formatted representation of the date and time.
 
+        The output is identical to ``datetime.ctime()`` regardless of the current locale,
+        single-digit days are padded with a space.
+
         Usage::
             >>> AtomicClock.utcnow().ctime()
             'Wed Mar 23 16:44:00 2022'
+            >>> AtomicClock(2022, 1, 2, 3, 4, 5).ctime()
+            'Sun Jan  2 03:04:05 2022'
         """
     def strftime(self, format: str) -> str:
         """Formats in the style of ``datetime.strftime``.
@@ -467,6 +1019,37 @@ class AtomicClock:
             >>> AtomicClock.utcnow().strftime('%d-%m-%Y %H:%M:%S')
             '23-03-2022 16:44:37'
         """
+    def to_rfc2822(self) -> str:
+        """Returns an RFC 2822 formatted representation of the date and time, as used in
+        email and HTTP headers.  Fractional seconds are dropped and the timezone is written as
+        its UTC offset, :func:`fromrfc2822 <atomic_clock.AtomicClock.fromrfc2822>` parses
+        it back.
+
+        Usage::
+            >>> AtomicClock(2022, 3, 15, 10, 30, tzinfo='Asia/Shanghai').to_rfc2822()
+            'Tue, 15 Mar 2022 10:30:00 +0800'
+        """
+    def to_rfc3339(
+        self,
+        timespec: Literal["auto", "seconds", "milliseconds", "microseconds"] = "auto",
+    ) -> str:
+        """Returns a strict RFC 3339 formatted representation of the date and time, using
+        ``Z`` for UTC.
+
+        Usage::
+            >>> AtomicClock(2022, 3, 15, 10, 30).to_rfc3339()
+            '2022-03-15T10:30:00Z'
+            >>> AtomicClock(2022, 3, 15, 10, 30, tzinfo='Asia/Shanghai').to_rfc3339('milliseconds')
+            '2022-03-15T10:30:00.000+08:00'
+        """
+    def to_httpdate(self) -> str:
+        """Returns an HTTP-date (IMF-fixdate) formatted representation of the date and time,
+        always converted to GMT as RFC 7231 requires.  Fractional seconds are dropped.
+
+        Usage::
+            >>> AtomicClock(2022, 3, 15, 10, 30, tzinfo='Asia/Shanghai').to_httpdate()
+            'Tue, 15 Mar 2022 02:30:00 GMT'
+        """
     def clone(self) -> AtomicClock:
         """Returns a new :class:`AtomicClock <atomic_clock.AtomiClock>` object, cloned from the current one.
 
@@ -484,7 +1067,11 @@ class AtomicClock:
         minute: int | None = None,
         second: int | None = None,
         microsecond: int | None = None,
-        tzinfo: str | dt.tzinfo | Tz | None = None,
+        nanosecond: int | None = None,
+        tzinfo: TzLike | int | None = None,
+        fold: Literal[0, 1] | None = None,
+        delta: RelativeDelta | None = None,
+        day_clamp: bool = False,
     ) -> AtomicClock:
         """Returns a new :class:`AtomicClock <atomic_clock.AtomicClock>` object with attributes updated
         according to inputs.
@@ -498,11 +1085,81 @@ class AtomicClock:
             >>> arw.replace(year=2021, month=8)
             <AtomicClock [2021-08-24T14:44:51.560065+00:00]>
 
+        ``microsecond`` and ``nanosecond`` both set the whole fraction of the second, no
+        nanoseconds of the original are left behind.  Passing both is an error::
+
+            >>> ac = AtomicClock.fromtimestamp_ns(1_000_000_000_123_456_789, "UTC")
+            >>> ac.replace(microsecond=5).isoformat()
+            '2001-09-09T01:46:40.000005+00:00'
+            >>> ac.replace(nanosecond=5).isoformat()
+            '2001-09-09T01:46:40.000000005+00:00'
+
         You can also replace the timezone without conversion, using a
         :ref:`timezone expression <tz-expr>`::
 
             >>> now.replace(tzinfo="local")
             <AtomicClock [2021-08-24T22:44:51.560065+08:00]>
+
+        ``"local"`` is resolved on every call, so it follows changes to ``TZ`` made
+        with :func:`time.tzset`.
+
+        An integer ``tzinfo`` is an UTC offset in minutes (``-1439..1439``)::
+
+            >>> now.replace(tzinfo=-480)
+            <AtomicClock [2021-08-24T14:44:51.560065-08:00]>
+
+        Use ``fold`` to pick the later of two ambiguous wall times during a DST
+        transition; the current fold is kept when ``fold`` isn't given::
+
+            >>> ac = AtomicClock(2017, 11, 5, 1, 30, tzinfo="UTC")
+            >>> ac.replace(tzinfo="America/New_York", fold=1)
+            <AtomicClock [2017-11-05T01:30:00-05:00]>
+
+        A day the target month doesn't have raises a ``ValueError``, as replacing the year
+        of February 29th with a common year does.  With ``day_clamp=True`` the date is
+        replaced at once and the day, kept or given, is clamped to the end of the month::
+
+            >>> ac = AtomicClock(2024, 2, 29)
+            >>> ac.replace(year=2023, day_clamp=True)
+            <AtomicClock [2023-02-28T00:00:00+00:00]>
+            >>> ac.replace(month=4, day=31, day_clamp=True)
+            <AtomicClock [2024-04-30T00:00:00+00:00]>
+
+        A day past 31 still raises a ``ValueError``.
+
+        Pass a ``delta`` to shift the result in the same call.  Fields are replaced
+        first, then ``delta`` is applied as by :meth:`shift`, so its ``weekday``
+        targeting runs last::
+
+            >>> ac = AtomicClock(2022, 4, 5, 10)
+            >>> ac.replace(day=1, delta=RelativeDelta(months=1, weekday=0))
+            <AtomicClock [2022-05-02T10:00:00+00:00]>
+
+        A keyword that isn't one of the above, such as a misspelled ``mircosecond``,
+        raises a ``TypeError`` naming it instead of being ignored.
+        """
+    def on(self, year: int, month: int, day: int) -> AtomicClock:
+        """Returns a new :class:`AtomicClock <atomic_clock.AtomicClock>` object with the date
+        replaced and the wall time kept.  Unlike chained :meth:`replace` calls, the date is
+        replaced at once, so moving from the 31st to a shorter month works.
+
+        :raises ValueError: if the date doesn't exist, or the wall time doesn't exist on it.
+
+        Usage::
+            >>> AtomicClock(2022, 1, 31, 10).on(2022, 2, 28)
+            <AtomicClock [2022-02-28T10:00:00+00:00]>
+        """
+    def at(
+        self, hour: int, minute: int = 0, second: int = 0, microsecond: int = 0
+    ) -> AtomicClock:
+        """Returns a new :class:`AtomicClock <atomic_clock.AtomicClock>` object with the wall
+        time replaced and the date kept.  Omitted fields, and nanoseconds, are zero.
+
+        :raises ValueError: if the time is invalid, or skipped by a DST transition.
+
+        Usage::
+            >>> atomic_clock.get('2022-03-15').at(14, 30).with_tz('Asia/Tokyo')
+            <AtomicClock [2022-03-15T23:30:00+09:00 Asia/Tokyo]>
         """
     def shift(
         self,
@@ -517,6 +1174,10 @@ class AtomicClock:
         weeks: int = 0,
         quarters: int = 0,
         weekday: Literal[0, 1, 2, 3, 4, 5, 6] | Weekday | None = None,
+        weekday_backwards: bool = False,
+        timedelta: dt.timedelta | None = None,
+        anchor: Literal["preserve", "month_end"] = "preserve",
+        clamp: bool = False,
     ) -> AtomicClock:
         """Returns a new :class:`AtomicClock <atomic_clock.AtomicClock>` object with attributes updated
         according to inputs.
@@ -544,6 +1205,43 @@ class AtomicClock:
 
         >>> now.shift(weekday=0)
         <AtomicClock [2022-03-28T10:29:11.634832+00:00]>
+
+        With ``weekday_backwards=True`` the returned date is always less than or equal to the
+        starting date instead:
+
+        >>> now.shift(weekday=0, weekday_backwards=True)
+        <AtomicClock [2022-03-21T10:29:11.634832+00:00]>
+
+        The units are applied in order: years, months and quarters, days and weeks, the time
+        units, and the weekday last, so these recipes work across year boundaries:
+
+        >>> now.replace(day=1).shift(months=1, weekday=0)  # first Monday of next month
+        <AtomicClock [2022-04-04T10:29:11.634832+00:00]>
+        >>> now.replace(day=1).shift(months=1, days=-1, weekday=4, weekday_backwards=True)
+        <AtomicClock [2022-03-25T10:29:11.634832+00:00]>
+
+        A ``timedelta`` is added exactly, together with the other units:
+
+        >>> now.shift(months=1, timedelta=timedelta(hours=1, microseconds=5))
+        <AtomicClock [2022-04-25T11:29:11.634837+00:00]>
+
+        Shifting months clamps the day to the end of shorter months, which then sticks.  With
+        ``anchor="month_end"`` a clock on the last day of its month lands on the last day of
+        the target month instead:
+
+        >>> jan = AtomicClock(2022, 1, 31)
+        >>> jan.shift(months=1).shift(months=1)
+        <AtomicClock [2022-03-28T00:00:00+00:00]>
+        >>> jan.shift(months=1, anchor="month_end").shift(months=1, anchor="month_end")
+        <AtomicClock [2022-03-31T00:00:00+00:00]>
+
+        The wall time of the result has to be within the years 1 to 9999, as for ``datetime``,
+        otherwise :class:`RangeError <atomic_clock.RangeError>`, an ``OverflowError``, is
+        raised.  With ``clamp=True`` the result saturates at the first or last wall time of
+        that range instead, :attr:`min` and :attr:`max` in UTC:
+
+        >>> AtomicClock(9999, 6, 1).shift(years=1, clamp=True)
+        <AtomicClock [9999-12-31T23:59:59.999999+00:00]>
         """
     def for_json(self) -> str:
         """Serializes for the ``for_json`` protocol of simplejson.
@@ -552,12 +1250,15 @@ class AtomicClock:
             >>> AtomicClock.utcnow().for_json()
             '2022-03-23T16:45:17.722416+00:00'
         """
-    def to(self, tzinfo: str | dt.tzinfo | Tz) -> AtomicClock:
+    def to(self, tzinfo: TzLike) -> AtomicClock:
         """Returns a new :class:`AtomicClock <atomic_clock.AtomiClock>` object, converted
         to the target timezone.
 
         :param tzinfo: A :ref:`timezone expression <tz-expr>`.
 
+        ``'local'`` is looked up at call time and converts with the local offset in effect at
+        this instant, whereas ``Tz('local')`` keeps the offset it was created with.
+
         Usage::
             >>> utc = AtomicClock.utcnow()
             >>> utc
@@ -575,13 +1276,62 @@ class AtomicClock:
             >>> utc.to('local').to('utc')
             <AtomicClock [2013-05-09T03:49:12.311072+00:00]>
         """
-    def format(self, fmt: str = "%Y-%m-%d %H:%M:%S%Z") -> str:
+    def with_tz(self, tzinfo: TzLike) -> AtomicClock:
+        """An alias of :meth:`to`, for chaining with :meth:`on` and :meth:`at`."""
+    def to_naive(self, tzinfo: TzLike | None = None) -> dt.datetime:
+        """Returns the naive wall time in the target timezone, or in the current one,
+        where :attr:`naive <atomic_clock.AtomicClock.naive>` gives the wall time in UTC.
+
+        :param tzinfo: (optional) A :ref:`timezone expression <tz-expr>`.
+
+        Usage::
+            >>> utc = AtomicClock(2022, 3, 23, 12, 36, 32)
+            >>> utc.to_naive('Asia/Tokyo')
+            datetime.datetime(2022, 3, 23, 21, 36, 32)
+            >>> utc.to('US/Pacific').to_naive()
+            datetime.datetime(2022, 3, 23, 5, 36, 32)
+        """
+    def format(
+        self, fmt: str = "%Y-%m-%d %H:%M:%S%:z", locale: str = "en", *, tokens: bool = False
+    ) -> str:
         """Returns a string representation of the :class:`AtomicClock <atomic_clock.AtomicClock>` object,
         formatted according to the provided format string.
 
-        Visit https://docs.rs/chrono/latest/chrono/format/strftime/index.html to get more formatter details.
+        The format string uses strftime directives, visit
+        https://docs.rs/chrono/latest/chrono/format/strftime/index.html to get more formatter details,
+        so text without directives is kept as-is.  With ``tokens`` it uses Arrow-style tokens:
+
+        ==========  ===============================================
+        Token       Output
+        ==========  ===============================================
+        YYYY, YY    2022, 22
+        Qo          quarter, 1st
+        MMMM, MMM   March, Mar
+        MM, M, Mo   03, 3, 3rd
+        DDDD, DDD   day of year, 060, 60
+        DDDo        day of year, 60th
+        DD, D, Do   01, 1, 1st
+        dddd, ddd   Tuesday, Tue
+        d           ISO weekday, 1 to 7
+        HH, H       15, 15
+        hh, h       03, 3
+        mm, m       04, 4
+        ss, s       05, 5
+        S...        fraction of second, truncated to 1 to 6 digits
+        a, A        pm, PM
+        ZZZ         timezone name, JST
+        ZZ, Z       +09:00, +0900
+        X, x        timestamp in seconds, in microseconds
+        W           ISO week date, 2022-W09-2
+        Wo          ISO week, 9th
+        [...]       escaped text, kept as-is
+        ==========  ===============================================
 
         :param fmt: the format string.
+        :param locale: (optional) the locale of the day, month and meridian names, and of the
+            ordinals.  See :func:`locales` for the available locales.  Defaults to ``"en"``.
+        :param tokens: (optional) read ``fmt`` as Arrow-style tokens instead of strftime
+            directives.  Defaults to ``False``.
 
         Usage::
             >>> now = AtomicClock.utcnow()
@@ -590,17 +1340,223 @@ class AtomicClock:
             >>> now.format('%s')
             '1648041950'
             >>> now.format()
-            2022-03-23 13:25:50+00:00'
+            '2022-03-23 13:25:50+00:00'
+            >>> now.format('dddd, MMMM Do YYYY [at] HH:mm', tokens=True)
+            'Wednesday, March 23rd 2022 at 13:25'
+            >>> now.format('dddd, MMMM Do YYYY', locale='fr', tokens=True)
+            'mercredi, mars 23e 2022'
+        """
+    def ordinal_day(self, locale: str = "en") -> str:
+        """Returns the day of the month as an ordinal, as the ``Do`` token of :meth:`format`.
+
+        :param locale: (optional) the locale of the ordinal.  Defaults to ``"en"``.
+
+        Usage::
+            >>> AtomicClock(2022, 3, 22).ordinal_day()
+            '22nd'
+            >>> AtomicClock(2022, 3, 1).ordinal_day(locale='fr')
+            '1er'
+        """
+    def humanize(
+        self, other: dt.datetime | AtomicClock | None = None, locale: str = "en"
+    ) -> str:
+        """Returns a localized, humanized representation of a relative difference in time.
+
+        :param other: (optional) the datetime to compare against.  Defaults to now.
+        :param locale: (optional) the locale of the phrases.  See :func:`locales` for the
+            available locales.  Defaults to ``"en"``.
+
+        The phrase only depends on the time elapsed between both instants, not on the day
+        boundaries of either timezone, so an hour across midnight is ``'an hour ago'``
+        rather than ``'a day ago'``.
+
+        Usage::
+            >>> earlier = atomic_clock.utcnow().shift(hours=-2)
+            >>> earlier.humanize()
+            '2 hours ago'
+            >>> later = earlier.shift(hours=4)
+            >>> later.humanize(earlier)
+            'in 4 hours'
+            >>> later.humanize(earlier, locale='fr')
+            'dans 4 heures'
+        """
+    def detect_frame(
+        self, other: dt.datetime | AtomicClock
+    ) -> Literal[
+        "year",
+        "quarter",
+        "month",
+        "week",
+        "day",
+        "hour",
+        "minute",
+        "second",
+        "microsecond",
+    ]:
+        """Returns the coarsest timeframe whose single unit fits in the difference between the
+        :class:`AtomicClock <atomic_clock.AtomicClock>` object and ``other``, in either order.
+        Calendar frames are measured from the earlier of the two.
+
+        :param other: a ``datetime`` or :class:`AtomicClock <atomic_clock.AtomicClock>`.
+
+        Usage::
+            >>> start = AtomicClock(2022, 1, 1)
+            >>> start.detect_frame(start.shift(minutes=90))
+            'hour'
+            >>> start.detect_frame(start.shift(hours=40))
+            'day'
+        """
+    def seconds_since(self, other: dt.datetime | AtomicClock | None = None) -> float:
+        """Returns the seconds elapsed from the :class:`AtomicClock <atomic_clock.AtomicClock>`
+        object to ``other``, positive when the object is in the past.
+
+        :param other: (optional) a ``datetime`` or :class:`AtomicClock <atomic_clock.AtomicClock>`.
+            Defaults to now.
+
+        Usage::
+            >>> now = AtomicClock.utcnow()
+            >>> now.shift(hours=-1).seconds_since(now)
+            3600.0
+        """
+    def seconds_until(self, other: dt.datetime | AtomicClock | None = None) -> float:
+        """Returns the seconds remaining from ``other`` to the :class:`AtomicClock
+        <atomic_clock.AtomicClock>` object, positive when the object is in the future.
+
+        :param other: (optional) a ``datetime`` or :class:`AtomicClock <atomic_clock.AtomicClock>`.
+            Defaults to now.
+
+        Usage::
+            >>> now = AtomicClock.utcnow()
+            >>> now.shift(minutes=5).seconds_until(now)
+            300.0
+        """
+    def years_since(self, other: dt.datetime | AtomicClock | None = None) -> int:
+        """Returns the whole calendar years elapsed from the :class:`AtomicClock
+        <atomic_clock.AtomicClock>` object to ``other``, positive when the object is in the
+        past.  A year counts once ``other`` reaches the object's month and day, both taken
+        in the object's timezone, so a birth date gives the age in years.
+
+        A February 29th anniversary is reached on March 1st in common years.
+
+        :param other: (optional) a ``datetime`` or :class:`AtomicClock <atomic_clock.AtomicClock>`.
+            Defaults to now.
+
+        Usage::
+            >>> birth = AtomicClock(1990, 6, 15)
+            >>> birth.years_since(AtomicClock(2022, 6, 14))
+            31
+            >>> birth.years_since(AtomicClock(2022, 6, 15))
+            32
+        """
+    def years_until(self, other: dt.datetime | AtomicClock | None = None) -> int:
+        """Returns the whole calendar years remaining from ``other`` to the
+        :class:`AtomicClock <atomic_clock.AtomicClock>` object, positive when the object is
+        in the future, same as ``-self.years_since(other)``.
+
+        :param other: (optional) a ``datetime`` or :class:`AtomicClock <atomic_clock.AtomicClock>`.
+            Defaults to now.
+
+        Usage::
+            >>> AtomicClock(2030, 1, 1).years_until(AtomicClock(2022, 6, 15))
+            7
+        """
+    def age(self, other: dt.datetime | AtomicClock | None = None) -> dt.timedelta:
+        """Returns the ``timedelta`` elapsed from the :class:`AtomicClock <atomic_clock.AtomicClock>`
+        object to ``other``, same as ``other - self``.
+
+        :param other: (optional) a ``datetime`` or :class:`AtomicClock <atomic_clock.AtomicClock>`.
+            Defaults to now.
+
+        Usage::
+            >>> now = AtomicClock.utcnow()
+            >>> now.shift(days=-2).age(now)
+            datetime.timedelta(days=2)
+        """
+    def diff(
+        self, other: dt.datetime | AtomicClock, *, assume_utc: bool = False
+    ) -> dt.timedelta:
+        """Returns ``self - other``.
+
+        Subtracting a naive ``datetime`` raises ``TypeError``, as it does between
+        ``datetime`` objects.  Set ``assume_utc`` to read a naive ``other`` as UTC instead.
+
+        :param other: a ``datetime`` or :class:`AtomicClock <atomic_clock.AtomicClock>`.
+        :param assume_utc: (optional) read a naive ``other`` as UTC.  Defaults to False.
+
+        Usage::
+            >>> clock = AtomicClock(2013, 5, 5, 12)
+            >>> clock - datetime(2013, 5, 5)
+            Traceback (most recent call last):
+            TypeError: can't subtract offset-naive and offset-aware datetimes
+            >>> clock.diff(datetime(2013, 5, 5), assume_utc=True)
+            datetime.timedelta(seconds=43200)
+        """
+    def diff_ns(
+        self, other: dt.datetime | AtomicClock, *, assume_utc: bool = False
+    ) -> int:
+        """Returns ``self - other`` in nanoseconds, where subtracting gives a ``timedelta``
+        truncated to microseconds.
+
+        :param other: a ``datetime`` or :class:`AtomicClock <atomic_clock.AtomicClock>`.
+        :param assume_utc: (optional) read a naive ``other`` as UTC, as in
+            :meth:`diff <atomic_clock.AtomicClock.diff>`.  Defaults to False.
+
+        Usage::
+            >>> start = atomic_clock.get('2022-01-01T00:00:00+00:00')
+            >>> end = atomic_clock.get('2022-01-01T00:00:00.000000500+00:00')
+            >>> end.diff_ns(start)
+            500
+            >>> end - start
+            datetime.timedelta(0)
+        """
+    def __format__(self, __format_spec: str) -> str:
+        """Formats the object in f-strings and ``str.format``.
+
+        An empty spec gives ``str(self)``.  A spec containing a ``%`` is read as strftime
+        directives.  As a spec can't pass ``tokens=True``, any other spec is read as
+        :meth:`format <atomic_clock.AtomicClock.format>` tokens, in which literal text must be
+        escaped with square brackets.  The names are English.
+
+        Usage::
+            >>> clock = AtomicClock(2013, 5, 5, 12, 30, 45)
+            >>> f"{clock:%Y-%m-%d}"
+            '2013-05-05'
+            >>> f"{clock:YYYY-MM-DD}"
+            '2013-05-05'
+            >>> f"{clock:[Today is] dddd}"
+            'Today is Sunday'
+        """
+    def __getnewargs_ex__(self) -> tuple[tuple[int, ...], dict[str, Any]]:
+        """Returns the constructor arguments used by ``pickle`` and ``copy``, subclasses pickle
+        their ``__dict__`` alongside.
+
+        Usage::
+            >>> AtomicClock(2022, 3, 15, 10, tzinfo="Asia/Shanghai").__getnewargs_ex__()
+            ((2022, 3, 15, 10, 0, 0, 0), {'tzinfo': 'Asia/Shanghai', 'fold': 0})
+        """
+    @property
+    def fold(self) -> Literal[0, 1]:
+        """Returns ``1`` if the :class:`AtomicClock <atomic_clock.AtomicClock>` object is the later
+        of two ambiguous wall times during a DST transition, otherwise ``0``.
+
+        Usage::
+            >>> ac = AtomicClock(2017, 11, 5, 6, 30).to('America/New_York')
+            >>> ac
+            <AtomicClock [2017-11-05T01:30:00-05:00]>
+            >>> ac.fold
+            1
         """
-    def __format__(self, __format_spec: str) -> str: ...
     @property
     def tzinfo(self) -> Tz:
         """Gets the ``atomic_clock.Tz`` of the :class:`AtomicClock <atomic_clock.AtomicClock>` object.
+        All UTC clocks share the same ``Tz`` object.
 
         Usage::
             >>> now = AtomicClock.now('Asia/Shanghai')
             >>> now.tzinfo
             <Tz [Asia/Shanghai]>
+            >>> AtomicClock.utcnow().tzinfo is AtomicClock(2022, 1, 1).tzinfo
+            True
         """
     @property
     def datetime(self) -> dt.datetime:
@@ -634,16 +1590,55 @@ class AtomicClock:
     @property
     def minute(self) -> int: ...
     @property
-    def second(self) -> int: ...
+    def second(self) -> int:
+        """The second, 60 during a leap second."""
     @property
     def microsecond(self) -> int: ...
     @property
+    def week(self) -> int: ...
+    @property
     def quarter(self) -> int: ...
+    @property
+    def start_of_day(self) -> AtomicClock:
+        """The :func:`floor <atomic_clock.AtomicClock.floor>` of the day in the clock's own
+        timezone, as an attribute for template engines.  ``start_of_week``, ``start_of_month``,
+        ``start_of_quarter`` and ``start_of_year`` follow, weeks start on Monday.
+
+        Usage::
+            >>> AtomicClock(2022, 3, 30, 14, 15, tzinfo='Asia/Tokyo').start_of_day
+            <AtomicClock [2022-03-30T00:00:00+09:00 Asia/Tokyo]>
+        """
+    @property
+    def end_of_day(self) -> AtomicClock:
+        """The :func:`ceil <atomic_clock.AtomicClock.ceil>` of the day in the clock's own
+        timezone, as an attribute for template engines.  ``end_of_week``, ``end_of_month``,
+        ``end_of_quarter`` and ``end_of_year`` follow, weeks start on Monday.
+
+        Usage::
+            >>> AtomicClock(2022, 3, 30, 14, 15, tzinfo='Asia/Tokyo').end_of_day
+            <AtomicClock [2022-03-30T23:59:59.999999+09:00 Asia/Tokyo]>
+        """
+    @property
+    def start_of_week(self) -> AtomicClock: ...
+    @property
+    def end_of_week(self) -> AtomicClock: ...
+    @property
+    def start_of_month(self) -> AtomicClock: ...
+    @property
+    def end_of_month(self) -> AtomicClock: ...
+    @property
+    def start_of_quarter(self) -> AtomicClock: ...
+    @property
+    def end_of_quarter(self) -> AtomicClock: ...
+    @property
+    def start_of_year(self) -> AtomicClock: ...
+    @property
+    def end_of_year(self) -> AtomicClock: ...
     def is_between(
         self,
         start: AtomicClock,
         end: AtomicClock,
-        bounds: Literal["[]", "()", "[)", "(]"] = "()",
+        bounds: Bounds = "()",
     ) -> bool:
         """Returns a boolean denoting whether the :class:`AtomicClock <atomic_clock.AtomicClock>` object is between
         the start and end limits.
@@ -655,6 +1650,19 @@ class AtomicClock:
             the start, '[' includes the start, ')' excludes the end, and ']' includes the end.
             If the bounds are not specified, the default bound '()' is used.
 
+        .. _bounds:
+
+        The bracket forms can also be spelled out:
+
+        ============================  ======
+        Alias                         Bounds
+        ============================  ======
+        ``"both"``                    '[]'
+        ``"neither"``                 '()'
+        ``"left"``, ``"start"``       '[)'
+        ``"right"``, ``"end"``        '(]'
+        ============================  ======
+
         Usage::
             >>> start = AtomicClock(2013, 5, 5, 12, 30, 10)
             >>> end = AtomicClock(2013, 5, 5, 12, 30, 36)
@@ -671,29 +1679,45 @@ class AtomicClock:
         """
     def span(
         self,
-        frame: Literal[
-            "year", "quarter", "month", "week", "day", "hour", "minute", "second"
-        ],
+        frame: Frame,
         *,
         count: int = 1,
-        bounds: Literal["[]", "()", "[)", "(]"] = "[)",
+        n: int = 1,
+        bounds: Bounds = "[)",
         exact: bool = False,
         week_start: Literal[1, 2, 3, 4, 5, 6, 7] = 1,
+        anchor: AtomicClock | dt.datetime | None = None,
     ) -> Tuple[AtomicClock, AtomicClock]:
         """Returns a tuple of two new :class:`AtomicClock <atomic_clock.AtomicClock>` objects, representing the timespan
         of the :class:`AtomicClock <atomic_clock.AtomicClock>` object in a given timeframe.
 
-        :param frame: the timeframe.  Can be any ``datetime`` property (day, hour, minute...).
+        :param frame: the timeframe.  Can be any ``datetime`` property (day, hour, minute...),
+            or one of its :ref:`aliases <frame-alias>`.
         :param count: (optional) the number of frames to span.
+        :param n: (optional) the size of each frame as a multiple of ``frame``, aligned to the
+            start of the parent frame, e.g. ``n=15`` with ``'minute'`` spans quarters of an hour.
+            ``n`` must evenly divide the parent frame: 60 for seconds and minutes, 24 for hours,
+            12 for months and 4 for quarters.  Years are aligned to multiples of ``n``, other frames
+            only accept ``n=1``.
         :param bounds: (optional) a ``str`` of either '()', '(]', '[)', or '[]' that specifies
             whether to include or exclude the start and end values in the span. '(' excludes
             the start, '[' includes the start, ')' excludes the end, and ']' includes the end.
-            If the bounds are not specified, the default bound '[)' is used.
+            If the bounds are not specified, the default bound '[)' is used.  The bounds may also
+            be one of their :ref:`aliases <bounds>`.
         :param exact: (optional) whether to have the start of the timespan begin exactly
             at the time specified by ``start`` and the end of the timespan truncated
             so as not to extend beyond ``end``.
         :param week_start: (optional) only used in combination with the week timeframe. Follows isoweekday() where
             Monday is 1 and Sunday is 7.
+        :param anchor: (optional) a datetime expression that the spans of ``count`` times
+            ``n`` frames are counted from instead of the calendar, e.g. ``'week'`` with
+            ``count=2`` spans fortnights starting on the anchor, and instants before it fall in
+            earlier fortnights.  Any ``n`` is accepted, ``week_start`` is ignored.  Days and weeks keep the anchor's
+            time of day on the wall clock.  Only day, week, hour, minute and second frames
+            support an anchor, and it can't be combined with ``exact``.
+
+        The returned floor is never greater than the ceil, a ``ValueError`` is raised when the
+        combination of ``count`` and ``bounds`` leaves no instant in the timespan.
 
         Usage::
 
@@ -717,40 +1741,195 @@ class AtomicClock:
 
             >>> atomic_clock.utcnow().span('week', week_start=6)
             (<AtomicClock [2022-03-26T00:00:00+00:00]>, <AtomicClock [2022-04-01T23:59:59.999999+00:00]>)
+
+            >>> atomic_clock.utcnow().span('minute', n=15)
+            (<AtomicClock [2022-03-30T14:30:00+00:00]>, <AtomicClock [2022-03-30T14:44:59.999999+00:00]>)
+
+            >>> atomic_clock.utcnow().span('week', count=2, anchor=atomic_clock.get('2022-01-03'))
+            (<AtomicClock [2022-03-28T00:00:00+00:00]>, <AtomicClock [2022-04-10T23:59:59.999999+00:00]>)
         """
     def floor(
         self,
-        frame: Literal[
-            "year", "quarter", "month", "week", "day", "hour", "minute", "second"
-        ],
+        frame: Frame,
+        *,
+        count: int = 1,
+        n: int = 1,
+        week_start: int = 1,
+        anchor: AtomicClock | dt.datetime | None = None,
     ) -> AtomicClock:
         """Returns a new :class:`AtomicClock <atomic_clock.AtomicClock>` object, representing the "floor"
         of the timespan of the :class:`AtomicClock <atomic_clock.AtomicClock>` object in a given timeframe.
         Equivalent to the first element in the 2-tuple returned by
         :func:`span <atomic_clock.AtomicClock.span>`.
 
-        :param frame: the timeframe.  Can be any ``datetime`` property (day, hour, minute...).
+        :param frame: the timeframe.  Can be any ``datetime`` property (day, hour, minute...),
+            or one of its :ref:`aliases <frame-alias>`.
+        :param n: (optional) a multiple of ``frame`` aligned to the start of the parent frame,
+            see :func:`span <atomic_clock.AtomicClock.span>`.
+        :param week_start: (optional) only used in combination with the week timeframe. Follows isoweekday() where
+            Monday is 1 and Sunday is 7.
+        :param count: (optional) the number of frames to span, see
+            :func:`span <atomic_clock.AtomicClock.span>`.
+        :param anchor: (optional) counts the spans of ``count`` times ``n`` frames from this
+            datetime instead of the calendar, see :func:`span <atomic_clock.AtomicClock.span>`.
 
         Usage::
             >>> atomic_clock.utcnow().floor('hour')
             <AtomicClock [2022-03-30T14:00:00+00:00]>
+            >>> atomic_clock.utcnow().floor('week', week_start=7)
+            <AtomicClock [2022-03-27T00:00:00+00:00]>
+            >>> atomic_clock.utcnow().floor('hour', n=6)
+            <AtomicClock [2022-03-30T12:00:00+00:00]>
+            >>> atomic_clock.utcnow().floor('week', count=2, anchor=atomic_clock.get('2022-01-03'))
+            <AtomicClock [2022-03-28T00:00:00+00:00]>
         """
     def ceil(
         self,
-        frame: Literal[
-            "year", "quarter", "month", "week", "day", "hour", "minute", "second"
-        ],
+        frame: Frame,
+        *,
+        count: int = 1,
+        n: int = 1,
+        week_start: int = 1,
+        anchor: AtomicClock | dt.datetime | None = None,
     ) -> AtomicClock:
         """Returns a new :class:`AtomicClock <atomic_clock.AtomicClock>` object, representing the "ceiling"
         of the timespan of the :class:`AtomicClock <atomic_clock.AtomicClock>` object in a given timeframe.
         Equivalent to the second element in the 2-tuple returned by
         :func:`span <atomic_clock.AtomicClock.span>`.
 
-        :param frame: the timeframe.  Can be any ``datetime`` property (day, hour, minute...).
+        :param frame: the timeframe.  Can be any ``datetime`` property (day, hour, minute...),
+            or one of its :ref:`aliases <frame-alias>`.
+        :param n: (optional) a multiple of ``frame`` aligned to the start of the parent frame,
+            see :func:`span <atomic_clock.AtomicClock.span>`.
+        :param week_start: (optional) only used in combination with the week timeframe. Follows isoweekday() where
+            Monday is 1 and Sunday is 7.
+        :param count: (optional) the number of frames to span, see
+            :func:`span <atomic_clock.AtomicClock.span>`.
+        :param anchor: (optional) counts the spans of ``count`` times ``n`` frames from this
+            datetime instead of the calendar, see :func:`span <atomic_clock.AtomicClock.span>`.
 
         Usage::
             >>> atomic_clock.utcnow().ceil('hour')
             <AtomicClock [2022-03-30T14:59:59.999999+00:00]>
+            >>> atomic_clock.utcnow().ceil('minute', n=15)
+            <AtomicClock [2022-03-30T14:44:59.999999+00:00]>
+        """
+    def at_start_of(
+        self,
+        frame: Frame,
+        *,
+        n: int = 1,
+        week_start: int = 1,
+    ) -> AtomicClock:
+        """An alias of :meth:`floor`.
+
+        Usage::
+            >>> AtomicClock(2022, 3, 15, 14, 30).at_start_of('month')
+            <AtomicClock [2022-03-01T00:00:00+00:00]>
+        """
+    def at_end_of(
+        self,
+        frame: Frame,
+        *,
+        n: int = 1,
+        week_start: int = 1,
+    ) -> AtomicClock:
+        """An alias of :meth:`ceil`.
+
+        Usage::
+            >>> AtomicClock(2022, 3, 15, 14, 30).at_end_of('month')
+            <AtomicClock [2022-03-31T23:59:59.999999+00:00]>
+        """
+    def timespan(
+        self,
+        frame: Frame,
+        *,
+        count: int = 1,
+        n: int = 1,
+        bounds: Bounds = "[)",
+        exact: bool = False,
+        week_start: Literal[1, 2, 3, 4, 5, 6, 7] = 1,
+    ) -> Span:
+        """Like :func:`span <atomic_clock.AtomicClock.span>`, but returns a :class:`Span`
+        with ``start`` and ``end`` attributes, which still unpacks as a 2-tuple.
+
+        Usage::
+            >>> day = AtomicClock(2022, 3, 30, 14, 35).timespan('day')
+            >>> day.start
+            <AtomicClock [2022-03-30T00:00:00+00:00]>
+            >>> day.end
+            <AtomicClock [2022-03-30T23:59:59.999999+00:00]>
+            >>> start, end = day
+        """
+    def first_of(
+        self,
+        frame: Literal["month", "quarter", "year"],
+        weekday: Literal[0, 1, 2, 3, 4, 5, 6] | Weekday | None = None,
+    ) -> AtomicClock:
+        """Returns a new :class:`AtomicClock <atomic_clock.AtomicClock>` object on the first day
+        of the month, quarter or year, at the same time of day.
+
+        :param frame: the timeframe, one of ``'month'``, ``'quarter'`` or ``'year'``, or one of
+            their :ref:`aliases <frame-alias>`.
+        :param weekday: (optional) the first of this weekday in the frame instead, where
+            Monday is 0 and Sunday is 6.
+
+        Usage::
+            >>> AtomicClock(2022, 8, 10, 8, 15).first_of('month')
+            <AtomicClock [2022-08-01T08:15:00+00:00]>
+            >>> AtomicClock(2022, 8, 10, 8, 15).first_of('quarter', weekday=0)
+            <AtomicClock [2022-07-04T08:15:00+00:00]>
+        """
+    def last_of(
+        self,
+        frame: Literal["month", "quarter", "year"],
+        weekday: Literal[0, 1, 2, 3, 4, 5, 6] | Weekday | None = None,
+    ) -> AtomicClock:
+        """Returns a new :class:`AtomicClock <atomic_clock.AtomicClock>` object on the last day
+        of the month, quarter or year, at the same time of day.
+
+        :param frame: the timeframe, one of ``'month'``, ``'quarter'`` or ``'year'``, or one of
+            their :ref:`aliases <frame-alias>`.
+        :param weekday: (optional) the last of this weekday in the frame instead, where
+            Monday is 0 and Sunday is 6.
+
+        Usage::
+            >>> AtomicClock(2022, 8, 10, 8, 15).last_of('month')
+            <AtomicClock [2022-08-31T08:15:00+00:00]>
+            >>> AtomicClock(2022, 8, 10, 8, 15).last_of('month', weekday=4)
+            <AtomicClock [2022-08-26T08:15:00+00:00]>
+        """
+    def last_of_month(self) -> AtomicClock:
+        """Same as :meth:`last_of('month') <atomic_clock.AtomicClock.last_of>`."""
+    def is_last_of_month(self) -> bool:
+        """Whether the clock is on the last day of its month, in its own timezone.
+
+        Usage::
+            >>> AtomicClock(2024, 2, 29).is_last_of_month()
+            True
+        """
+    def nth_of(
+        self,
+        frame: Literal["month", "quarter", "year"],
+        nth: int,
+        weekday: Literal[0, 1, 2, 3, 4, 5, 6] | Weekday,
+    ) -> AtomicClock | None:
+        """Returns a new :class:`AtomicClock <atomic_clock.AtomicClock>` object on the ``nth``
+        ``weekday`` of the month, quarter or year, at the same time of day, or ``None`` when the
+        frame doesn't have that many.
+
+        :param frame: the timeframe, one of ``'month'``, ``'quarter'`` or ``'year'``, or one of
+            their :ref:`aliases <frame-alias>`.
+        :param nth: counts from 1, negative ``nth`` count back from the end of the frame.
+        :param weekday: the weekday, where Monday is 0 and Sunday is 6.
+
+        Usage::
+            >>> AtomicClock(2022, 8, 10, 8, 15).nth_of('month', 3, weekday=3)
+            <AtomicClock [2022-08-18T08:15:00+00:00]>
+            >>> AtomicClock(2022, 8, 10, 8, 15).nth_of('month', -2, weekday=4)
+            <AtomicClock [2022-08-19T08:15:00+00:00]>
+            >>> AtomicClock(2022, 8, 10, 8, 15).nth_of('month', 5, weekday=4) is None
+            True
         """
     def timestamp(self) -> float:
         """Returns a timestamp representation of the :class:`AtomicClock <atomic_clock.AtomiClock>`
@@ -770,6 +1949,15 @@ class AtomicClock:
             1647928543
         """
     @property
+    def int_timestamp_ns(self) -> int:
+        """Returns an integer timestamp representation of the :class:`AtomicClock <atomic_clock.AtomiClock>`
+        object in nanoseconds, in UTC time.
+
+        Usage::
+            >>> AtomicClock.fromtimestamp_ns(1647924832531622123).int_timestamp_ns
+            1647924832531622123
+        """
+    @property
     def float_timestamp(self) -> float:
         """Returns a float timestamp representation of the :class:`AtomicClock <atomic_clock.AtomiClock>`
         object, in UTC time.
@@ -778,13 +1966,23 @@ class AtomicClock:
             >>> AtomicClock.utcnow().float_timestamp
             1647928591.746371
         """
-    # comparisons
+    # comparisons, strings are parsed like :func:`get`, unparsable ones are never equal
     def __eq__(self, other: object) -> bool: ...
     def __ne__(self, other: object) -> bool: ...
-    def __gt__(self, other: dt.datetime | AtomicClock) -> bool: ...
-    def __ge__(self, other: dt.datetime | AtomicClock) -> bool: ...
-    def __lt__(self, other: dt.datetime | AtomicClock) -> bool: ...
-    def __le__(self, other: dt.datetime | AtomicClock) -> bool: ...
+    def __hash__(self) -> int:
+        """The hash of the aware ``datetime`` of the same instant, so a clock and an equal
+        aware ``datetime`` are interchangeable in sets and dicts.  Naive datetimes and
+        strings compare equal to clocks but hash differently, don't mix them as keys.
+
+        Usage::
+            >>> clock = AtomicClock(2022, 1, 1)
+            >>> len({clock, datetime(2022, 1, 1, tzinfo=timezone.utc)})
+            1
+        """
+    def __gt__(self, other: dt.datetime | AtomicClock | str) -> bool: ...
+    def __ge__(self, other: dt.datetime | AtomicClock | str) -> bool: ...
+    def __lt__(self, other: dt.datetime | AtomicClock | str) -> bool: ...
+    def __le__(self, other: dt.datetime | AtomicClock | str) -> bool: ...
     # math
     def __add__(self, delta: dt.timedelta | RelativeDelta) -> AtomicClock: ...
     def __radd__(self, delta: dt.timedelta | RelativeDelta) -> AtomicClock: ...
@@ -794,6 +1992,156 @@ class AtomicClock:
     def __sub__(self, datetime: dt.datetime | AtomicClock) -> dt.timedelta: ...
     def __rsub__(self, datetime: dt.datetime | AtomicClock) -> dt.timedelta: ...
 
+class Span:
+    """The timespan returned by :meth:`timespan <atomic_clock.AtomicClock.timespan>`."""
+
+    @property
+    def start(self) -> AtomicClock: ...
+    @property
+    def end(self) -> AtomicClock: ...
+    def __iter__(self) -> Iterator[AtomicClock]: ...
+
+class DatetimeRangeIter(Iterator[AtomicClock]):
+    """The iterator returned by :meth:`range <atomic_clock.AtomicClock.range>`."""
+
+    def __next__(self) -> AtomicClock: ...
+    def __contains__(self, clock: object) -> bool: ...
+    def contains(self, clock: dt.datetime | AtomicClock) -> bool:
+        """Returns whether ``clock`` is one of the values of the range, whether it was
+        iterated over or not.  The membership is computed from the step of the frame and
+        the bounds of the range, without iterating, so unlike ``in`` on other iterators it
+        doesn't consume any value.
+
+        :param clock: a ``datetime`` or :class:`AtomicClock <atomic_clock.AtomicClock>`.
+
+        Usage::
+            >>> hours = AtomicClock.range('hour', AtomicClock(2022, 1, 1), limit=24)
+            >>> hours.contains(AtomicClock(2022, 1, 1, 12))
+            True
+            >>> AtomicClock(2022, 1, 1, 12, 30) in hours
+            False
+            >>> next(hours)
+            <AtomicClock [2022-01-01T00:00:00+00:00]>
+        """
+
+class Period:
+    """The time between two :class:`AtomicClock <atomic_clock.AtomicClock>` objects.
+
+    Iterating over a period yields its days, as ``period.range('day')``, and ``len``
+    counts them.
+
+    :param start: a ``datetime``, :class:`AtomicClock <atomic_clock.AtomicClock>` or an
+        ISO 8601 interval string, which stands for both the start and the end.
+    :param end: (optional) a ``datetime`` or :class:`AtomicClock <atomic_clock.AtomicClock>`,
+        not before ``start``.  Required unless ``start`` is an interval string.
+
+    Usage::
+        >>> period = Period(AtomicClock(2022, 1, 1), AtomicClock(2022, 1, 5, 12))
+        >>> len(period)
+        5
+        >>> AtomicClock(2022, 1, 3) in period
+        True
+    """
+
+    def __init__(
+        self,
+        start: AtomicClock | dt.datetime | str,
+        end: AtomicClock | dt.datetime | None = None,
+    ) -> None: ...
+    @property
+    def start(self) -> AtomicClock: ...
+    @property
+    def end(self) -> AtomicClock: ...
+    def __iter__(self) -> DatetimeRangeIter: ...
+    def __len__(self) -> int: ...
+    def __contains__(self, clock: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def range(self, frame: Frame) -> DatetimeRangeIter:
+        """Returns an iterator of :class:`AtomicClock <atomic_clock.AtomicClock>` objects
+        stepping by ``frame`` from the start to the end of the period, as
+        :meth:`AtomicClock.range <atomic_clock.AtomicClock.range>`.
+
+        :param frame: the timeframe.  Can be any ``datetime`` property (day, hour, minute...).
+
+        Usage::
+            >>> period = Period(AtomicClock(2022, 1, 1), AtomicClock(2022, 1, 1, 2))
+            >>> list(period.range('hour'))
+            [<AtomicClock [2022-01-01T00:00:00+00:00]>, <AtomicClock [2022-01-01T01:00:00+00:00]>, <AtomicClock [2022-01-01T02:00:00+00:00]>]
+        """
+    def contains(self, clock: AtomicClock | dt.datetime, bounds: Bounds = "[]") -> bool:
+        """Returns whether ``clock`` is within the period.
+
+        :param clock: a ``datetime`` or :class:`AtomicClock <atomic_clock.AtomicClock>`.
+        :param bounds: (optional) a ``str`` of either '()', '(]', '[)', or '[]' that specifies
+            whether to include or exclude the start and end of the period.  Both are
+            included by default, as for ``in``.
+
+        Usage::
+            >>> period = Period(AtomicClock(2022, 1, 1), AtomicClock(2022, 1, 5))
+            >>> period.contains(AtomicClock(2022, 1, 5))
+            True
+            >>> period.contains(AtomicClock(2022, 1, 5), '[)')
+            False
+        """
+    def in_weeks(self) -> int:
+        """Returns the number of whole weeks elapsed in the period."""
+    def in_days(self) -> int:
+        """Returns the number of whole days elapsed in the period.
+
+        Usage::
+            >>> Period(AtomicClock(2022, 1, 1), AtomicClock(2022, 1, 5, 12)).in_days()
+            4
+        """
+    def in_hours(self) -> int:
+        """Returns the number of whole hours elapsed in the period."""
+    def in_minutes(self) -> int:
+        """Returns the number of whole minutes elapsed in the period."""
+    def in_seconds(self) -> int:
+        """Returns the number of whole seconds elapsed in the period."""
+
+class Stopwatch:
+    """Measures elapsed time on a monotonic clock, unaffected by changes to the system
+    clock.  A new stopwatch isn't started.
+
+    Usage::
+        >>> sw = Stopwatch().start()
+        >>> do_work()
+        >>> sw.stop()
+        datetime.timedelta(microseconds=50146)
+    """
+
+    def __init__(self) -> None: ...
+    @property
+    def running(self) -> bool:
+        """Whether the stopwatch is started and not stopped."""
+    def start(self) -> Stopwatch:
+        """Starts counting from zero and returns the stopwatch.  A running or stopped
+        stopwatch is restarted."""
+    def stop(self) -> dt.timedelta:
+        """Freezes and returns the elapsed time.  Stopping again keeps the first stop.
+
+        :raises ValueError: if the stopwatch isn't started.
+        """
+    def elapsed(self) -> dt.timedelta:
+        """Returns the time elapsed since the start, up to the stop if stopped.  Zero
+        before the stopwatch is started."""
+    def elapsed_ns(self) -> int:
+        """Returns :meth:`elapsed` in nanoseconds."""
+    def elapsed_since(self, other: Stopwatch | float) -> dt.timedelta:
+        """Returns the time from the start of ``other``, or a :func:`monotonic` reading, to
+        the start of this stopwatch.
+
+        :raises ValueError: if either stopwatch isn't started, or the reading isn't
+            finite.
+        :raises OverflowError: if the elapsed time is out of range.
+
+        Usage::
+            >>> first = Stopwatch().start()
+            >>> second = Stopwatch().start()
+            >>> second.elapsed_since(first)
+            datetime.timedelta(microseconds=13)
+        """
+
 class Tz(dt.tzinfo):
     """A :class: `Tz <atomic_clock.Tz>` object
 
@@ -805,11 +2153,123 @@ class Tz(dt.tzinfo):
 
     Recognized timezone expressions:
         - A ``str`` describing a timezone, similar to 'US/Pacific', or 'Asia/Shanghai'.
-        - A ``str`` in ISO 8601 style, as in '+07:00'.
+        - A ``str`` in ISO 8601 style, as in '+07', '+0700' or '+07:00', within ±24 hours.
+        - A POSIX TZ ``str`` with DST rules, as in 'EST5EDT,M3.2.0,M11.1.0'. Note that
+          POSIX offsets are positive west of UTC, 'EST5' is '-05:00'.
         - A ``str``, one of the following:  'local', 'utc', 'UTC'.
     """
 
-    def __init__(self, tzinfo: str) -> None: ...
+    def __init__(self, tzinfo: TzLike) -> None: ...
+    def tzname(self, dt: dt.datetime | None = None) -> str | None:
+        """Returns the abbreviation in effect at the wall time ``dt``, as ``%Z`` shows it,
+        or the name of the zone without ``dt``.  A fixed offset has no name, its
+        abbreviation is the offset.
+
+        A ``Tz`` is a complete ``tzinfo``: ``fromutc`` sets the ``fold`` of the later of two
+        repeated wall times, and a ``datetime`` in it can be pickled.
+
+        Usage::
+            >>> Tz('America/New_York').tzname(dt.datetime(2022, 7, 1))
+            'EDT'
+            >>> Tz('America/New_York').tzname()
+            'America/New_York'
+        """
+    def key_at(self, datetime: dt.datetime | AtomicClock | None = None) -> int:
+        """Returns the offset from UTC in seconds at ``datetime``, or now, for sorting zones
+        explicitly.
+
+        :param datetime: (optional) the instant of the offset.  Defaults to now.
+
+        Usage::
+            >>> zones = [Tz('Asia/Tokyo'), Tz('UTC'), Tz('America/New_York')]
+            >>> sorted(zones, key=lambda tz: tz.key_at(AtomicClock(2022, 1, 1)))
+            [<Tz [America/New_York]>, <Tz [UTC]>, <Tz [Asia/Tokyo]>]
+        """
+    def transitions(
+        self, start_year: int, end_year: int | None = None
+    ) -> List[Tuple[AtomicClock, int, int]]:
+        """Returns the changes of the offset from UTC from the start of ``start_year`` to
+        the end of ``end_year``, in UTC, as ``(instant, offset_before, offset_after)``
+        tuples with the offsets in seconds.  Empty for a fixed offset.
+
+        The changes of a named zone come from the tz database.  The offsets of a POSIX
+        TZ string are probed a day apart, then bisected to the second, so two changes
+        within a single day are missed.
+
+        :param start_year: the first year, within 1..9999.
+        :param end_year: (optional) the last year, not before ``start_year``.  Defaults to
+            ``start_year``.
+
+        Usage::
+            >>> Tz('America/New_York').transitions(2022)
+            [(<AtomicClock [2022-03-13T03:00:00-04:00 America/New_York]>, -18000, -14400), (<AtomicClock [2022-11-06T01:00:00-05:00 America/New_York]>, -14400, -18000)]
+        """
+    def next_transition(
+        self, after: dt.datetime | AtomicClock | None = None
+    ) -> Tuple[AtomicClock, int, int] | None:
+        """Returns the first change of the offset from UTC after ``after``, as
+        :meth:`transitions`, or None if there's none before ``AtomicClock.max``.
+
+        **NOTE**: a zone without any further change is probed up to ``AtomicClock.max``,
+        which takes tens of milliseconds.
+
+        :param after: (optional) the instant to search from.  Defaults to now.
+
+        Usage::
+            >>> Tz('America/New_York').next_transition(AtomicClock(2022, 6, 1))
+            (<AtomicClock [2022-11-06T01:00:00-05:00 America/New_York]>, -14400, -18000)
+        """
+    def prev_transition(
+        self, before: dt.datetime | AtomicClock | None = None
+    ) -> Tuple[AtomicClock, int, int] | None:
+        """Returns the last change of the offset from UTC before ``before``, as
+        :meth:`next_transition`, or None if there's none after ``AtomicClock.min``.
+
+        :param before: (optional) the instant to search from.  Defaults to now.
+
+        Usage::
+            >>> Tz('America/New_York').prev_transition(AtomicClock(2022, 6, 1))
+            (<AtomicClock [2022-03-13T03:00:00-04:00 America/New_York]>, -18000, -14400)
+        """
+    def __eq__(self, other: object) -> bool:
+        """Named zones are equal to the same zone, other zones to any zone with the same
+        current offset from UTC.  Any other ``tzinfo`` is compared by its ``utcoffset``,
+        a ``zoneinfo.ZoneInfo`` is also equal to the named zone of its ``key``.
+
+        Usage::
+            >>> Tz('UTC') == datetime.timezone.utc
+            True
+            >>> Tz('Asia/Shanghai') == datetime.timezone(datetime.timedelta(hours=8))
+            True
+        """
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: dt.tzinfo) -> bool:
+        """Zones, and other ``tzinfo`` objects, are ordered by their current offset from UTC.
+
+        **NOTE**: the order of zones observing DST depends on the instant, use :meth:`key_at`
+        to sort them at an explicit one.
+        """
+    def __le__(self, other: dt.tzinfo) -> bool: ...
+    def __gt__(self, other: dt.tzinfo) -> bool: ...
+    def __ge__(self, other: dt.tzinfo) -> bool: ...
+    def __sub__(self, other: Tz) -> dt.timedelta:
+        """Returns the difference between the current offsets of both zones.
+
+        Usage::
+            >>> Tz('Asia/Tokyo') - Tz('UTC')
+            datetime.timedelta(seconds=32400)
+        """
+
+TzLike: TypeAlias = Union[str, dt.tzinfo, Tz]
+"""A :ref:`timezone expression <tz-expr>`, accepted wherever a timezone is expected."""
+
+UTC: Tz
+"""The UTC :class:`Tz`, the same object as the ``tzinfo`` of every UTC clock, so
+``clock.tzinfo is atomic_clock.UTC`` holds for them."""
+
+LOCAL: Tz
+"""The local timezone as a :class:`Tz`, with the local offset looked up on every access.
+Pass the ``'local'`` string instead to follow DST transitions of the converted instant."""
 
 def utcnow() -> AtomicClock:
     """Calls the default :class:`AtomicClock <atomic_clock.AtomicClock>` ``utcnow`` staticmethod.
@@ -820,7 +2280,29 @@ def utcnow() -> AtomicClock:
         <AtomicClock [2022-03-26T14:21:50.255157+00:00]>
     """
 
-def now(tz: str | dt.tzinfo | Tz = "local") -> AtomicClock:
+def locales() -> list[str]:
+    """Returns the names of the locales available to :meth:`AtomicClock.format` and
+    :meth:`AtomicClock.humanize`.
+
+    Regional names like ``"en_US"`` or ``"fr-CA"`` fall back to their language.
+
+    Usage::
+        >>> atomic_clock.locales()
+        ['en', 'es', 'fr']
+    """
+
+def monotonic() -> float:
+    """Returns the seconds elapsed on a monotonic clock since ``atomic_clock`` was
+    imported.  Unlike :func:`utcnow`, it never goes backwards when the system clock is
+    changed, so only the difference of two readings is meaningful.
+
+    Usage::
+        >>> start = atomic_clock.monotonic()
+        >>> atomic_clock.monotonic() - start
+        1.2e-05
+    """
+
+def now(tz: TzLike = "local") -> AtomicClock:
     """Calls the default :class:`AtomicClock <atomic_clock.AtomicClock>` ``now`` staticmethod.
 
     :param tz: (optional) A :ref:`timezone expression <tz-expr>`.  Defaults to local time.
@@ -837,8 +2319,84 @@ def now(tz: str | dt.tzinfo | Tz = "local") -> AtomicClock:
         <AtomicClock [2022-03-26T22:19:55.370497+08:00]>
     """
 
+def parse_interval(interval: str) -> Tuple[AtomicClock, AtomicClock]:
+    """Parses an ISO 8601 interval, ``start/end``, ``start/duration`` or ``duration/end``, into
+    its start and end.  Durations are ``PnYnMnWnDTnHnMnS``, where only the seconds may have a
+    fraction of up to 9 digits.  Endpoints without an offset are in UTC, like :func:`get`.
+
+    An endpoint may leave out trailing components, such as ``2022-01`` or ``2022-01-01T10``,
+    which stand for the start of their unit.  When the end is coarser than the start it stands
+    for the end of its unit instead.
+
+    :raises ParserError: if the interval is malformed.
+
+    Usage::
+        >>> atomic_clock.parse_interval('2022-01-01/P1M')
+        (<AtomicClock [2022-01-01T00:00:00+00:00]>, <AtomicClock [2022-02-01T00:00:00+00:00]>)
+        >>> atomic_clock.parse_interval('2022-01-15/2022-03')
+        (<AtomicClock [2022-01-15T00:00:00+00:00]>, <AtomicClock [2022-03-31T23:59:59.999999+00:00]>)
+        >>> list(AtomicClock.range('month', '2022-01/2022-03'))
+        [<AtomicClock [2022-01-01T00:00:00+00:00]>, <AtomicClock [2022-02-01T00:00:00+00:00]>, <AtomicClock [2022-03-01T00:00:00+00:00]>]
+    """
+
+def tzdata_version() -> str:
+    """Returns the release of the IANA tz database the named timezones are built from,
+    embedded when ``atomic_clock`` was compiled.
+
+    Usage::
+        >>> atomic_clock.tzdata_version()
+        '2021e'
+    """
+
+def register_timezone(
+    name: str,
+    offset_or_rules: int
+    | dt.timedelta
+    | list[Tuple[AtomicClock | dt.datetime, int | dt.timedelta]],
+) -> None:
+    """Installs a timezone resolvable by ``name`` wherever a
+    :ref:`timezone expression <tz-expr>` is accepted, for the rest of the process.  It takes
+    precedence over the tz database, so a zone can be patched before a new release ships.
+    Registering a name again replaces the zone.
+
+    :param name: the name of the zone, ``'utc'``, ``'UTC'`` and ``'local'`` are reserved.
+    :param offset_or_rules: a fixed offset, in seconds east of UTC or as a timedelta, or a
+        list of ``(instant, offset)`` pairs in increasing order of their instants, from which
+        each offset applies.  The first offset also applies before its instant.  Naive
+        datetimes are in UTC.
+    :raises ValueError: if the name is reserved, an offset isn't whole seconds within a day,
+        or the rules are empty or out of order.
+
+    Usage::
+        >>> atomic_clock.register_timezone('X/Zone', [
+        ...     (AtomicClock(2000, 1, 1), 3600),
+        ...     (AtomicClock(2022, 3, 1), 7200),
+        ... ])
+        >>> AtomicClock(2022, 6, 1, tzinfo='X/Zone')
+        <AtomicClock [2022-06-01T00:00:00+02:00 X/Zone]>
+    """
+
+def unregister_timezone(name: str) -> None:
+    """Removes a timezone installed by :func:`register_timezone`, so that the name resolves
+    as before.  Clocks already in the zone keep it.
+
+    :raises TimezoneError: if no timezone is registered as ``name``.
+    """
+
+def custom_timezones() -> list[str]:
+    """Returns the sorted names of the timezones installed by :func:`register_timezone`.
+
+    Usage::
+        >>> atomic_clock.custom_timezones()
+        ['X/Zone']
+    """
+
+@overload
+def get(*, tzinfo: TzLike | None = None) -> AtomicClock: ...
+@overload
+def get(__none: None, /, *, tzinfo: TzLike | None = None) -> None: ...
 @overload
-def get(__tzinfo: dt.tzinfo | Tz | None = None, /) -> AtomicClock: ...
+def get(__tzinfo: dt.tzinfo | Tz, /) -> AtomicClock: ...
 @overload
 def get(
     __obj: Union[
@@ -848,15 +2406,23 @@ def get(
         int,
         float,
         str,
-        Tuple[int, int, int],
+        bytes,
+        bytearray,
+        struct_time,
+        Tuple[Any, ...],
+        list[Any],
     ],
     /,
     *,
-    tzinfo: dt.tzinfo | Tz | str | None = None,
+    tzinfo: TzLike | None = None,
 ) -> AtomicClock: ...
 @overload
 def get(
-    __arg1: dt.datetime | dt.date, __tz: dt.tzinfo | Tz | str, /
+    __arg1: dt.datetime | dt.date, __tz: TzLike, /
+) -> AtomicClock: ...
+@overload
+def get(
+    __date: dt.date, __time: dt.time, /, *, tzinfo: TzLike | None = None
 ) -> AtomicClock: ...
 @overload
 def get(__datetime_str: str, __fmt: str, /) -> AtomicClock: ...
@@ -869,15 +2435,20 @@ def get(
     __minute: int = 0,
     __second: int = 0,
     __microsecond: int = 0,
-    __tzinfo: dt.tzinfo | Tz | str | None = None,
+    __tzinfo: TzLike | None = None,
     /,
+    *,
+    tzinfo: TzLike | None = None,
 ) -> AtomicClock: ...
-def get(self, *args: Any, **kwargs: Any) -> AtomicClock:  # type: ignore
+def get(self, *args: Any, **kwargs: Any) -> AtomicClock | None:  # type: ignore
     """Returns an :class:`AtomicClock <atomic_clock.AtomicClock>` object based on flexible inputs.
 
     :param tzinfo: (optional) a :ref:`timezone expression <tz-expr>` or tzinfo object.
         Replaces the timezone unless using an input form that is explicitly UTC or specifies
         the timezone in a positional argument. Defaults to UTC.
+    :raises TypeError: if the arguments are of a type none of the forms below accept, such as
+        a ``dict``.
+    :raises ParserError: if the arguments have a supported type but can't be parsed.
 
     Usage::
 
@@ -888,6 +2459,11 @@ def get(self, *args: Any, **kwargs: Any) -> AtomicClock:  # type: ignore
         >>> atomic_clock.get()
         <AtomicClock [2022-03-27T04:39:39.961630+00:00]>
 
+    **One** ``None``, passed through so a nullable column can be mapped without a check::
+
+        >>> atomic_clock.get(None) is None
+        True
+
     **One** :class:`AtomicClock <atomic_clock.AtomicClock>` object, to get a copy.
 
         >>> now = atomic_clock.utcnow()
@@ -913,6 +2489,45 @@ def get(self, *args: Any, **kwargs: Any) -> AtomicClock:  # type: ignore
         >>> atomic_clock.get('20160413T133656.456289')
         <AtomicClock [2016-04-13T13:36:56.456289+00:00]>
 
+    **One** date-only or space-separated ``str``, the missing fields default to midnight::
+
+        >>> atomic_clock.get('2022-03-15')
+        <AtomicClock [2022-03-15T00:00:00+00:00]>
+        >>> atomic_clock.get('2022-03-15 10:30')
+        <AtomicClock [2022-03-15T10:30:00+00:00]>
+
+    **One** ISO week date ``str``, ``YYYY-Www`` or ``YYYY-Www-D``, to get the Monday (or
+    the given weekday) of that week at midnight::
+
+        >>> atomic_clock.get('2022-W11')
+        <AtomicClock [2022-03-14T00:00:00+00:00]>
+        >>> atomic_clock.get('2022-W11-2', tzinfo='Asia/Tokyo')
+        <AtomicClock [2022-03-15T00:00:00+09:00 Asia/Tokyo]>
+
+    A week the year doesn't have, such as ``2021-W53``, raises a
+    :class:`ParserError <atomic_clock.ParserError>`.
+
+    A ``str`` is matched against these formats in order, then as RFC 2822 and HTTP-date:
+    ``%Y-%m-%dT%H:%M:%S%.f%z``, ``%Y-%m-%dT%H:%M:%S%.f``, ``%Y%m%dT%H%M%S%.f``,
+    ``%Y%m%dT%H%M%S%.f%z``, ``%Y-%m-%d``, ``%Y-%m-%d %H:%M:%S%.f``, ``%Y-%m-%d %H:%M``,
+    ``%Y/%m/%d`` and ``%Y%m%d``.  The :class:`ParserError <atomic_clock.ParserError>` of
+    a ``str`` none of them match lists them all.
+
+    **One** RFC 2822-formatted ``str``, to parse it::
+
+        >>> atomic_clock.get('Tue, 15 Mar 2022 10:30:00 +0800')
+        <AtomicClock [2022-03-15T10:30:00+08:00]>
+
+    **One** HTTP-date ``str``, to parse it::
+
+        >>> atomic_clock.get('Sunday, 06-Nov-94 08:49:37 GMT')
+        <AtomicClock [1994-11-06T08:49:37+00:00]>
+
+    **One** ``bytes`` or ``bytearray``, decoded as UTF-8 and parsed like a ``str``::
+
+        >>> atomic_clock.get(b'2022-01-01T00:00:00Z')
+        <AtomicClock [2022-01-01T00:00:00+00:00]>
+
     **One** ``tzinfo``, to get the current time **converted** to that timezone::
 
         >>> atomic_clock.get(Tz("local"))
@@ -933,10 +2548,20 @@ def get(self, *args: Any, **kwargs: Any) -> AtomicClock:  # type: ignore
         >>> atomic_clock.get(date(2022, 1, 1))
         <AtomicClock [2022-01-01T00:00:00+00:00]>
 
-    **One** iso calendar ``tuple``, to get that week date in UTC::
+    **One** ``time.struct_time``, to get that time in its ``tm_gmtoff`` offset, or in UTC
+    if it's not available, see :meth:`fromstructtime <atomic_clock.AtomicClock.fromstructtime>`
+    to read it in another timezone::
+
+        >>> atomic_clock.get(time.gmtime(0))
+        <AtomicClock [1970-01-01T00:00:00+00:00]>
+
+    **One** ``tuple`` or ``list`` of 3 to 8 elements, in the same order as the positional
+    arguments ``(year, month, day[, hour, minute, second, microsecond, tzinfo])``::
 
         >>> atomic_clock.get((2022, 2, 2))
         <AtomicClock [2022-02-02T00:00:00+00:00]>
+        >>> atomic_clock.get([2022, 2, 2, 10, 30, 0, 0, 'Asia/Shanghai'])
+        <AtomicClock [2022-02-02T10:30:00+08:00]>
 
     **Two** arguments, a naive or aware ``datetime``, and a replacement
 
@@ -952,6 +2577,13 @@ def get(self, *args: Any, **kwargs: Any) -> AtomicClock:  # type: ignore
         >>> atomic_clock.get(date(2022, 1, 1), 'US/Pacific')
         <AtomicClock [2022-01-01T00:00:00-07:00]>
 
+    **Two** arguments, a ``date`` and a ``time``, combined on the wall clock of the
+    ``tzinfo`` keyword, the time's own timezone, or UTC, see
+    :meth:`combine <atomic_clock.AtomicClock.combine>`::
+
+        >>> atomic_clock.get(date(2022, 1, 1), time(10, 30), tzinfo='Europe/Paris')
+        <AtomicClock [2022-01-01T10:30:00+01:00 Europe/Paris]>
+
     **Two** arguments, both ``str``, to parse the first according to the format of the second::
 
         >>> atomic_clock.get('2022-01-05 12:30:45 +0800', '%Y-%m-%d %H:%M:%S %z')
@@ -963,6 +2595,63 @@ def get(self, *args: Any, **kwargs: Any) -> AtomicClock:  # type: ignore
         <AtomicClock [2022-01-05T19:04:08+00:00]>
     """
 
+def try_get(
+    *args: Any, tzinfo: TzLike | None = None, default: Any = None
+) -> AtomicClock | Any:
+    """Same as :func:`get`, but returns ``default`` instead of raising when the inputs can't be
+    parsed.  The failure path doesn't build a Python exception, so it's cheaper than calling
+    :func:`get` in a ``try``/``except``.
+
+    :param tzinfo: (optional) same as for :func:`get`.
+    :param default: (optional) the value returned on failure.  Defaults to ``None``.  A
+        ``None`` input isn't a failure, it's passed through as for :func:`get`.
+
+    Usage::
+
+        >>> import atomic_clock
+        >>> atomic_clock.try_get('2013-09-29T01:26:43.830580')
+        <AtomicClock [2013-09-29T01:26:43.830580+00:00]>
+        >>> atomic_clock.try_get('not a date') is None
+        True
+        >>> atomic_clock.try_get('not a date', default=0)
+        0
+    """
+
+@overload
+def get_safe(
+    __none: None,
+    /,
+    *,
+    tzinfo: TzLike | None = None,
+    on_unknown_tz: Literal["error", "utc"] = "error",
+) -> None: ...
+@overload
+def get_safe(
+    *args: Any,
+    tzinfo: TzLike | None = None,
+    on_unknown_tz: Literal["error", "utc"] = "error",
+) -> AtomicClock: ...
+def get_safe(  # type: ignore
+    *args: Any,
+    tzinfo: TzLike | None = None,
+    on_unknown_tz: Literal["error", "utc"] = "error",
+) -> AtomicClock | None:
+    """Same as :func:`get`, but unknown timezone names can be replaced by UTC, so that a
+    batch import isn't aborted by a single bad zone.
+
+    :param tzinfo: (optional) same as for :func:`get`.
+    :param on_unknown_tz: (optional) ``"error"`` raises on unknown timezone names like
+        :func:`get`, ``"utc"`` uses UTC instead and issues a ``UserWarning``.  Applies to
+        ``tzinfo`` and to timezone names passed positionally.  Defaults to ``"error"``.
+
+    Usage::
+
+        >>> import atomic_clock
+        >>> atomic_clock.get_safe('2013-09-29T01:26:43', tzinfo='Bogus/Zone', on_unknown_tz='utc')
+        UserWarning: unknown timezone `Bogus/Zone`, using UTC instead
+        <AtomicClock [2013-09-29T01:26:43+00:00]>
+    """
+
 class RelativeDelta:
     def __init__(
         self,
@@ -977,5 +2666,21 @@ class RelativeDelta:
         quarters: int = 0,
         weekday: Literal[0, 1, 2, 4, 5, 6] | Weekday | None = None,
     ) -> None: ...
+    years: int
+    months: int
+    days: int
+    hours: int
+    minutes: int
+    seconds: int
+    microseconds: int
+    weeks: int
+    quarters: int
+    weekday: int | None
+    def __eq__(self, other: object) -> bool:
+        """Deltas are equal when every field is, ``weeks=1`` isn't equal to ``days=7``."""
+    def __ne__(self, other: object) -> bool: ...
+    def __hash__(self) -> int:
+        """The hash of the tuple of fields.  Fields are settable, don't change a delta
+        that is a set member or a dict key."""
     def __neg__(self) -> RelativeDelta: ...
     def clone(self) -> RelativeDelta: ...
@@ -1,36 +1,447 @@
-use std::{
-    ops::{Div, Mul},
-    vec,
-};
+use std::{cell::OnceCell, vec};
 
 use chrono::{
-    DateTime, Datelike, Duration, Local, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeZone,
-    Timelike, Utc,
+    DateTime, Datelike, Duration, FixedOffset, Local, LocalResult, NaiveDate, NaiveDateTime,
+    NaiveTime, Offset, TimeZone, Timelike, Utc, Weekday,
 };
 use pyo3::{
     exceptions,
     prelude::*,
     pyclass::CompareOp,
-    types::{PyDate, PyDateAccess, PyDateTime, PyDelta, PyTime, PyTimeAccess, PyTuple, PyTzInfo},
+    types::{
+        PyByteArray, PyBytes, PyDate, PyDateAccess, PyDateTime, PyDelta, PyDeltaAccess, PyDict,
+        PyList, PyString, PyTime, PyTimeAccess, PyTuple, PyTzInfo,
+    },
 };
 use relativedelta::RelativeDelta;
-use rust_decimal::{
-    prelude::{FromPrimitive, ToPrimitive},
-    Decimal,
-};
 
-use crate::hybrid_tz::{HybridTz, PyTz, PyTzLike, UTC, UTC_NOW};
+use crate::{
+    errors::{ParserError, RangeError, TimezoneError},
+    formatter::{self, Timespec},
+    hybrid_tz::{utc_tzinfo, HybridTz, PyTz, PyTzLike, UTC},
+    locales::{self, Timeframe},
+};
 
 const MIN_ORDINAL: i64 = 1;
 const MAX_ORDINAL: i64 = 3652059;
 
+const NANOS_PER_DAY: i128 = 86_400_000_000_000;
+/// Nanoseconds from the Julian date epoch, -4713-11-24T12:00:00 UTC, to the Unix epoch.
+const JULIAN_EPOCH_NANOS: i128 = 2_440_587 * NANOS_PER_DAY + NANOS_PER_DAY / 2;
+/// Nanoseconds from the Modified Julian date epoch, 1858-11-17T00:00:00 UTC, to the Unix epoch.
+const MODIFIED_JULIAN_EPOCH_NANOS: i128 = 40_587 * NANOS_PER_DAY;
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+/// Nanoseconds from the GPS epoch, 1980-01-06T00:00:00 UTC, to the Unix epoch, which is
+/// before it.  Leap seconds are ignored, as for Unix timestamps.
+const GPS_EPOCH_NANOS: i128 = -3_657 * NANOS_PER_DAY;
+
+lazy_static! {
+    /// The earliest wall time a clock can be shifted to, as `datetime.min`.
+    pub(crate) static ref MIN_NAIVE_DATETIME: NaiveDateTime = NaiveDate::from_ymd(1, 1, 1).and_hms(0, 0, 0);
+    /// The latest wall time a clock can be shifted to, as `datetime.max`.
+    pub(crate) static ref MAX_NAIVE_DATETIME: NaiveDateTime =
+        NaiveDate::from_ymd(9999, 12, 31).and_hms_micro(23, 59, 59, 999_999);
+}
+
 #[pyclass(subclass, module = "atomic_clock")]
 #[pyo3(
-    text_signature = "(year, month, day, hour = 0, minute = 0, second = 0, microsecond = 0, tzinfo = \"utc\")"
+    text_signature = "(year, month, day, hour = 0, minute = 0, second = 0, microsecond = 0, tzinfo = \"utc\", *, fold = 0, nanosecond = None)"
 )]
 #[derive(Clone)]
 pub struct AtomicClock {
     datetime: DateTime<HybridTz>,
+    // derived values are cached lazily, which is sound because the datetime never changes
+    timestamp_nanos: OnceCell<i128>,
+    iso_calendar: OnceCell<(u32, u32, u32)>,
+    hash: OnceCell<isize>,
+    // `str()` is called on every logged clock, so its text is only built once
+    text: OnceCell<Py<PyString>>,
+}
+
+impl From<DateTime<HybridTz>> for AtomicClock {
+    fn from(datetime: DateTime<HybridTz>) -> Self {
+        Self {
+            datetime,
+            timestamp_nanos: OnceCell::new(),
+            iso_calendar: OnceCell::new(),
+            hash: OnceCell::new(),
+            text: OnceCell::new(),
+        }
+    }
+}
+
+impl AtomicClock {
+    /// The RFC 3339 text of `str()`.
+    fn to_str(&self) -> String {
+        formatter::format_iso(&self.datetime, "T", Timespec::Auto)
+            .unwrap_or_else(|| self.datetime.to_rfc3339())
+    }
+
+    /// The nanoseconds since the epoch, in i128 as chrono's i64 nanoseconds wrap outside
+    /// the years 1677 to 2262.
+    fn timestamp_nanos(&self) -> i128 {
+        *self
+            .timestamp_nanos
+            .get_or_init(|| timestamp_nanos(&self.datetime))
+    }
+
+    fn iso_calendar(&self) -> (u32, u32, u32) {
+        *self
+            .iso_calendar
+            .get_or_init(|| iso_calendar(&self.datetime))
+    }
+
+    /// Moves a range bound to `tz`, either converting the instant or, with `wall_time`,
+    /// keeping the wall time and reinterpreting it in `tz`.
+    fn with_range_tz(self, tz: Option<PyTzLike>, wall_time: bool) -> PyResult<Self> {
+        match tz {
+            Some(tz) if wall_time => self.at_wall_time(
+                self.datetime.naive_local(),
+                Some(TzLikeOrOffset::TzLike(tz)),
+                self.fold(),
+            ),
+            Some(tz) => self.to(tz),
+            None => Ok(self),
+        }
+    }
+
+    /// The wall time the span of `frame` containing `self` starts at, `None` for the
+    /// frames without a calendar start.
+    fn floor_wall(&self, frame: &Frame, week_start: u32) -> Option<NaiveDateTime> {
+        let local = self.datetime.naive_local();
+        let midnight = NaiveTime::from_hms(0, 0, 0);
+        let (date, time) = match frame {
+            Frame::Year => (NaiveDate::from_ymd(local.year(), 1, 1), midnight),
+            Frame::Quarter => (
+                NaiveDate::from_ymd(local.year(), local.month0() / 3 * 3 + 1, 1),
+                midnight,
+            ),
+            Frame::Month => (
+                NaiveDate::from_ymd(local.year(), local.month(), 1),
+                midnight,
+            ),
+            Frame::Week | Frame::IsoWeek => {
+                let week_start = if matches!(frame, Frame::IsoWeek) {
+                    1
+                } else {
+                    week_start
+                };
+                let delta = if week_start > self.isoweekday() { 7 } else { 0 };
+                let days = -(self.isoweekday() as i64 - week_start as i64) - delta;
+                // step back in calendar days, a DST transition in between doesn't move
+                // the floor off midnight
+                (local.date() + Duration::days(days), midnight)
+            }
+            Frame::Day => (local.date(), midnight),
+            Frame::Hour => (local.date(), NaiveTime::from_hms(local.hour(), 0, 0)),
+            Frame::Minute => (
+                local.date(),
+                NaiveTime::from_hms(local.hour(), local.minute(), 0),
+            ),
+            // a leap second stays one
+            Frame::Second => (
+                local.date(),
+                NaiveTime::from_hms_nano(
+                    local.hour(),
+                    local.minute(),
+                    local.second(),
+                    local.nanosecond() / 1_000_000_000 * 1_000_000_000,
+                ),
+            ),
+            Frame::Microsecond => return None,
+        };
+        Some(date.and_time(time))
+    }
+
+    /// Moves a wall time floored to `frame` back to the start of its span of `n` frames,
+    /// counted from the start of the parent frame (years are counted from year 0).
+    fn align_wall(frame: &Frame, n: u32, wall: NaiveDateTime) -> PyResult<NaiveDateTime> {
+        let aligned = match frame {
+            Frame::Year => wall.with_year(wall.year() - wall.year().rem_euclid(n as i32)),
+            Frame::Month => wall.with_month(wall.month0() / n * n + 1),
+            Frame::Quarter => wall.with_month(wall.month0() / (3 * n) * 3 * n + 1),
+            Frame::Hour => wall.with_hour(wall.hour() / n * n),
+            Frame::Minute => wall.with_minute(wall.minute() / n * n),
+            // a leap second is past the 59th, the aligned second isn't one
+            Frame::Second => wall
+                .with_nanosecond(0)
+                .and_then(|wall| wall.with_second(wall.second() / n * n)),
+            _ => Some(wall),
+        };
+        aligned.ok_or_else(|| exceptions::PyValueError::new_err("invalid datetime"))
+    }
+
+    /// The instant a frame starting at the wall time `wall` starts at in the timezone of
+    /// `self`, at the end of a gap skipping `wall`. A repeated wall time is taken as its
+    /// first instant for whole days and as the one of the fold of `self` otherwise.
+    fn frame_start(&self, frame: &Frame, wall: &NaiveDateTime) -> PyResult<Self> {
+        let ambiguous = if frame.is_calendar() || self.fold() == 0 {
+            Ambiguous::Earlier
+        } else {
+            Ambiguous::Later
+        };
+        Ok(Self::from(resolve_wall_time(
+            &self.datetime.timezone(),
+            wall,
+            Nonexistent::ShiftForward,
+            ambiguous,
+        )?))
+    }
+
+    /// The start of the span of `frames` frames containing `self`, with the spans counted
+    /// from `anchor` instead of the calendar. Days and weeks are counted on the wall clock, so
+    /// their boundaries keep the time of day of `anchor` across DST transitions.
+    fn anchored_floor(&self, frame: &Frame, frames: i64, anchor: &AtomicClock) -> PyResult<Self> {
+        let tz = self.datetime.timezone();
+        let anchor = anchor.datetime.with_timezone(&tz);
+        let unit = match frame {
+            Frame::Week | Frame::IsoWeek => 7 * NANOS_PER_DAY,
+            Frame::Day => NANOS_PER_DAY,
+            Frame::Hour => 3_600 * NANOS_PER_SECOND,
+            Frame::Minute => 60 * NANOS_PER_SECOND,
+            _ => NANOS_PER_SECOND,
+        };
+        let period = unit * frames as i128;
+        let since = |elapsed: Duration| {
+            let rem = duration_nanos(elapsed).rem_euclid(period);
+            Duration::seconds((rem / NANOS_PER_SECOND) as i64)
+                + Duration::nanoseconds((rem % NANOS_PER_SECOND) as i64)
+        };
+
+        let floor = if frame.is_calendar() {
+            let wall = self.datetime.naive_local();
+            let wall = wall - since(wall - anchor.naive_local());
+            resolve_wall_time(&tz, &wall, Nonexistent::ShiftForward, Ambiguous::Earlier)?
+        } else {
+            self.datetime.clone() - since(self.datetime.clone() - anchor)
+        };
+        // an anchor far after `self` may count the spans from before the supported years
+        if floor.year() < 1 {
+            return Err(RangeError::new_err(format!(
+                "anchored span is out of range, it has to start after {}",
+                *MIN_NAIVE_DATETIME
+            )));
+        }
+        Ok(Self::from(floor))
+    }
+
+    /// The first and last days of the month, quarter or year of `self`, `method` names the
+    /// caller in the error for other frames.
+    fn frame_dates(&self, frame: &Frame, method: &str) -> PyResult<(NaiveDate, NaiveDate)> {
+        let months = match frame {
+            Frame::Year => 12,
+            Frame::Quarter => 3,
+            Frame::Month => 1,
+            _ => {
+                return Err(exceptions::PyValueError::new_err(format!(
+                    "{} doesn't support frame `{}`",
+                    method,
+                    frame.name()
+                )))
+            }
+        };
+        let start_month = (self.month() - 1) / months * months + 1;
+        let (year, month) = match start_month + months {
+            13 => (self.year() + 1, 1),
+            month => (self.year(), month),
+        };
+        Ok((
+            NaiveDate::from_ymd(self.year(), start_month, 1),
+            NaiveDate::from_ymd(year, month, 1) - Duration::days(1),
+        ))
+    }
+
+    /// The first or last day of `frame` with the same time of day, or the first or last
+    /// `weekday` in it.
+    fn day_of(&self, frame: Frame, weekday: Option<i32>, last: bool) -> PyResult<Self> {
+        let (first, last_date) =
+            self.frame_dates(&frame, if last { "last_of" } else { "first_of" })?;
+        let mut date = if last { last_date } else { first };
+
+        if let Some(weekday) = weekday {
+            let weekday = check_weekday(weekday)?;
+            let current_weekday = date.weekday().num_days_from_monday() as i64;
+            let days = if last {
+                -(current_weekday - weekday).rem_euclid(7)
+            } else {
+                (weekday - current_weekday).rem_euclid(7)
+            };
+            date += Duration::days(days);
+        }
+
+        self.on_date(date)
+    }
+
+    /// `self` moved to `date`, at the same time of day.
+    fn on_date(&self, date: NaiveDate) -> PyResult<Self> {
+        self.with_date_time(date, self.datetime.naive_local().time())
+    }
+
+    /// `self` moved to `time` on the same date.
+    fn with_time(&self, time: NaiveTime) -> PyResult<Self> {
+        self.with_date_time(self.datetime.naive_local().date(), time)
+    }
+
+    /// `self` moved to the wall time `time` of `date`, in its own timezone and fold.
+    fn with_date_time(&self, date: NaiveDate, time: NaiveTime) -> PyResult<Self> {
+        self.at_wall_time(date.and_time(time), None, self.fold())
+    }
+
+    /// The wall time `naive` in `tzinfo`, the timezone of `self` by default, with `fold`
+    /// picking the side of an ambiguous time.
+    fn at_wall_time(
+        &self,
+        naive: NaiveDateTime,
+        tzinfo: Option<TzLikeOrOffset>,
+        fold: i32,
+    ) -> PyResult<Self> {
+        let tz = match tzinfo {
+            Some(TzLikeOrOffset::TzLike(tzinfo)) => wall_time_tz(tzinfo, &naive, fold)?,
+            Some(tzinfo) => tzinfo.try_to_tz()?,
+            None => self.datetime.timezone(),
+        };
+        Ok(Self::from(resolve_local_datetime(&tz, &naive, fold)?))
+    }
+
+    /// `self` moved by `nanos` nanoseconds of elapsed time.
+    fn add_nanos(&self, nanos: i128) -> PyResult<Self> {
+        self.shifted(0, nanos, None, false, false, false)
+    }
+
+    /// `self` moved by `months` calendar months and `nanos` nanoseconds as `checked_shift`
+    /// does, then onto `weekday`, backwards with `weekday_backwards`. Past the supported
+    /// years it's a `RangeError`, or the nearest bound with `clamp`.
+    fn shifted(
+        &self,
+        months: i128,
+        nanos: i128,
+        weekday: Option<i64>,
+        weekday_backwards: bool,
+        month_end: bool,
+        clamp: bool,
+    ) -> PyResult<Self> {
+        let shifted =
+            self.checked_shift(months, nanos, month_end)
+                .and_then(|datetime| match weekday {
+                    // the weekday is applied last, staying put when already on it
+                    Some(weekday) => {
+                        let current_weekday = datetime.weekday().num_days_from_monday() as i64;
+                        let days = if weekday_backwards {
+                            -(current_weekday - weekday).rem_euclid(7)
+                        } else {
+                            (weekday - current_weekday).rem_euclid(7)
+                        };
+                        datetime.checked_add_signed(Duration::days(days))
+                    }
+                    None => Some(datetime),
+                });
+
+        // past chrono's range the direction is estimated from the average month length
+        let forward = match shifted {
+            Some(datetime) if (1..=9999).contains(&datetime.year()) => {
+                return Ok(Self::from(datetime))
+            }
+            Some(datetime) => datetime.year() > 9999,
+            None => months as f64 * 2_629_746e9 + nanos as f64 > 0.0,
+        };
+        let bound = if forward {
+            *MAX_NAIVE_DATETIME
+        } else {
+            *MIN_NAIVE_DATETIME
+        };
+        match resolve_imaginary(&self.datetime.timezone(), &bound, false) {
+            Some(datetime) if clamp => Ok(Self::from(datetime)),
+            _ => Err(RangeError::new_err(format!(
+                "shifted datetime is out of range, it has to be between {} and {}",
+                *MIN_NAIVE_DATETIME, *MAX_NAIVE_DATETIME
+            ))),
+        }
+    }
+
+    /// Shifts by `months` calendar months in wall time, then by `nanos` nanoseconds of
+    /// elapsed time, `None` past chrono's range. The day is clamped to the target month, or
+    /// moved to its last day with `month_end`.
+    fn checked_shift(
+        &self,
+        months: i128,
+        nanos: i128,
+        month_end: bool,
+    ) -> Option<DateTime<HybridTz>> {
+        let mut datetime = self.datetime.clone();
+        if months != 0 {
+            let local = datetime.naive_local();
+            let month0 = i128::from(local.year()) * 12 + i128::from(local.month0()) + months;
+            let year = i32::try_from(month0.div_euclid(12)).ok()?;
+            // the next month has to exist too for the month end
+            if year >= chrono::naive::MAX_DATE.year() {
+                return None;
+            }
+            let first = NaiveDate::from_ymd_opt(year, month0.rem_euclid(12) as u32 + 1, 1)?;
+            let last = last_day_of_month(first).day();
+            let day = if month_end {
+                last
+            } else {
+                local.day().min(last)
+            };
+            let naive = first.with_day(day)?.and_time(local.time());
+            datetime = resolve_imaginary(&datetime.timezone(), &naive, self.fold() == 1)?;
+        }
+
+        let seconds = i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?;
+        if seconds.unsigned_abs() > i64::MAX as u64 / 1_000 {
+            return None;
+        }
+        let duration = Duration::seconds(seconds).checked_add(&Duration::nanoseconds(
+            nanos.rem_euclid(1_000_000_000) as i64,
+        ))?;
+        datetime.checked_add_signed(duration)
+    }
+
+    /// The UTC time of the datetime operand of a subtraction, which must be aware unless
+    /// `assume_utc` is set, as mixing naive and aware datetimes is an error in `datetime` too.
+    fn operand_naive_utc(datetime: &PyDateTime, assume_utc: bool) -> PyResult<NaiveDateTime> {
+        if !assume_utc && datetime.getattr("tzinfo")?.is_none() {
+            return Err(exceptions::PyTypeError::new_err(
+                "can't subtract offset-naive and offset-aware datetimes",
+            ));
+        }
+        naive_utc_from_datetime(datetime)
+    }
+
+    /// Nanoseconds from `self` to `other`, which defaults to now.
+    fn nanos_until(&self, other: Option<DateTimeLike>) -> PyResult<i128> {
+        let other = match other {
+            Some(other) => other.to_atomic_clock()?,
+            None => Self::utcnow()?,
+        };
+        Ok(duration_nanos(other.datetime - self.datetime.clone()))
+    }
+
+    /// The whole calendar years from `self` to `other`, counted on the dates in the
+    /// timezone of `self` and truncated toward zero.
+    fn years_to(&self, other: Option<DateTimeLike>) -> PyResult<i32> {
+        let other = match other {
+            Some(other) => other.to_atomic_clock()?,
+            None => Self::utcnow()?,
+        };
+        let start = self.datetime.date().naive_local();
+        let end = other
+            .datetime
+            .with_timezone(&self.datetime.timezone())
+            .date()
+            .naive_local();
+
+        let years = end.year() - start.year();
+        let anniversary = (start.month(), start.day());
+        let reached = (end.month(), end.day());
+        Ok(if years > 0 && reached < anniversary {
+            years - 1
+        } else if years < 0 && reached > anniversary {
+            years + 1
+        } else {
+            years
+        })
+    }
 }
 
 // Constructors
@@ -42,7 +453,10 @@ impl AtomicClock {
         minute = "0",
         second = "0",
         microsecond = "0",
-        tzinfo = "PyTzLike::utc()"
+        tzinfo = "PyTzLike::utc()",
+        "*",
+        fold = "0",
+        nanosecond = "None"
     )]
     #[allow(clippy::too_many_arguments)]
     fn new(
@@ -54,20 +468,27 @@ impl AtomicClock {
         second: u32,
         microsecond: u32,
         tzinfo: PyTzLike,
+        fold: i32,
+        nanosecond: Option<u32>,
     ) -> PyResult<Self> {
-        let tz = tzinfo.try_to_tz()?;
-
-        let datetime =
-            tz.ymd_opt(year, month, day)
-                .and_hms_micro_opt(hour, minute, second, microsecond);
-
-        if matches!(&datetime, LocalResult::None) {
-            return Err(exceptions::PyValueError::new_err("invalid datetime"));
-        }
+        // like in `replace`, both set the whole fraction of the second
+        let nanosecond = match nanosecond {
+            Some(_) if microsecond != 0 => {
+                return Err(exceptions::PyValueError::new_err(
+                    "microsecond and nanosecond can't be given together",
+                ))
+            }
+            Some(nanosecond) => nanosecond,
+            None => microsecond
+                .checked_mul(1000)
+                .ok_or_else(|| exceptions::PyValueError::new_err("invalid datetime"))?,
+        };
+        let naive = NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|date| date.and_hms_nano_opt(hour, minute, second, nanosecond))
+            .ok_or_else(|| exceptions::PyValueError::new_err("invalid datetime"))?;
+        let tz = wall_time_tz(tzinfo, &naive, fold)?;
 
-        Ok(Self {
-            datetime: datetime.unwrap(),
-        })
+        Ok(Self::from(resolve_local_datetime(&tz, &naive, fold)?))
     }
 
     #[staticmethod]
@@ -75,95 +496,284 @@ impl AtomicClock {
     #[pyo3(text_signature = "(tzinfo = \"local\")")]
     fn now(tzinfo: PyTzLike) -> PyResult<Self> {
         let tz = tzinfo.try_to_tz()?;
+        if tz == *UTC {
+            return Self::utcnow();
+        }
         let now = Local::now();
         let datetime = tz.from_utc_datetime(&now.naive_utc());
-        Ok(Self { datetime })
+        Ok(Self::from(datetime))
     }
 
     #[staticmethod]
     pub fn utcnow() -> PyResult<Self> {
         let now = Utc::now();
         let datetime = UTC.from_utc_datetime(&now.naive_utc());
-        Ok(Self { datetime })
+        Ok(Self::from(datetime))
     }
 
     #[staticmethod]
     #[args(tzinfo = "PyTzLike::local()")]
     #[pyo3(text_signature = "(timestamp, tzinfo = \"local\")")]
     fn fromtimestamp(timestamp: f64, tzinfo: PyTzLike) -> PyResult<Self> {
-        let tz = tzinfo.try_to_tz()?;
-        let nano_timestamp = Decimal::from_f64(timestamp)
-            .unwrap()
-            .mul(Decimal::from_i64(1_000_000_000).unwrap())
-            .to_i64()
-            .unwrap();
-        let secs = nano_timestamp / 1_000_000_000;
-        let nsecs = nano_timestamp % 1_000_000_000;
-        let datetime = tz.from_utc_datetime(&NaiveDateTime::from_timestamp(secs, nsecs as u32));
+        let naive = naive_from_timestamp(timestamp)?;
+        let tz = if tzinfo.is_local() {
+            HybridTz::local_at(&naive)
+        } else {
+            tzinfo.try_to_tz()?
+        };
+        let datetime = tz.from_utc_datetime(&naive);
+
+        Ok(Self::from(datetime))
+    }
+
+    #[staticmethod]
+    #[args(tzinfo = "PyTzLike::local()")]
+    #[pyo3(text_signature = "(ns, tzinfo = \"local\")")]
+    fn fromtimestamp_ns(ns: i64, tzinfo: PyTzLike) -> PyResult<Self> {
+        // every i64 of nanoseconds is within chrono's range
+        let naive = NaiveDateTime::from_timestamp(
+            ns.div_euclid(1_000_000_000),
+            ns.rem_euclid(1_000_000_000) as u32,
+        );
+        let tz = if tzinfo.is_local() {
+            HybridTz::local_at(&naive)
+        } else {
+            tzinfo.try_to_tz()?
+        };
 
-        Ok(Self { datetime })
+        Ok(Self::from(tz.from_utc_datetime(&naive)))
     }
 
     #[staticmethod]
     #[pyo3(text_signature = "(timestamp)")]
     fn utcfromtimestamp(timestamp: f64) -> PyResult<Self> {
-        let nano_timestamp = Decimal::from_f64(timestamp)
-            .unwrap()
-            .mul(Decimal::from_i64(1_000_000_000).unwrap())
-            .to_i64()
-            .unwrap();
-        let secs = nano_timestamp / 1_000_000_000;
-        let nsecs = nano_timestamp % 1_000_000_000;
-        let datetime = UTC.from_utc_datetime(&NaiveDateTime::from_timestamp(secs, nsecs as u32));
+        let datetime = UTC.from_utc_datetime(&naive_from_timestamp(timestamp)?);
+
+        Ok(Self::from(datetime))
+    }
+
+    #[staticmethod]
+    #[args(tzinfo = "PyTzLike::local()")]
+    #[pyo3(text_signature = "(st, tzinfo = \"local\")")]
+    fn fromstructtime(st: &PyAny, tzinfo: PyTzLike) -> PyResult<Self> {
+        if st.len()? != 9 {
+            return Err(ParserError::new_err(format!(
+                "invalid struct_time of {} elements, expected 9 elements",
+                st.len()?
+            )));
+        }
+        let field = |idx: usize| st.get_item(idx)?.extract::<u32>();
+
+        let year = st.get_item(0)?.extract::<i32>()?;
+        let (month, day) = (field(1)?, field(2)?);
+        let (hour, minute, second) = (field(3)?, field(4)?, field(5)?);
+        let naive = NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|date| date.and_hms_opt(hour, minute, second))
+            .ok_or_else(|| exceptions::PyValueError::new_err("invalid datetime"))?;
+
+        // "local" takes the offsets in effect at the wall time rather than the current one,
+        // and for a skipped wall time the offset at it read as UTC
+        let (tz, local) = if tzinfo.is_local() {
+            let local = Local.from_local_datetime(&naive).map(|datetime| {
+                datetime.with_timezone(&HybridTz::Offset(datetime.offset().fix()))
+            });
+            (HybridTz::local_at(&naive), local)
+        } else {
+            let tz = tzinfo.try_to_tz()?;
+            let local = tz.from_local_datetime(&naive);
+            (tz, local)
+        };
+
+        // like `time.mktime`, a known `tm_isdst` picks between the two instants of a repeated
+        // wall time, the DST one has the larger offset, and -1 leaves it to the zone
+        let datetime = match (local, st.get_item(8)?.extract::<i32>()?) {
+            (LocalResult::Single(datetime), _) => datetime,
+            (LocalResult::Ambiguous(earlier, later), isdst) if isdst >= 0 => {
+                let earlier_is_dst = earlier.offset().fix().local_minus_utc()
+                    > later.offset().fix().local_minus_utc();
+                if earlier_is_dst == (isdst > 0) {
+                    earlier
+                } else {
+                    later
+                }
+            }
+            (LocalResult::Ambiguous(earlier, _), _) => earlier,
+            (LocalResult::None, _) => resolve_local_datetime(&tz, &naive, 0)?,
+        };
 
-        Ok(Self { datetime })
+        Ok(Self::from(datetime))
     }
 
     #[staticmethod]
     #[args(tzinfo = "None")]
     #[pyo3(text_signature = "(dt, tzinfo = \"None\")")]
     fn fromdatetime(dt: &PyDateTime, tzinfo: Option<PyTzLike>) -> PyResult<Self> {
+        let naive = NaiveDate::from_ymd(dt.get_year(), dt.get_month() as u32, dt.get_day() as u32)
+            .and_hms_micro(
+                dt.get_hour() as u32,
+                dt.get_minute() as u32,
+                dt.get_second() as u32,
+                dt.get_microsecond(),
+            );
+        let fold = dt.get_fold() as i32;
+
         let tz = {
             if let Some(tzinfo) = tzinfo {
-                tzinfo.try_to_tz()?
+                wall_time_tz(tzinfo, &naive, fold)?
             } else {
                 let tz = dt.getattr("tzinfo")?;
                 if let Ok(tz) = tz.extract::<&PyTzInfo>() {
                     PyTzLike::PyTzInfo(tz).try_to_tz()?
                 } else {
-                    *UTC
+                    UTC.clone()
                 }
             }
         };
 
-        let naive = NaiveDate::from_ymd(dt.get_year(), dt.get_month() as u32, dt.get_day() as u32)
-            .and_hms_micro(
-                dt.get_hour() as u32,
-                dt.get_minute() as u32,
-                dt.get_second() as u32,
-                dt.get_microsecond(),
-            );
-
-        Ok(Self {
-            datetime: tz.from_local_datetime(&naive).unwrap(),
-        })
+        Ok(Self::from(resolve_local_datetime(&tz, &naive, fold)?))
     }
 
     #[staticmethod]
     #[args(tzinfo = "PyTzLike::utc()")]
     #[pyo3(text_signature = "(date, tzinfo = \"UTC\")")]
     fn fromdate(date: &PyDate, tzinfo: PyTzLike) -> PyResult<Self> {
-        let tz = tzinfo.try_to_tz()?;
         let naive = NaiveDate::from_ymd(
             date.get_year(),
             date.get_month() as u32,
             date.get_day() as u32,
         )
         .and_hms_micro(0, 0, 0, 0);
+        let tz = wall_time_tz(tzinfo, &naive, 0)?;
 
-        Ok(Self {
-            datetime: tz.from_local_datetime(&naive).unwrap(),
-        })
+        Ok(Self::from(tz.from_local_datetime(&naive).unwrap()))
+    }
+
+    #[staticmethod]
+    #[args(tzinfo = "None")]
+    #[pyo3(text_signature = "(date, time, tzinfo = \"None\")")]
+    fn combine(date: &PyDate, time: &PyTime, tzinfo: Option<PyTzLike>) -> PyResult<Self> {
+        let naive = NaiveDate::from_ymd(
+            date.get_year(),
+            date.get_month() as u32,
+            date.get_day() as u32,
+        )
+        .and_hms_micro(
+            time.get_hour() as u32,
+            time.get_minute() as u32,
+            time.get_second() as u32,
+            time.get_microsecond(),
+        );
+        let fold = time.get_fold() as i32;
+
+        let time_tz = match time.getattr("tzinfo")?.extract::<&PyTzInfo>() {
+            Ok(tz) => Some(PyTzLike::PyTzInfo(tz).try_to_tz()?),
+            Err(_) => None,
+        };
+        let datetime = match (tzinfo, time_tz) {
+            (Some(tzinfo), time_tz) => {
+                let tz = wall_time_tz(tzinfo, &naive, fold)?;
+                let datetime = resolve_local_datetime(&tz, &naive, fold)?;
+                // zones disagree when the wall time is a different instant in each
+                if let Some(time_tz) = time_tz {
+                    if resolve_local_datetime(&time_tz, &naive, fold)? != datetime {
+                        return Err(exceptions::PyValueError::new_err(
+                            "the tzinfo of the time conflicts with the tzinfo argument",
+                        ));
+                    }
+                }
+                datetime
+            }
+            (None, Some(time_tz)) => resolve_local_datetime(&time_tz, &naive, fold)?,
+            (None, None) => resolve_local_datetime(&UTC, &naive, fold)?,
+        };
+
+        Ok(Self::from(datetime))
+    }
+
+    #[staticmethod]
+    #[args(
+        nonexistent = "Nonexistent::ShiftForward",
+        ambiguous = "Ambiguous::Earlier"
+    )]
+    #[pyo3(
+        text_signature = "(naive, tz, nonexistent = \"shift_forward\", ambiguous = \"earlier\")"
+    )]
+    fn resolve(
+        naive: WallTime,
+        tz: PyTzLike,
+        nonexistent: Nonexistent,
+        ambiguous: Ambiguous,
+    ) -> PyResult<Self> {
+        let tz = tz.try_to_tz()?;
+        let naive = naive.to_naive()?;
+
+        Ok(Self::from(resolve_wall_time(
+            &tz,
+            &naive,
+            nonexistent,
+            ambiguous,
+        )?))
+    }
+
+    #[staticmethod]
+    #[pyo3(text_signature = "(d)")]
+    fn fromdict(d: &PyDict) -> PyResult<Self> {
+        const KEYS: [&str; 10] = [
+            "year",
+            "month",
+            "day",
+            "hour",
+            "minute",
+            "second",
+            "microsecond",
+            "tzinfo",
+            "fold",
+            "nanosecond",
+        ];
+        for key in d.keys() {
+            if !matches!(key.extract::<&str>(), Ok(key) if KEYS.contains(&key)) {
+                return Err(exceptions::PyTypeError::new_err(format!(
+                    "fromdict() got an unexpected key {}",
+                    key.repr()?
+                )));
+            }
+        }
+        // missing fields default as for the constructor, except the year
+        let field = |key: &str, default: u32| match d.get_item(key) {
+            Some(value) => value.extract::<u32>(),
+            None => Ok(default),
+        };
+        let year = d
+            .get_item("year")
+            .ok_or_else(|| {
+                exceptions::PyTypeError::new_err("fromdict() missing required key 'year'")
+            })?
+            .extract::<i32>()?;
+        let tzinfo = match d.get_item("tzinfo") {
+            Some(tzinfo) => tzinfo.extract::<PyTzLike>()?,
+            None => PyTzLike::utc(),
+        };
+        let fold = match d.get_item("fold") {
+            Some(fold) => fold.extract::<i32>()?,
+            None => 0,
+        };
+        let nanosecond = d
+            .get_item("nanosecond")
+            .map(|nanosecond| nanosecond.extract::<u32>())
+            .transpose()?;
+
+        Self::new(
+            year,
+            field("month", 1)?,
+            field("day", 1)?,
+            field("hour", 0)?,
+            field("minute", 0)?,
+            field("second", 0)?,
+            field("microsecond", 0)?,
+            tzinfo,
+            fold,
+            nanosecond,
+        )
     }
 
     #[staticmethod]
@@ -171,27 +781,65 @@ impl AtomicClock {
     fn strptime(datetime: &str, fmt: &str, tzinfo: Option<PyTzLike>) -> PyResult<Self> {
         use chrono::format::{parse, Parsed, StrftimeItems};
 
+        let fmt = permissive_offset_format(fmt);
+        let items = StrftimeItems::new(&fmt);
+        let dotted;
+        let datetime = match dotted_meridiem(datetime) {
+            Some(normalized) if fmt.contains("%p") || fmt.contains("%P") => {
+                dotted = normalized;
+                dotted.as_str()
+            }
+            _ => datetime,
+        };
         let mut parsed = Parsed::new();
-        parse(&mut parsed, datetime, StrftimeItems::new(fmt))
-            .map_err(|e| exceptions::PyValueError::new_err(e.to_string()))?;
+        if let Err(e) = parse(&mut parsed, datetime, items.clone()) {
+            // unlike `%.f`, the `%f` and `%9f` directives fail on sub-nanosecond digits
+            let truncated = truncate_subnanosecond_digits(datetime)
+                .ok_or_else(|| ParserError::new_err(e.to_string()))?;
+            parsed = Parsed::new();
+            parse(&mut parsed, &truncated, items)
+                .map_err(|e| ParserError::new_err(e.to_string()))?;
+        }
 
         // set default values
         parsed.year = parsed.year.or(Some(0));
         parsed.month = parsed.month.or(Some(1));
         parsed.day = parsed.day.or(Some(1));
-        if parsed.hour_div_12.is_none() {
-            parsed.set_hour(0).unwrap();
+        match (parsed.hour_div_12, parsed.hour_mod_12) {
+            (None, None) => parsed.set_hour(0).unwrap(),
+            (None, Some(_)) => {
+                return Err(ParserError::new_err(
+                    "ambiguous 12-hour clock, %I requires %p or %H",
+                ))
+            }
+            (Some(_), None) => parsed.hour_mod_12 = Some(0),
+            (Some(_), Some(_)) => (),
         }
         parsed.minute = parsed.minute.or(Some(0));
         parsed.second = parsed.second.or(Some(0));
         parsed.nanosecond = parsed.nanosecond.or(Some(0));
-        parsed.offset = parsed.offset.or(Some(0));
 
+        if parsed.offset.is_none() {
+            // without an offset directive the wall time belongs to `tzinfo`
+            let tz = match tzinfo {
+                Some(tzinfo) => tzinfo.try_to_tz()?,
+                None => UTC.clone(),
+            };
+            let naive = parsed
+                .to_naive_datetime_with_offset(0)
+                .map_err(|e| ParserError::new_err(e.to_string()))?;
+            let datetime = tz
+                .from_local_datetime(&naive)
+                .earliest()
+                .ok_or_else(|| ParserError::new_err("invalid datetime"))?;
+            return Ok(Self::from(datetime));
+        }
+
+        // the parsed offset defines the instant, `tzinfo` only changes the displayed zone
         let datetime = parsed
             .to_datetime()
-            .map_err(|e| exceptions::PyValueError::new_err(e.to_string()))?;
+            .map_err(|e| ParserError::new_err(e.to_string()))?;
 
-        // get tz
         let tz = {
             if let Some(tzinfo) = tzinfo {
                 tzinfo.try_to_tz()?
@@ -201,69 +849,83 @@ impl AtomicClock {
             }
         };
 
-        Ok(Self {
-            datetime: datetime.with_timezone(&tz),
-        })
+        Ok(Self::from(datetime.with_timezone(&tz)))
+    }
+
+    #[staticmethod]
+    #[pyo3(text_signature = "(datetime)")]
+    fn fromrfc2822(datetime: &str) -> PyResult<Self> {
+        use chrono::format::{parse, Fixed, Item, Parsed};
+
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, datetime, [Item::Fixed(Fixed::RFC2822)].iter())
+            .map_err(|e| ParserError::new_err(e.to_string()))?;
+
+        // RFC 2822 treats unknown zone names (e.g. military zones) as "-0000"
+        parsed.offset = parsed.offset.or(Some(0));
+
+        let datetime = parsed
+            .to_datetime()
+            .map_err(|e| ParserError::new_err(e.to_string()))?;
+        let tz = HybridTz::Offset(*datetime.offset());
+
+        Ok(Self::from(datetime.with_timezone(&tz)))
+    }
+
+    #[staticmethod]
+    #[pyo3(text_signature = "(datetime)")]
+    fn fromhttpdate(datetime: &str) -> PyResult<Self> {
+        let naive = parse_http_date(datetime).map_err(|e| ParserError::new_err(e.to_string()))?;
+
+        Ok(Self::from(UTC.from_utc_datetime(&naive)))
     }
 
     #[staticmethod]
     #[pyo3(text_signature = "(ordinal)")]
     fn fromordinal(ordinal: i64) -> PyResult<Self> {
         if !matches!(ordinal, MIN_ORDINAL..=MAX_ORDINAL) {
-            return Err(exceptions::PyValueError::new_err(format!(
+            return Err(RangeError::new_err(format!(
                 "ordinal {ordinal} is out of range"
             )));
         }
 
         let datetime = NaiveDate::from_ymd(1, 1, 1).and_hms(0, 0, 0) + Duration::days(ordinal - 1);
-        Ok(Self {
-            datetime: UTC.from_utc_datetime(&datetime),
-        })
+        Ok(Self::from(UTC.from_utc_datetime(&datetime)))
     }
 
     #[staticmethod]
-    #[args(frame, start, end, "*", tz = "None", limit = "None")]
-    #[pyo3(text_signature = "(frame, start, end=None, *, tz=None, limit=None)")]
-    fn range(
-        py: Python,
-        frame: Frame,
-        start: DateTimeLike,
-        end: Option<DateTimeLike>,
-        tz: Option<PyTzLike>,
-        limit: Option<u64>,
-    ) -> PyResult<Py<DatetimeRangeIter>> {
-        let start = start.to_atomic_clock()?;
-        let end_timestamp = if let Some(end) = end {
-            let end = end.to_atomic_clock()?;
-            if end.timestamp() < start.timestamp() {
-                return Err(exceptions::PyValueError::new_err("end is less than start"));
-            }
-            end.timestamp()
-        } else {
-            f64::MAX
-        };
+    #[args(tzinfo = "PyTzLike::utc()")]
+    #[pyo3(text_signature = "(jd, tzinfo = \"UTC\")")]
+    fn from_julian_day(jd: f64, tzinfo: PyTzLike) -> PyResult<Self> {
+        let tz = tzinfo.try_to_tz()?;
+        let naive = naive_from_units(jd, JULIAN_EPOCH_NANOS, NANOS_PER_DAY)
+            .ok_or_else(|| RangeError::new_err(format!("julian date {jd} is out of range")))?;
 
-        let limit = limit.or(Some(u64::MAX)).unwrap();
-        let start = if let Some(tz) = tz {
-            AtomicClock::new(
-                start.datetime.year(),
-                start.datetime.month(),
-                start.datetime.day(),
-                start.datetime.hour(),
-                start.datetime.minute(),
-                start.datetime.second(),
-                start.datetime.nanosecond() / 1000,
-                tz,
-            )?
-        } else {
-            start
-        };
+        Ok(Self::from(tz.from_utc_datetime(&naive)))
+    }
 
-        let iter = DatetimeRangeIter {
-            generator: DatetimeRangeGenerator::new(start, end_timestamp, frame.duration(), limit),
-        };
+    #[staticmethod]
+    #[args(tzinfo = "PyTzLike::utc()")]
+    #[pyo3(text_signature = "(mjd, tzinfo = \"UTC\")")]
+    fn from_mjd(mjd: f64, tzinfo: PyTzLike) -> PyResult<Self> {
+        let tz = tzinfo.try_to_tz()?;
+        let naive =
+            naive_from_units(mjd, MODIFIED_JULIAN_EPOCH_NANOS, NANOS_PER_DAY).ok_or_else(|| {
+                RangeError::new_err(format!("modified julian date {mjd} is out of range"))
+            })?;
 
-        Py::new(py, iter)
+        Ok(Self::from(tz.from_utc_datetime(&naive)))
+    }
+
+    #[staticmethod]
+    #[args(tzinfo = "PyTzLike::utc()")]
+    #[pyo3(text_signature = "(seconds, tzinfo = \"UTC\")")]
+    fn from_gps_seconds(seconds: f64, tzinfo: PyTzLike) -> PyResult<Self> {
+        let tz = tzinfo.try_to_tz()?;
+        let naive = naive_from_units(seconds, GPS_EPOCH_NANOS, NANOS_PER_SECOND)
+            .ok_or_else(|| RangeError::new_err(format!("gps time {seconds} is out of range")))?;
+
+        Ok(Self::from(tz.from_utc_datetime(&naive)))
     }
 
     #[staticmethod]
@@ -273,59 +935,113 @@ impl AtomicClock {
         end,
         "*",
         tz = "None",
+        wall_time = "false",
         limit = "None",
+        chunk_size = "None"
+    )]
+    #[pyo3(
+        text_signature = "(frame, start, end=None, *, tz=None, wall_time=False, limit=None, chunk_size=None)"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn range(
+        py: Python,
+        frame: Frame,
+        start: RangeStart,
+        end: Option<DateTimeLike>,
+        tz: Option<PyTzLike>,
+        wall_time: bool,
+        limit: Option<u64>,
+        chunk_size: Option<usize>,
+    ) -> PyResult<Py<DatetimeRangeIter>> {
+        check_chunk_size(chunk_size)?;
+        let (start, end) = start.resolve(end)?;
+        let mut start = start.with_range_tz(tz.clone(), wall_time)?;
+        // ISO weeks are aligned to their Monday, the other frames step from `start`
+        if matches!(frame, Frame::IsoWeek) {
+            start = start.floor(Frame::IsoWeek, 1, 1, 1, None)?;
+        }
+        let end = if let Some(end) = end {
+            let end = end.with_range_tz(tz, wall_time)?;
+            if end.datetime < start.datetime {
+                return Err(exceptions::PyValueError::new_err("end is less than start"));
+            }
+            Some(end.datetime.naive_utc())
+        } else {
+            None
+        };
+
+        let limit = limit.unwrap_or(u64::MAX);
+        let iter = DatetimeRangeIter {
+            generator: DatetimeRangeGenerator::new(start, end, frame.duration(), limit),
+            chunk_size,
+        };
+
+        Py::new(py, iter)
+    }
+
+    #[staticmethod]
+    #[args(
+        frame,
+        start,
+        end = "None",
+        "*",
+        tz = "None",
+        wall_time = "false",
+        limit = "None",
+        n = 1,
         bounds = "Bounds::StartInclude",
-        exact = "false"
+        exact = "false",
+        week_start = "1",
+        chunk_size = "None",
+        anchor = "None"
     )]
     #[pyo3(
-        text_signature = "(frame, start, end, *, tz=None, limit=None, bounds=\"[)\", exact=False)"
+        text_signature = "(frame, start, end=None, *, tz=None, wall_time=False, limit=None, n=1, bounds=\"[)\", exact=False, week_start=1, chunk_size=None, anchor=None)"
     )]
     #[allow(clippy::too_many_arguments)]
     fn span_range(
         py: Python,
         frame: Frame,
-        start: DateTimeLike,
-        end: DateTimeLike,
+        start: RangeStart,
+        end: Option<DateTimeLike>,
         tz: Option<PyTzLike>,
+        wall_time: bool,
         limit: Option<u64>,
+        n: u32,
         bounds: Bounds,
         exact: bool,
+        week_start: u32,
+        chunk_size: Option<usize>,
+        anchor: Option<DateTimeLike>,
     ) -> PyResult<Py<DatetimeSpanRangeIter>> {
-        let limit = limit.or(Some(u64::MAX)).unwrap();
-        let (start, end) = if let Some(tz) = tz {
-            (
-                start.to_atomic_clock()?.replace(
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    Some(tz.clone()),
-                )?,
-                end.to_atomic_clock()?.replace(
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    Some(tz),
-                )?,
-            )
-        } else {
-            (start.to_atomic_clock()?, end.to_atomic_clock()?)
-        };
+        check_chunk_size(chunk_size)?;
+        let limit = limit.unwrap_or(u64::MAX);
+        let (start, end) = start.resolve_bounded(end)?;
+        let start = start.with_range_tz(tz.clone(), wall_time)?;
+        let end = end.with_range_tz(tz, wall_time)?;
+        let anchor = anchor.map(|anchor| anchor.to_atomic_clock()).transpose()?;
         let start = start
-            .span(frame.clone(), 1, Bounds::StartInclude, exact, 1)?
+            .span(
+                frame.clone(),
+                1,
+                n,
+                Bounds::StartInclude,
+                exact,
+                week_start,
+                anchor.clone().map(DateTimeLike::AtomicClock),
+            )?
             .0;
 
-        let generator =
-            DatetimeRangeGenerator::new(start, end.timestamp(), frame.clone().duration(), limit);
+        let generator = DatetimeRangeGenerator::new(
+            start,
+            Some(end.datetime.naive_utc()),
+            frame.clone().duration() * n as f64,
+            limit,
+        );
 
-        let iter = DatetimeSpanRangeIter::new(generator, frame, 1, bounds, exact, end);
+        let iter = DatetimeSpanRangeIter::new(
+            generator, frame, 1, n, bounds, exact, week_start, anchor, end, chunk_size, false,
+        );
         Py::new(py, iter)
     }
 
@@ -333,112 +1049,214 @@ impl AtomicClock {
     #[args(
         frame,
         start,
-        end,
+        end = "None",
         "*",
         interval = "1",
         tz = "None",
+        wall_time = "false",
         limit = "None",
         bounds = "Bounds::StartInclude",
-        exact = "false"
+        exact = "false",
+        week_start = "1",
+        chunk_size = "None",
+        starts_only = "false"
     )]
     #[pyo3(
-        text_signature = "(frame, start, end, *, interval=1, tz=None, limit=None, bounds=\"[)\", exact=False)"
+        text_signature = "(frame, start, end=None, *, interval=1, tz=None, wall_time=False, limit=None, bounds=\"[)\", exact=False, week_start=1, chunk_size=None, starts_only=False)"
     )]
     #[allow(clippy::too_many_arguments)]
     fn interval(
         py: Python,
         frame: Frame,
-        start: DateTimeLike,
-        end: DateTimeLike,
+        start: RangeStart,
+        end: Option<DateTimeLike>,
         interval: u64,
         tz: Option<PyTzLike>,
+        wall_time: bool,
         limit: Option<u64>,
         bounds: Bounds,
         exact: bool,
+        week_start: u32,
+        chunk_size: Option<usize>,
+        starts_only: bool,
     ) -> PyResult<Py<DatetimeSpanRangeIter>> {
         if interval < 1 {
             return Err(exceptions::PyValueError::new_err(
                 "interval has to be a positive int",
             ));
         }
+        check_chunk_size(chunk_size)?;
 
-        let limit = limit.or(Some(u64::MAX)).unwrap();
-        let (start, end) = if let Some(tz) = tz {
-            (
-                start.to_atomic_clock()?.replace(
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    Some(tz.clone()),
-                )?,
-                end.to_atomic_clock()?.replace(
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    Some(tz),
-                )?,
-            )
-        } else {
-            (start.to_atomic_clock()?, end.to_atomic_clock()?)
-        };
+        let limit = limit.unwrap_or(u64::MAX);
+        let (start, end) = start.resolve_bounded(end)?;
+        let start = start.with_range_tz(tz.clone(), wall_time)?;
+        let end = end.with_range_tz(tz, wall_time)?;
         let start = start
-            .span(frame.clone(), 1, Bounds::StartInclude, exact, 1)?
+            .span(
+                frame.clone(),
+                1,
+                1,
+                Bounds::StartInclude,
+                exact,
+                week_start,
+                None,
+            )?
             .0;
 
         let generator = DatetimeRangeGenerator::new(
             start,
-            end.timestamp(),
+            Some(end.datetime.naive_utc()),
             frame.clone().duration() * interval as f64,
             limit,
         );
 
-        let iter =
-            DatetimeSpanRangeIter::new(generator, frame, interval as i64, bounds, exact, end);
+        let iter = DatetimeSpanRangeIter::new(
+            generator,
+            frame,
+            interval as i64,
+            1,
+            bounds,
+            exact,
+            week_start,
+            None,
+            end,
+            chunk_size,
+            starts_only,
+        );
         Py::new(py, iter)
     }
+
+    #[staticmethod]
+    #[pyo3(text_signature = "(a, b)")]
+    fn midpoint(a: &Self, b: &Self) -> Self {
+        // floor division, odd gaps round down to the earlier instant whatever the order
+        let half = duration_nanos(b.datetime.naive_utc() - a.datetime.naive_utc()).div_euclid(2);
+        let half = Duration::seconds(half.div_euclid(1_000_000_000) as i64)
+            + Duration::nanoseconds(half.rem_euclid(1_000_000_000) as i64);
+        Self::from(a.datetime.clone() + half)
+    }
+
+    #[classattr]
+    fn min() -> Self {
+        Self::from(UTC.from_utc_datetime(&MIN_NAIVE_DATETIME))
+    }
+
+    #[classattr]
+    fn max() -> Self {
+        Self::from(UTC.from_utc_datetime(&MAX_NAIVE_DATETIME))
+    }
 }
 
 // Protocols
 #[pymethods]
 impl AtomicClock {
-    fn __repr__(&self) -> String {
-        format!("<AtomicClock [{}]>", self.__str__())
+    fn __repr__(slf: &PyCell<Self>) -> PyResult<String> {
+        // `PyType::name` is the qualified name, which includes the enclosing scopes
+        let name: &str = slf.get_type().getattr("__name__")?.extract()?;
+        let clock = slf.borrow();
+        let text = clock.__str__(slf.py());
+        let text = text.as_ref(slf.py()).to_str()?;
+        // an offset alone doesn't tell which named zone it comes from
+        Ok(match clock.datetime.timezone() {
+            HybridTz::Timespan(tz) if tz != chrono_tz::UTC => {
+                format!("<{} [{} {}]>", name, text, tz.name())
+            }
+            HybridTz::Posix(posix) => format!("<{} [{} {}]>", name, text, posix),
+            HybridTz::Custom(custom) => format!("<{} [{} {}]>", name, text, custom.name()),
+            _ => format!("<{} [{}]>", name, text),
+        })
+    }
+
+    fn __str__(&self, py: Python) -> Py<PyString> {
+        self.text
+            .get_or_init(|| PyString::new(py, &self.to_str()).into())
+            .clone_ref(py)
+    }
+
+    // no `py` argument, so the method keeps the METH_NOARGS convention which is safe to
+    // call from the C API with a null argument vector, as pickle and copy do
+    fn __getnewargs_ex__(&self) -> PyResult<(Py<PyTuple>, Py<PyDict>)> {
+        Python::with_gil(|py| {
+            // the nanoseconds are only passed when the microseconds would lose them
+            let nanosecond = self.datetime.nanosecond();
+            let (microsecond, nanosecond) = if nanosecond.is_multiple_of(1000) {
+                (nanosecond / 1000, None)
+            } else {
+                (0, Some(nanosecond))
+            };
+            let args = PyTuple::new(
+                py,
+                [
+                    self.datetime.year().into_py(py),
+                    self.datetime.month().into_py(py),
+                    self.datetime.day().into_py(py),
+                    self.datetime.hour().into_py(py),
+                    self.datetime.minute().into_py(py),
+                    self.datetime.second().into_py(py),
+                    microsecond.into_py(py),
+                ],
+            );
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("tzinfo", self.datetime.timezone().to_string())?;
+            kwargs.set_item("fold", self.fold())?;
+            if let Some(nanosecond) = nanosecond {
+                kwargs.set_item("nanosecond", nanosecond)?;
+            }
+            Ok((args.into(), kwargs.into()))
+        })
     }
 
-    fn __str__(&self) -> String {
-        self.datetime.to_rfc3339()
+    fn __setattr__(&mut self, _name: &str, _value: &PyAny) -> PyResult<()> {
+        Err(exceptions::PyAttributeError::new_err(
+            "AtomicClock is immutable; use replace()/shift()",
+        ))
     }
 
-    fn __format__(&self, formatstr: &str) -> String {
+    fn __delattr__(&mut self, _name: &str) -> PyResult<()> {
+        Err(exceptions::PyAttributeError::new_err(
+            "AtomicClock is immutable; use replace()/shift()",
+        ))
+    }
+
+    fn __format__(&self, py: Python, formatstr: &str) -> PyResult<PyObject> {
         if formatstr.is_empty() {
-            self.__str__()
+            Ok(self.__str__(py).into_py(py))
         } else {
-            self.format(formatstr)
+            // a format spec can't pass `tokens`, so a spec without strftime directives is
+            // taken as Arrow-style tokens
+            Ok(self
+                .format(formatstr, "en", !formatstr.contains('%'))?
+                .into_py(py))
         }
     }
 
-    fn __richcmp__(&self, datetime: DateTimeLike, op: CompareOp) -> PyResult<bool> {
-        let left_timestamp = self.timestamp();
-        let right_timestamp = match datetime {
-            DateTimeLike::AtomicClock(d) => d.timestamp(),
-            DateTimeLike::PyDateTime(d) => Self::fromdatetime(d, None).unwrap().timestamp(),
+    fn __richcmp__(&self, py: Python, other: CompareOperand, op: CompareOp) -> PyResult<PyObject> {
+        // operands that aren't datetime-like fail to extract, pyo3 then returns
+        // NotImplemented so Python can try the reflected comparison
+        let left = self.datetime.naive_utc();
+        let right = match other {
+            CompareOperand::DateTimeLike(datetime) => datetime.naive_utc()?,
+            CompareOperand::Str(datetime) => match parse_datetime_str(datetime) {
+                Ok(clock) => clock.datetime.naive_utc(),
+                // an unparsable string is just another object, never equal to a clock
+                Err(_) => {
+                    return Ok(match op {
+                        CompareOp::Eq => false.into_py(py),
+                        CompareOp::Ne => true.into_py(py),
+                        _ => py.NotImplemented(),
+                    })
+                }
+            },
         };
-        match op {
-            CompareOp::Lt => Ok(left_timestamp < right_timestamp),
-            CompareOp::Le => Ok(left_timestamp <= right_timestamp),
-            CompareOp::Eq => Ok(left_timestamp == right_timestamp),
-            CompareOp::Ne => Ok(left_timestamp != right_timestamp),
-            CompareOp::Gt => Ok(left_timestamp > right_timestamp),
-            CompareOp::Ge => Ok(left_timestamp >= right_timestamp),
-        }
+        let result = match op {
+            CompareOp::Lt => left < right,
+            CompareOp::Le => left <= right,
+            CompareOp::Eq => left == right,
+            CompareOp::Ne => left != right,
+            CompareOp::Gt => left > right,
+            CompareOp::Ge => left >= right,
+        };
+        Ok(result.into_py(py))
     }
 
     fn __add__(&self, delta: DeltaLike) -> PyResult<Self> {
@@ -454,27 +1272,15 @@ impl AtomicClock {
                 weeks,
                 quarters,
                 weekday,
-            }) => self.shift(
-                years,
-                months,
-                days,
-                hours,
-                minutes,
-                seconds,
-                microseconds,
-                weeks,
-                quarters,
-                weekday,
+            }) => self.shifted(
+                i128::from(years) * 12 + i128::from(months) + i128::from(quarters) * 3,
+                elapsed_nanos(weeks, days, hours, minutes, seconds, microseconds),
+                weekday.map(check_weekday).transpose()?,
+                false,
+                false,
+                false,
             ),
-            DeltaLike::PyDelta(delta) => {
-                let seconds = delta.call_method1("total_seconds", ())?.extract::<f64>()?;
-                let mut seconds = Decimal::from_f64(seconds).unwrap();
-                if seconds.scale() > 0 {
-                    seconds.set_scale(6).unwrap();
-                }
-                let microseconds = seconds.mul(Decimal::from_i64(1_000_000).unwrap());
-                self.shift(0, 0, 0, 0, 0, 0, microseconds.to_i64().unwrap(), 0, 0, None)
-            }
+            DeltaLike::PyDelta(delta) => self.add_nanos(timedelta_nanos(delta)),
         }
     }
 
@@ -486,15 +1292,15 @@ impl AtomicClock {
         match obj {
             DateTimeOrDeltaLike::DateTimeLike(datetime) => match datetime {
                 DateTimeLike::AtomicClock(datetime) => {
-                    let duration = self.datetime - datetime.datetime;
+                    let duration = self.datetime.naive_utc() - datetime.datetime.naive_utc();
                     let (days, seconds, microseconds) = normalize_duration(duration);
                     let delta =
                         PyDelta::new(py, days as i32, seconds as i32, microseconds as i32, true)?;
                     Ok(delta.into())
                 }
                 DateTimeLike::PyDateTime(datetime) => {
-                    let datetime = AtomicClock::fromdatetime(datetime, None)?;
-                    let duration = self.datetime - datetime.datetime;
+                    let duration = self.datetime.naive_utc()
+                        - AtomicClock::operand_naive_utc(datetime, false)?;
                     let (days, seconds, microseconds) = normalize_duration(duration);
                     let delta =
                         PyDelta::new(py, days as i32, seconds as i32, microseconds as i32, true)?;
@@ -507,24 +1313,7 @@ impl AtomicClock {
                     Ok(Py::new(py, datetime)?.to_object(py))
                 }
                 DeltaLike::PyDelta(delta) => {
-                    let seconds = delta.call_method1("total_seconds", ())?.extract::<f64>()?;
-                    let mut seconds = Decimal::from_f64(seconds).unwrap();
-                    if seconds.scale() > 0 {
-                        seconds.set_scale(6).unwrap();
-                    }
-                    let microseconds = seconds.mul(Decimal::from_i64(1_000_000).unwrap());
-                    let datetime = self.shift(
-                        0,
-                        0,
-                        0,
-                        0,
-                        0,
-                        0,
-                        -microseconds.to_i64().unwrap(),
-                        0,
-                        0,
-                        None,
-                    )?;
+                    let datetime = self.add_nanos(-timedelta_nanos(delta))?;
                     Ok(Py::new(py, datetime)?.to_object(py))
                 }
             },
@@ -533,20 +1322,38 @@ impl AtomicClock {
     fn __rsub__<'p>(&self, py: Python<'p>, datetime: DateTimeLike) -> PyResult<&'p PyDelta> {
         match datetime {
             DateTimeLike::AtomicClock(datetime) => {
-                let duration = datetime.datetime - self.datetime;
+                let duration = datetime.datetime.naive_utc() - self.datetime.naive_utc();
                 let (days, seconds, microseconds) = normalize_duration(duration);
                 PyDelta::new(py, days as i32, seconds as i32, microseconds as i32, true)
             }
             DateTimeLike::PyDateTime(datetime) => {
-                let datetime = AtomicClock::fromdatetime(datetime, None)?;
-                let duration = datetime.datetime - self.datetime;
+                let duration =
+                    AtomicClock::operand_naive_utc(datetime, false)? - self.datetime.naive_utc();
                 let (days, seconds, microseconds) = normalize_duration(duration);
                 PyDelta::new(py, days as i32, seconds as i32, microseconds as i32, true)
             }
         }
     }
-    fn __hash__(&self) -> i64 {
-        self.datetime.timestamp_nanos()
+    fn __hash__(&self, py: Python) -> PyResult<isize> {
+        if let Some(hash) = self.hash.get() {
+            return Ok(*hash);
+        }
+        // the hash of the aware `datetime` of the same instant, so clocks equal to each other
+        // or to an aware `datetime` hash alike and are interchangeable in sets and dicts.
+        // That's the hash of its UTC `timedelta` from 0001-01-01, computed from the fields so
+        // the instants beyond the years of `datetime` hash too
+        let utc = self.datetime.naive_utc();
+        let days = (utc.date() - NaiveDate::from_ymd(1, 1, 1)).num_days() + 1;
+        let fields: PyObject = (
+            days,
+            utc.num_seconds_from_midnight(),
+            py_microsecond(utc.nanosecond()),
+        )
+            .into_py(py);
+        let hash = fields.as_ref(py).hash()?;
+        // no `datetime` equals a clock with nanoseconds, which are mixed in to tell them apart
+        let hash = hash ^ (utc.nanosecond() % 1000) as isize;
+        Ok(*self.hash.get_or_init(|| hash))
     }
 }
 
@@ -580,12 +1387,13 @@ impl AtomicClock {
 
     #[getter]
     fn second(&self) -> u32 {
-        self.datetime.second()
+        // chrono keeps a leap second as nanoseconds past the 59th second
+        self.datetime.second() + self.datetime.nanosecond() / 1_000_000_000
     }
 
     #[getter]
     fn microsecond(&self) -> u32 {
-        self.datetime.nanosecond() / 1000
+        self.datetime.nanosecond() % 1_000_000_000 / 1000
     }
 
     #[getter]
@@ -598,15 +1406,29 @@ impl AtomicClock {
         (self.month() - 1) / 3 + 1
     }
 
+    #[getter]
+    fn fold(&self) -> i32 {
+        let local = self.datetime.naive_local();
+        match self.datetime.timezone().from_local_datetime(&local) {
+            LocalResult::Ambiguous(_, later) if later == self.datetime => 1,
+            _ => 0,
+        }
+    }
+
     #[getter]
     fn tzinfo(&self, py: Python) -> PyResult<Py<PyAny>> {
-        let py_tz = PyTz::new(self.datetime.timezone());
-        Ok(Py::new(py, py_tz)?.to_object(py))
+        // UTC clocks share a single `Tz`, the most common zone isn't allocated on every access
+        let tz = self.datetime.timezone();
+        if tz == *UTC {
+            return Ok(utc_tzinfo(py)?.to_object(py));
+        }
+        Ok(Py::new(py, PyTz::new(tz))?.to_object(py))
     }
 
     #[getter]
     fn datetime<'p>(&self, py: Python<'p>) -> &'p PyDateTime {
-        PyDateTime::new(
+        // the fold tells the `tzinfo` which of two repeated wall times this is
+        PyDateTime::new_with_fold(
             py,
             self.datetime.year(),
             self.datetime.month() as u8,
@@ -614,8 +1436,9 @@ impl AtomicClock {
             self.datetime.hour() as u8,
             self.datetime.minute() as u8,
             self.datetime.second() as u8,
-            self.datetime.nanosecond() / 1000,
+            py_microsecond(self.datetime.nanosecond()),
             Some(&self.tzinfo(py).unwrap()),
+            self.fold() == 1,
         )
         .unwrap()
     }
@@ -631,7 +1454,7 @@ impl AtomicClock {
             naive_datetime.hour() as u8,
             naive_datetime.minute() as u8,
             naive_datetime.second() as u8,
-            naive_datetime.nanosecond() / 1000,
+            py_microsecond(naive_datetime.nanosecond()),
             None,
         )
         .unwrap()
@@ -642,10 +1465,65 @@ impl AtomicClock {
         self.datetime.timestamp()
     }
 
+    #[getter]
+    fn int_timestamp_ns(&self) -> i128 {
+        self.timestamp_nanos()
+    }
+
     #[getter]
     fn float_timestamp(&self) -> f64 {
         self.timestamp()
     }
+
+    #[getter]
+    fn start_of_day(&self) -> PyResult<Self> {
+        self.floor(Frame::Day, 1, 1, 1, None)
+    }
+
+    #[getter]
+    fn end_of_day(&self) -> PyResult<Self> {
+        self.ceil(Frame::Day, 1, 1, 1, None)
+    }
+
+    #[getter]
+    fn start_of_week(&self) -> PyResult<Self> {
+        self.floor(Frame::Week, 1, 1, 1, None)
+    }
+
+    #[getter]
+    fn end_of_week(&self) -> PyResult<Self> {
+        self.ceil(Frame::Week, 1, 1, 1, None)
+    }
+
+    #[getter]
+    fn start_of_month(&self) -> PyResult<Self> {
+        self.floor(Frame::Month, 1, 1, 1, None)
+    }
+
+    #[getter]
+    fn end_of_month(&self) -> PyResult<Self> {
+        self.ceil(Frame::Month, 1, 1, 1, None)
+    }
+
+    #[getter]
+    fn start_of_quarter(&self) -> PyResult<Self> {
+        self.floor(Frame::Quarter, 1, 1, 1, None)
+    }
+
+    #[getter]
+    fn end_of_quarter(&self) -> PyResult<Self> {
+        self.ceil(Frame::Quarter, 1, 1, 1, None)
+    }
+
+    #[getter]
+    fn start_of_year(&self) -> PyResult<Self> {
+        self.floor(Frame::Year, 1, 1, 1, None)
+    }
+
+    #[getter]
+    fn end_of_year(&self) -> PyResult<Self> {
+        self.ceil(Frame::Year, 1, 1, 1, None)
+    }
 }
 
 // Methods
@@ -654,141 +1532,252 @@ impl AtomicClock {
     #[args(bounds = "Bounds::BothExclude")]
     #[pyo3(text_signature = "(start, end, bounds: \"()\")")]
     fn is_between(&self, start: &Self, end: &Self, bounds: Bounds) -> bool {
-        bounds.is_between(&self.datetime, &start.datetime, &end.datetime)
+        bounds.is_between(
+            self.datetime.naive_utc(),
+            start.datetime.naive_utc(),
+            end.datetime.naive_utc(),
+        )
     }
 
     #[args(
         frame,
         "*",
         count = 1,
+        n = 1,
         bounds = "Bounds::StartInclude",
         exact = "false",
-        week_start = "1"
+        week_start = "1",
+        anchor = "None"
+    )]
+    #[pyo3(
+        text_signature = "(frame, *, count=1, n=1, bounds=\"[)\", exact=False, week_start=1, anchor=None)"
     )]
-    #[pyo3(text_signature = "(frame, *, count=1, bounds=\"[)\", exact=False, week_start=0)")]
+    #[allow(clippy::too_many_arguments)]
     fn span(
         &self,
         frame: Frame,
         count: i64,
+        n: u32,
         bounds: Bounds,
         exact: bool,
         week_start: u32,
+        anchor: Option<DateTimeLike>,
     ) -> PyResult<(Self, Self)> {
         if !matches!(week_start, 1..=7) {
             return Err(exceptions::PyValueError::new_err(
                 "invalid week_start, valid week_start should be 1..7",
             ));
         }
-
-        let mut floor = if exact {
-            self.clone()
-        } else {
-            match frame {
-                Frame::Year => self.replace(
-                    None,
-                    Some(1),
-                    Some(1),
-                    Some(0),
-                    Some(0),
-                    Some(0),
-                    Some(0),
-                    None,
-                )?,
-                Frame::Month => self.replace(
-                    None,
-                    None,
-                    Some(1),
-                    Some(0),
-                    Some(0),
-                    Some(0),
-                    Some(0),
-                    None,
-                )?,
-                Frame::Day => {
-                    self.replace(None, None, None, Some(0), Some(0), Some(0), Some(0), None)?
-                }
-                Frame::Hour => {
-                    self.replace(None, None, None, None, Some(0), Some(0), Some(0), None)?
-                }
-                Frame::Minute => {
-                    self.replace(None, None, None, None, None, Some(0), Some(0), None)?
-                }
-                Frame::Second => self.replace(None, None, None, None, None, None, Some(0), None)?,
-                Frame::Microsecond => {
-                    return Err(exceptions::PyValueError::new_err(
-                        "span doesn't support frame `microsecond`",
-                    ))
-                }
-                Frame::Week => {
-                    let floor =
-                        self.replace(None, None, None, Some(0), Some(0), Some(0), Some(0), None)?;
-                    let delta = if week_start > self.isoweekday() { 7 } else { 0 };
-                    let days = -(self.isoweekday() as i64 - week_start as i64) - delta;
-                    floor.shift(0, 0, days, 0, 0, 0, 0, 0, 0, None)?
-                }
-                Frame::Quarter => self
-                    .replace(
-                        None,
-                        None,
-                        Some(1),
-                        Some(0),
-                        Some(0),
-                        Some(0),
-                        Some(0),
-                        None,
-                    )?
-                    .shift(
-                        0,
-                        -((self.month() - 1) as i64) % 3,
-                        0,
-                        0,
-                        0,
-                        0,
-                        0,
-                        0,
-                        0,
-                        None,
-                    )?,
+        let anchor = anchor.map(|anchor| anchor.to_atomic_clock()).transpose()?;
+        match anchor {
+            Some(_) if exact => {
+                return Err(exceptions::PyValueError::new_err(
+                    "anchor can't be combined with exact",
+                ))
             }
+            Some(_) => frame.check_anchor(count, n)?,
+            None => frame.check_multiple(n)?,
+        }
+        let frames = count
+            .checked_mul(i64::from(n))
+            .filter(|frames| frames.unsigned_abs() <= frame.max_frames())
+            .ok_or_else(|| {
+                RangeError::new_err(format!(
+                    "span is out of range, count * n has to be at most {} for frame `{}`",
+                    frame.max_frames(),
+                    frame.name()
+                ))
+            })?;
+
+        let (mut floor, wall) = if exact {
+            (self.clone(), self.datetime.naive_local())
+        } else if let Some(anchor) = &anchor {
+            let floor = self.anchored_floor(&frame, frames, anchor)?;
+            let wall = floor.datetime.naive_local();
+            (floor, wall)
+        } else {
+            let wall = self.floor_wall(&frame, week_start).ok_or_else(|| {
+                exceptions::PyValueError::new_err("span doesn't support frame `microsecond`")
+            })?;
+            let wall = if n > 1 {
+                Self::align_wall(&frame, n, wall)?
+            } else {
+                wall
+            };
+            (self.frame_start(&frame, &wall)?, wall)
         };
 
-        let mut ceil = AtomicClock {
-            datetime: floor.datetime + frame.duration() * count as f64,
+        let calendar = frame.is_calendar();
+        let duration = frame.clone().duration() * frames as f64;
+        let mut ceil = if calendar {
+            // the next frame starts at the same wall time, whatever DST does in between, or
+            // at the end of a gap skipping it
+            let wall = DateTime::<Utc>::from_utc(wall, Utc) + duration;
+            floor.frame_start(&frame, &wall.naive_utc())?
+        } else {
+            AtomicClock::from(floor.datetime.clone() + duration)
         };
 
         match bounds {
             Bounds::BothInclude => (),
             Bounds::BothExclude => {
-                floor = floor.shift(0, 0, 0, 0, 0, 0, 1, 0, 0, None)?;
-                ceil = ceil.shift(0, 0, 0, 0, 0, 0, -1, 0, 0, None)?;
-            }
-            Bounds::StartInclude => {
-                ceil = ceil.shift(0, 0, 0, 0, 0, 0, -1, 0, 0, None)?;
-            }
-            Bounds::EndInclude => {
-                floor = floor.shift(0, 0, 0, 0, 0, 0, 1, 0, 0, None)?;
+                floor = floor.add_nanos(1_000)?;
+                ceil = ceil.add_nanos(-1_000)?;
             }
+            Bounds::StartInclude => ceil = ceil.add_nanos(-1_000)?,
+            Bounds::EndInclude => floor = floor.add_nanos(1_000)?,
+        }
+        // chrono keeps a leap second past the 59th second, so an open end just before the
+        // next minute is moved past the leap second of `self`
+        let local = self.datetime.naive_local();
+        let end = ceil.datetime.naive_local();
+        if local.nanosecond() >= 1_000_000_000
+            && end.nanosecond() == 999_999_000
+            && (end.date(), end.hour(), end.minute(), end.second())
+                == (local.date(), local.hour(), local.minute(), 59)
+        {
+            ceil = ceil.at_wall_time(end.with_nanosecond(1_999_999_000).unwrap(), None, 0)?;
+        }
+
+        if floor.datetime > ceil.datetime {
+            return Err(exceptions::PyValueError::new_err(
+                "span is empty, the bounds exclude every instant of the timespan",
+            ));
         }
 
         Ok((floor, ceil))
     }
 
-    #[pyo3(text_signature = "(frame)")]
-    fn floor(&self, frame: Frame) -> PyResult<Self> {
-        Ok(self.span(frame, 1, Bounds::StartInclude, false, 1)?.0)
+    #[args(frame, "*", count = 1, n = 1, week_start = "1", anchor = "None")]
+    #[pyo3(text_signature = "(frame, *, count=1, n=1, week_start=1, anchor=None)")]
+    fn floor(
+        &self,
+        frame: Frame,
+        count: i64,
+        n: u32,
+        week_start: u32,
+        anchor: Option<DateTimeLike>,
+    ) -> PyResult<Self> {
+        Ok(self
+            .span(
+                frame,
+                count,
+                n,
+                Bounds::StartInclude,
+                false,
+                week_start,
+                anchor,
+            )?
+            .0)
     }
 
-    #[pyo3(text_signature = "(frame)")]
-    fn ceil(&self, frame: Frame) -> PyResult<Self> {
-        Ok(self.span(frame, 1, Bounds::StartInclude, false, 1)?.1)
+    #[args(frame, "*", count = 1, n = 1, week_start = "1", anchor = "None")]
+    #[pyo3(text_signature = "(frame, *, count=1, n=1, week_start=1, anchor=None)")]
+    fn ceil(
+        &self,
+        frame: Frame,
+        count: i64,
+        n: u32,
+        week_start: u32,
+        anchor: Option<DateTimeLike>,
+    ) -> PyResult<Self> {
+        Ok(self
+            .span(
+                frame,
+                count,
+                n,
+                Bounds::StartInclude,
+                false,
+                week_start,
+                anchor,
+            )?
+            .1)
+    }
+
+    #[args(frame, "*", n = 1, week_start = "1")]
+    #[pyo3(text_signature = "(frame, *, n=1, week_start=1)")]
+    fn at_start_of(&self, frame: Frame, n: u32, week_start: u32) -> PyResult<Self> {
+        self.floor(frame, 1, n, week_start, None)
+    }
+
+    #[args(frame, "*", n = 1, week_start = "1")]
+    #[pyo3(text_signature = "(frame, *, n=1, week_start=1)")]
+    fn at_end_of(&self, frame: Frame, n: u32, week_start: u32) -> PyResult<Self> {
+        self.ceil(frame, 1, n, week_start, None)
+    }
+
+    #[args(
+        frame,
+        "*",
+        count = 1,
+        n = 1,
+        bounds = "Bounds::StartInclude",
+        exact = "false",
+        week_start = "1"
+    )]
+    #[pyo3(text_signature = "(frame, *, count=1, n=1, bounds=\"[)\", exact=False, week_start=1)")]
+    #[allow(clippy::too_many_arguments)]
+    fn timespan(
+        &self,
+        frame: Frame,
+        count: i64,
+        n: u32,
+        bounds: Bounds,
+        exact: bool,
+        week_start: u32,
+    ) -> PyResult<Span> {
+        let (start, end) = self.span(frame, count, n, bounds, exact, week_start, None)?;
+        Ok(Span { start, end })
+    }
+
+    #[args(frame, weekday = "None")]
+    #[pyo3(text_signature = "(frame, weekday=None)")]
+    fn first_of(&self, frame: Frame, weekday: Option<i32>) -> PyResult<Self> {
+        self.day_of(frame, weekday, false)
+    }
+
+    #[args(frame, weekday = "None")]
+    #[pyo3(text_signature = "(frame, weekday=None)")]
+    fn last_of(&self, frame: Frame, weekday: Option<i32>) -> PyResult<Self> {
+        self.day_of(frame, weekday, true)
+    }
+
+    fn last_of_month(&self) -> PyResult<Self> {
+        self.day_of(Frame::Month, None, true)
+    }
+
+    fn is_last_of_month(&self) -> bool {
+        let date = self.datetime.naive_local().date();
+        date == last_day_of_month(date)
+    }
+
+    #[pyo3(text_signature = "(frame, nth, weekday)")]
+    fn nth_of(&self, frame: Frame, nth: i32, weekday: i32) -> PyResult<Option<Self>> {
+        if nth == 0 {
+            return Err(exceptions::PyValueError::new_err(
+                "nth has to be a non-zero int",
+            ));
+        }
+        let (first, last) = self.frame_dates(&frame, "nth_of")?;
+        let weekday = check_weekday(weekday)?;
+
+        // negative `nth` count back from the end of the frame, like negative indices
+        let days = if nth > 0 {
+            let current_weekday = first.weekday().num_days_from_monday() as i64;
+            (weekday - current_weekday).rem_euclid(7) + (nth as i64 - 1) * 7
+        } else {
+            let current_weekday = last.weekday().num_days_from_monday() as i64;
+            -(current_weekday - weekday).rem_euclid(7) + (nth as i64 + 1) * 7
+        };
+        let start = if nth > 0 { first } else { last };
+        match start.checked_add_signed(Duration::days(days)) {
+            Some(date) if first <= date && date <= last => self.on_date(date).map(Some),
+            _ => Ok(None),
+        }
     }
 
     fn timestamp(&self) -> f64 {
-        let nan_timestamp = Decimal::from_i64(self.datetime.timestamp_nanos()).unwrap();
-        nan_timestamp
-            .div(Decimal::from_f64(1e9).unwrap())
-            .to_f64()
-            .unwrap()
+        nanos_to_seconds(self.timestamp_nanos())
     }
 
     fn date<'p>(&self, py: Python<'p>) -> &'p PyDate {
@@ -807,24 +1796,30 @@ impl AtomicClock {
             self.datetime.hour() as u8,
             self.datetime.minute() as u8,
             self.datetime.second() as u8,
-            self.datetime.nanosecond() / 1000,
+            py_microsecond(self.datetime.nanosecond()),
             None,
         )
         .unwrap()
     }
 
-    fn timez<'p>(&self, py: Python<'p>) -> &'p PyTime {
-        PyTime::new(
+    fn timetz<'p>(&self, py: Python<'p>) -> &'p PyTime {
+        PyTime::new_with_fold(
             py,
             self.datetime.hour() as u8,
             self.datetime.minute() as u8,
             self.datetime.second() as u8,
-            self.datetime.nanosecond() / 1000,
+            py_microsecond(self.datetime.nanosecond()),
             Some(&self.tzinfo(py).unwrap()),
+            self.fold() == 1,
         )
         .unwrap()
     }
 
+    /// The original misspelling of `timetz`, kept for compatibility.
+    fn timez<'p>(&self, py: Python<'p>) -> &'p PyTime {
+        self.timetz(py)
+    }
+
     #[args(tz = "None")]
     #[pyo3(text_signature = "(tz = None)")]
     fn astimezone<'p>(&self, py: Python<'p>, tz: Option<PyTzLike>) -> PyResult<&'p PyDateTime> {
@@ -836,23 +1831,27 @@ impl AtomicClock {
     }
 
     fn utcoffset<'p>(&self, py: Python<'p>) -> &'p PyDelta {
-        let seconds = match self.datetime.timezone() {
-            HybridTz::Offset(offset) => offset.local_minus_utc(),
-            HybridTz::Timespan(timespan) => UTC_NOW
-                .with_timezone(&timespan)
-                .offset()
-                .fix()
-                .local_minus_utc(),
-        };
-
+        let seconds = self.datetime.offset().fix().local_minus_utc();
         PyDelta::new(py, 0, seconds, 0, true).unwrap()
     }
 
+    #[args(colon = "true", "*", utc_as_z = "false")]
+    #[pyo3(text_signature = "(colon = True, *, utc_as_z = False)")]
+    fn offset_string(&self, colon: bool, utc_as_z: bool) -> String {
+        match self.datetime.offset().fix().local_minus_utc() {
+            0 if utc_as_z => "Z".to_owned(),
+            seconds => formatter::format_offset(seconds, colon),
+        }
+    }
+
     fn dst<'p>(&self, py: Python<'p>) -> &'p PyDelta {
         PyDelta::new(
             py,
             0,
-            self.datetime.timezone().dst_offset().num_seconds() as i32,
+            self.datetime
+                .timezone()
+                .dst_offset_at(&self.datetime.with_timezone(&Utc))
+                .num_seconds() as i32,
             0,
             true,
         )
@@ -872,24 +1871,69 @@ impl AtomicClock {
         duration.num_days() + 1
     }
 
+    fn julian_day(&self) -> f64 {
+        units_since(
+            &self.datetime.naive_utc(),
+            JULIAN_EPOCH_NANOS,
+            NANOS_PER_DAY,
+        )
+    }
+
+    fn mjd(&self) -> f64 {
+        units_since(
+            &self.datetime.naive_utc(),
+            MODIFIED_JULIAN_EPOCH_NANOS,
+            NANOS_PER_DAY,
+        )
+    }
+
+    fn gps_seconds(&self) -> f64 {
+        units_since(
+            &self.datetime.naive_utc(),
+            GPS_EPOCH_NANOS,
+            NANOS_PER_SECOND,
+        )
+    }
+
     fn weekday(&self) -> u32 {
         self.datetime.weekday().num_days_from_monday()
     }
 
     fn isoweekday(&self) -> u32 {
-        self.datetime.weekday().num_days_from_sunday()
+        self.datetime.weekday().number_from_monday()
     }
 
     fn isocalendar(&self) -> IsoCalendarDate {
+        let (year, week, weekday) = self.iso_calendar();
+        IsoCalendarDate(vec![year, week, weekday])
+    }
+
+    #[args(convention = "WeekConvention::Iso")]
+    #[pyo3(text_signature = "(convention='iso')")]
+    fn week_of_year(&self, convention: WeekConvention) -> u32 {
+        let ordinal0 = self.datetime.ordinal0();
+        let weekday = self.datetime.weekday();
+        match convention {
+            WeekConvention::Iso => self.datetime.iso_week().week(),
+            WeekConvention::Sunday => (ordinal0 + 7 - weekday.num_days_from_sunday()) / 7,
+            WeekConvention::Monday => (ordinal0 + 7 - weekday.num_days_from_monday()) / 7,
+        }
+    }
+
+    #[args(with_day = "false")]
+    #[pyo3(text_signature = "(with_day=False)")]
+    fn format_isoweek(&self, with_day: bool) -> String {
         let iso_week = self.datetime.iso_week();
-        IsoCalendarDate(vec![
-            iso_week.year() as u32,
-            iso_week.week(),
-            self.isoweekday(),
-        ])
+        let week = format!("{:04}-W{:02}", iso_week.year(), iso_week.week());
+        if with_day {
+            format!("{}-{}", week, self.datetime.weekday().number_from_monday())
+        } else {
+            week
+        }
     }
 
     fn ctime(&self) -> String {
+        // chrono always uses English names, `%e` pads the day with a space like C's asctime
         self.datetime.format("%a %b %e %T %Y").to_string()
     }
 
@@ -904,28 +1948,70 @@ impl AtomicClock {
     #[args(sep = "\"T\"", timespec = "\"auto\"")]
     #[pyo3(text_signature = "(spec = \"T\", timespec = \"auto\")")]
     fn isoformat(&self, sep: &str, timespec: &str) -> PyResult<String> {
-        let format = match timespec {
-            "auto" | "microseconds" => format!("%Y-%m-%d{sep}%H:%M:%S%.f%:z"),
-            "hours" => format!("%Y-%m-%d{sep}%H%:z"),
-            "minutes" => format!("%Y-%m-%d{sep}%H:%M%:z"),
-            "seconds" => format!("%Y-%m-%d{sep}%H:%M:%S%:z"),
-            "milliseconds" => format!("%Y-%m-%d{sep}%H:%M:%S%.3f%:z"),
+        let timespec = match timespec {
+            "auto" | "microseconds" => Timespec::Auto,
+            "hours" => Timespec::Hours,
+            "minutes" => Timespec::Minutes,
+            "seconds" => Timespec::Seconds,
+            "milliseconds" => Timespec::Milliseconds,
+            "nanoseconds" => Timespec::Nanoseconds,
             _ => return Err(exceptions::PyValueError::new_err("Unknown timespec value")),
         };
-        Ok(self.datetime.format(&format).to_string())
+        Ok(formatter::format_iso(&self.datetime, sep, timespec)
+            .unwrap_or_else(|| self.datetime.format(&timespec.strftime(sep)).to_string()))
     }
 
-    fn clone(&self) -> Self {
-        Clone::clone(self)
+    fn to_rfc2822(&self) -> String {
+        self.datetime.to_rfc2822()
     }
 
-    #[args("*", year, month, day, hour, minute, second, microsecond, tzinfo)]
-    #[pyo3(
-        text_signature = "(*, year=None, month=None, day=None, hour=None, minute=None, second=None, microsecond=None, tzinfo=None)"
-    )]
-    #[allow(clippy::too_many_arguments)]
-    fn replace(
-        &self,
+    #[args(timespec = "\"auto\"")]
+    #[pyo3(text_signature = "(timespec = \"auto\")")]
+    fn to_rfc3339(&self, timespec: &str) -> PyResult<String> {
+        use chrono::SecondsFormat;
+
+        let seconds_format = match timespec {
+            "auto" => SecondsFormat::AutoSi,
+            "seconds" => SecondsFormat::Secs,
+            "milliseconds" => SecondsFormat::Millis,
+            "microseconds" => SecondsFormat::Micros,
+            _ => return Err(exceptions::PyValueError::new_err("Unknown timespec value")),
+        };
+        Ok(self.datetime.to_rfc3339_opts(seconds_format, true))
+    }
+
+    fn to_httpdate(&self) -> String {
+        self.datetime
+            .naive_utc()
+            .format("%a, %d %b %Y %H:%M:%S GMT")
+            .to_string()
+    }
+
+    fn clone(&self) -> Self {
+        Clone::clone(self)
+    }
+
+    #[args(
+        "*",
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        microsecond,
+        nanosecond,
+        tzinfo,
+        fold,
+        delta,
+        day_clamp = "false"
+    )]
+    #[pyo3(
+        text_signature = "(*, year=None, month=None, day=None, hour=None, minute=None, second=None, microsecond=None, nanosecond=None, tzinfo=None, fold=None, delta=None, day_clamp=False)"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn replace(
+        &self,
         year: Option<i32>,
         month: Option<u32>,
         day: Option<u32>,
@@ -933,65 +2019,124 @@ impl AtomicClock {
         minute: Option<u32>,
         second: Option<u32>,
         microsecond: Option<u32>,
-        tzinfo: Option<PyTzLike>,
+        nanosecond: Option<u32>,
+        tzinfo: Option<TzLikeOrOffset>,
+        fold: Option<i32>,
+        delta: Option<PyRelativeDelta>,
+        day_clamp: bool,
     ) -> PyResult<Self> {
-        let mut obj = self.clone();
+        let mut naive = self.datetime.naive_local();
 
-        if let Some(year) = year {
-            obj.datetime = obj
-                .datetime
-                .with_year(year)
+        if day_clamp {
+            // the date is replaced at once, with the day clamped to the end of the month
+            let month = month.unwrap_or_else(|| naive.month());
+            if !(1..=12).contains(&month) {
+                return Err(exceptions::PyValueError::new_err("invalid month"));
+            }
+            let first = NaiveDate::from_ymd_opt(year.unwrap_or_else(|| naive.year()), month, 1)
                 .ok_or_else(|| exceptions::PyValueError::new_err("invalid year"))?;
-        }
+            let day = day.unwrap_or_else(|| naive.day());
+            // a day no month has isn't clamped
+            if !(1..=31).contains(&day) {
+                return Err(exceptions::PyValueError::new_err("invalid day"));
+            }
+            let date = first
+                .with_day(day.min(last_day_of_month(first).day()))
+                .unwrap();
+            naive = date.and_time(naive.time());
+        } else {
+            if let Some(year) = year {
+                naive = naive
+                    .with_year(year)
+                    .ok_or_else(|| exceptions::PyValueError::new_err("invalid year"))?;
+            }
 
-        if let Some(month) = month {
-            obj.datetime = obj
-                .datetime
-                .with_month(month)
-                .ok_or_else(|| exceptions::PyValueError::new_err("invalid month"))?;
-        }
+            if let Some(month) = month {
+                naive = naive
+                    .with_month(month)
+                    .ok_or_else(|| exceptions::PyValueError::new_err("invalid month"))?;
+            }
 
-        if let Some(day) = day {
-            obj.datetime = obj
-                .datetime
-                .with_day(day)
-                .ok_or_else(|| exceptions::PyValueError::new_err("invalid day"))?;
+            if let Some(day) = day {
+                naive = naive
+                    .with_day(day)
+                    .ok_or_else(|| exceptions::PyValueError::new_err("invalid day"))?;
+            }
         }
 
         if let Some(hour) = hour {
-            obj.datetime = obj
-                .datetime
+            naive = naive
                 .with_hour(hour)
                 .ok_or_else(|| exceptions::PyValueError::new_err("invalid hour"))?;
         }
 
         if let Some(minute) = minute {
-            obj.datetime = obj
-                .datetime
+            naive = naive
                 .with_minute(minute)
                 .ok_or_else(|| exceptions::PyValueError::new_err("invalid minute"))?;
         }
 
         if let Some(second) = second {
-            obj.datetime = obj
-                .datetime
+            // a leap second is left, its fraction is kept
+            naive = naive
                 .with_second(second)
+                .and_then(|naive| naive.with_nanosecond(naive.nanosecond() % 1_000_000_000))
                 .ok_or_else(|| exceptions::PyValueError::new_err("invalid second"))?;
         }
+        let leap_nanos = naive.nanosecond() / 1_000_000_000 * 1_000_000_000;
 
-        if let Some(microsecond) = microsecond {
-            obj.datetime = obj
-                .datetime
-                .with_nanosecond(microsecond * 1000)
-                .ok_or_else(|| exceptions::PyValueError::new_err("invalid microsecond"))?;
+        // both set the whole fraction of the second, nanoseconds left behind are cleared
+        match (microsecond, nanosecond) {
+            (Some(_), Some(_)) => {
+                return Err(exceptions::PyValueError::new_err(
+                    "microsecond and nanosecond can't be replaced together",
+                ))
+            }
+            (Some(microsecond), None) => {
+                naive = microsecond
+                    .checked_mul(1000)
+                    .filter(|nanos| *nanos < 1_000_000_000)
+                    .and_then(|nanos| naive.with_nanosecond(leap_nanos + nanos))
+                    .ok_or_else(|| exceptions::PyValueError::new_err("invalid microsecond"))?;
+            }
+            (None, Some(nanosecond)) => {
+                naive = Some(nanosecond)
+                    .filter(|nanos| *nanos < 1_000_000_000)
+                    .and_then(|nanos| naive.with_nanosecond(leap_nanos + nanos))
+                    .ok_or_else(|| exceptions::PyValueError::new_err("invalid nanosecond"))?;
+            }
+            (None, None) => (),
         }
 
-        if let Some(tzinfo) = tzinfo {
-            let tz = tzinfo.try_to_tz()?;
-            obj.datetime = tz.from_local_datetime(&obj.datetime.naive_local()).unwrap()
+        let fold = fold.unwrap_or_else(|| self.fold());
+        let replaced = self.at_wall_time(naive, tzinfo, fold)?;
+
+        // fields are replaced first, then the delta is shifted (weekday last)
+        match delta {
+            Some(delta) => replaced.__add__(DeltaLike::RelativeDelta(delta)),
+            None => Ok(replaced),
         }
+    }
 
-        Ok(obj)
+    #[pyo3(text_signature = "(year, month, day)")]
+    fn on(&self, year: i32, month: u32, day: u32) -> PyResult<Self> {
+        // the whole date at once, so a day past the end of the current month is fine
+        let date = NaiveDate::from_ymd_opt(year, month, day)
+            .ok_or_else(|| exceptions::PyValueError::new_err("invalid date"))?;
+        self.on_date(date)
+    }
+
+    #[args(hour, minute = "0", second = "0", microsecond = "0")]
+    #[pyo3(text_signature = "(hour, minute=0, second=0, microsecond=0)")]
+    fn at(&self, hour: u32, minute: u32, second: u32, microsecond: u32) -> PyResult<Self> {
+        let invalid = || exceptions::PyValueError::new_err("invalid time");
+        // chrono takes a microsecond past 999_999 as a leap second
+        if microsecond > 999_999 {
+            return Err(invalid());
+        }
+        let time =
+            NaiveTime::from_hms_micro_opt(hour, minute, second, microsecond).ok_or_else(invalid)?;
+        self.with_time(time)
     }
 
     #[args(
@@ -1005,10 +2150,14 @@ impl AtomicClock {
         microseconds = 0,
         weeks = 0,
         quarters = 0,
-        weekday = "None"
+        weekday = "None",
+        weekday_backwards = "false",
+        timedelta = "None",
+        anchor = "Anchor::Preserve",
+        clamp = "false"
     )]
     #[pyo3(
-        text_signature = "(*, years=0, months=0, days=0, hours=0, minutes=0, seconds=0, microseconds=0, weeks=0, quarters=0, weekday=None)"
+        text_signature = "(*, years=0, months=0, days=0, hours=0, minutes=0, seconds=0, microseconds=0, weeks=0, quarters=0, weekday=None, weekday_backwards=False, timedelta=None, anchor=\"preserve\", clamp=False)"
     )]
     #[allow(clippy::too_many_arguments)]
     fn shift(
@@ -1023,52 +2172,176 @@ impl AtomicClock {
         weeks: i64,
         quarters: i64,
         weekday: Option<i32>,
+        weekday_backwards: bool,
+        timedelta: Option<&PyDelta>,
+        anchor: Anchor,
+        clamp: bool,
     ) -> PyResult<Self> {
-        let mut obj = self.clone();
+        let months = i128::from(years) * 12 + i128::from(months) + i128::from(quarters) * 3;
+        let nanos = elapsed_nanos(weeks, days, hours, minutes, seconds, microseconds)
+            + timedelta.map_or(0, timedelta_nanos);
+        let weekday = weekday.map(check_weekday).transpose()?;
+        let month_end =
+            matches!(anchor, Anchor::MonthEnd) && months != 0 && self.is_last_of_month();
+        self.shifted(months, nanos, weekday, weekday_backwards, month_end, clamp)
+    }
 
-        let delta = RelativeDelta::with_years(years)
-            .and_months(months + quarters * 3)
-            .and_days(days + weeks * 7)
-            .and_hours(hours)
-            .and_minutes(minutes)
-            .and_seconds(seconds)
-            .and_nanoseconds(microseconds * 1000)
-            .new();
+    #[pyo3(text_signature = "(tzinfo)")]
+    fn to(&self, tzinfo: PyTzLike) -> PyResult<Self> {
+        let tz = if tzinfo.is_local() {
+            HybridTz::local_at(&self.datetime.naive_utc())
+        } else {
+            tzinfo.try_to_tz()?
+        };
+        Ok(Self::from(self.datetime.with_timezone(&tz)))
+    }
 
-        obj.datetime = obj.datetime + delta;
+    #[pyo3(text_signature = "(tzinfo)")]
+    fn with_tz(&self, tzinfo: PyTzLike) -> PyResult<Self> {
+        self.to(tzinfo)
+    }
 
-        if let Some(weekday) = weekday {
-            if !matches!(weekday, 0..=6) {
-                return Err(exceptions::PyIndexError::new_err(
-                    "invalid weekday, valid weekday should be 0..6",
-                ));
-            }
+    #[args(tzinfo = "None")]
+    #[pyo3(text_signature = "(tzinfo = None)")]
+    fn to_naive<'p>(&self, py: Python<'p>, tzinfo: Option<PyTzLike>) -> PyResult<&'p PyDateTime> {
+        let naive_datetime = match tzinfo {
+            Some(tzinfo) => self.to(tzinfo)?.datetime.naive_local(),
+            None => self.datetime.naive_local(),
+        };
+        PyDateTime::new(
+            py,
+            naive_datetime.year(),
+            naive_datetime.month() as u8,
+            naive_datetime.day() as u8,
+            naive_datetime.hour() as u8,
+            naive_datetime.minute() as u8,
+            naive_datetime.second() as u8,
+            py_microsecond(naive_datetime.nanosecond()),
+            None,
+        )
+    }
 
-            let current_weekday = obj.datetime.weekday().num_days_from_monday();
-            let weekday = weekday as u32;
-            if current_weekday <= weekday {
-                obj.datetime = obj.datetime + Duration::days((weekday - current_weekday) as i64)
-            } else {
-                let jumpdays =
-                    (current_weekday - (current_weekday - weekday)) + (6 - current_weekday) + 1;
-                obj.datetime = obj.datetime + Duration::days(jumpdays as i64);
-            }
+    #[args(
+        fmt = "\"%Y-%m-%d %H:%M:%S%:z\"",
+        locale = "\"en\"",
+        "*",
+        tokens = "false"
+    )]
+    #[pyo3(text_signature = "(fmt = \"%Y-%m-%d %H:%M:%S%:z\", locale = \"en\", *, tokens = False)")]
+    fn format(&self, fmt: &str, locale: &str, tokens: bool) -> PyResult<String> {
+        let locale = locales::get_locale(locale)?;
+        if tokens {
+            Ok(formatter::format_tokens(&self.datetime, fmt, locale))
+        } else {
+            Ok(formatter::strftime(&self.datetime, fmt, locale))
         }
-        Ok(obj)
     }
 
-    #[pyo3(text_signature = "(tzinfo)")]
-    fn to(&self, tzinfo: PyTzLike) -> PyResult<Self> {
-        let tz = tzinfo.try_to_tz()?;
-        Ok(Self {
-            datetime: self.datetime.with_timezone(&tz),
-        })
+    #[args(locale = "\"en\"")]
+    #[pyo3(text_signature = "(locale = \"en\")")]
+    fn ordinal_day(&self, locale: &str) -> PyResult<String> {
+        let locale = locales::get_locale(locale)?;
+        Ok((locale.ordinal)(self.datetime.day()))
+    }
+
+    #[args(other = "None", locale = "\"en\"")]
+    #[pyo3(text_signature = "(other = None, locale = \"en\")")]
+    fn humanize(&self, other: Option<DateTimeLike>, locale: &str) -> PyResult<String> {
+        let locale = locales::get_locale(locale)?;
+        let seconds = -self.nanos_until(other)? / 1_000_000_000;
+        let future = seconds > 0;
+        let delta = seconds.unsigned_abs() as u64;
+
+        let (timeframe, count) = match delta {
+            0..=9 => return Ok(locale.now.to_string()),
+            10..=44 => (Timeframe::Second, delta),
+            45..=89 => (Timeframe::Minute, 1),
+            90..=2699 => (Timeframe::Minute, (delta / 60).max(2)),
+            2700..=5399 => (Timeframe::Hour, 1),
+            5400..=79199 => (Timeframe::Hour, (delta / 3600).max(2)),
+            79200..=129599 => (Timeframe::Day, 1),
+            129600..=554399 => (Timeframe::Day, (delta / 86400).max(2)),
+            554400..=907199 => (Timeframe::Week, 1),
+            907200..=2419199 => (Timeframe::Week, (delta / 604800).max(2)),
+            2419200..=3887999 => (Timeframe::Month, 1),
+            3888000..=29807999 => (Timeframe::Month, (delta / 2592000).max(2)),
+            29808000..=47303999 => (Timeframe::Year, 1),
+            _ => (Timeframe::Year, (delta / 31536000).max(2)),
+        };
+        Ok(locale.relative(timeframe, count, future))
     }
 
-    #[args(fmt = "\"%Y-%m-%d %H:%M:%S%:z\"")]
-    #[pyo3(text_signature = "(fmt = \"%Y-%m-%d %H:%M:%S%:z\")")]
-    fn format(&self, fmt: &str) -> String {
-        self.datetime.format(fmt).to_string()
+    #[pyo3(text_signature = "(other)")]
+    fn detect_frame(&self, other: DateTimeLike) -> PyResult<&'static str> {
+        let other = other.to_atomic_clock()?;
+        let (earlier, later) = if self.datetime <= other.datetime {
+            (&self.datetime, &other.datetime)
+        } else {
+            (&other.datetime, &self.datetime)
+        };
+
+        let frame = Frame::DESCENDING
+            .into_iter()
+            .find(|frame| earlier.clone() + frame.clone().duration() <= *later)
+            .unwrap_or(Frame::Microsecond);
+        Ok(frame.name())
+    }
+
+    #[args(other = "None")]
+    #[pyo3(text_signature = "(other = None)")]
+    fn seconds_since(&self, other: Option<DateTimeLike>) -> PyResult<f64> {
+        Ok(nanos_to_seconds(self.nanos_until(other)?))
+    }
+
+    #[args(other = "None")]
+    #[pyo3(text_signature = "(other = None)")]
+    fn seconds_until(&self, other: Option<DateTimeLike>) -> PyResult<f64> {
+        Ok(nanos_to_seconds(-self.nanos_until(other)?))
+    }
+
+    #[args(other = "None")]
+    #[pyo3(text_signature = "(other = None)")]
+    fn years_since(&self, other: Option<DateTimeLike>) -> PyResult<i32> {
+        self.years_to(other)
+    }
+
+    #[args(other = "None")]
+    #[pyo3(text_signature = "(other = None)")]
+    fn years_until(&self, other: Option<DateTimeLike>) -> PyResult<i32> {
+        Ok(-self.years_to(other)?)
+    }
+
+    #[args(other = "None")]
+    #[pyo3(text_signature = "(other = None)")]
+    fn age<'p>(&self, py: Python<'p>, other: Option<DateTimeLike>) -> PyResult<&'p PyDelta> {
+        let other = match other {
+            Some(other) => other.to_atomic_clock()?,
+            None => Self::utcnow()?,
+        };
+        let (days, seconds, microseconds) =
+            normalize_duration(other.datetime - self.datetime.clone());
+        PyDelta::new(py, days as i32, seconds as i32, microseconds as i32, true)
+    }
+
+    #[args("*", assume_utc = "false")]
+    #[pyo3(text_signature = "(other, *, assume_utc = False)")]
+    fn diff<'p>(
+        &self,
+        py: Python<'p>,
+        other: DateTimeLike,
+        assume_utc: bool,
+    ) -> PyResult<&'p PyDelta> {
+        let duration = self.datetime.naive_utc() - other.operand_naive_utc(assume_utc)?;
+        let (days, seconds, microseconds) = normalize_duration(duration);
+        PyDelta::new(py, days as i32, seconds as i32, microseconds as i32, true)
+    }
+
+    #[args("*", assume_utc = "false")]
+    #[pyo3(text_signature = "(other, *, assume_utc = False)")]
+    fn diff_ns(&self, other: DateTimeLike, assume_utc: bool) -> PyResult<i128> {
+        Ok(duration_nanos(
+            self.datetime.naive_utc() - other.operand_naive_utc(assume_utc)?,
+        ))
     }
 }
 
@@ -1099,6 +2372,22 @@ impl IsoCalendarDate {
         self.0[2]
     }
 
+    fn __len__(&self) -> usize {
+        self.0.len()
+    }
+
+    fn __getitem__(&self, index: isize) -> PyResult<u32> {
+        // negative indices count from the end, as for a tuple
+        let len = self.0.len() as isize;
+        let index = if index < 0 { index + len } else { index };
+        if !(0..len).contains(&index) {
+            return Err(exceptions::PyIndexError::new_err(
+                "IsoCalendarDate index out of range",
+            ));
+        }
+        Ok(self.0[index as usize])
+    }
+
     fn __iter__(slf: PyRef<Self>) -> PyResult<Py<IsoCalendarDateIter>> {
         let iter = IsoCalendarDateIter {
             inner: slf.0.clone().into_iter(),
@@ -1123,6 +2412,155 @@ impl IsoCalendarDateIter {
     }
 }
 
+#[pyclass]
+struct Span {
+    #[pyo3(get)]
+    start: AtomicClock,
+    #[pyo3(get)]
+    end: AtomicClock,
+}
+
+#[pymethods]
+impl Span {
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        let start = Py::new(py, self.start.clone())?;
+        let end = Py::new(py, self.end.clone())?;
+        Ok(format!(
+            "Span(start={}, end={})",
+            start.as_ref(py).repr()?,
+            end.as_ref(py).repr()?
+        ))
+    }
+
+    fn __iter__(slf: PyRef<Self>) -> PyResult<Py<SpanIter>> {
+        let iter = SpanIter {
+            inner: vec![slf.start.clone(), slf.end.clone()].into_iter(),
+        };
+        Py::new(slf.py(), iter)
+    }
+}
+
+#[pyclass]
+struct SpanIter {
+    inner: std::vec::IntoIter<AtomicClock>,
+}
+
+#[pymethods]
+impl SpanIter {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<Self>) -> Option<AtomicClock> {
+        slf.inner.next()
+    }
+}
+
+#[pyclass(module = "atomic_clock")]
+#[pyo3(text_signature = "(start, end=None)")]
+#[derive(Clone)]
+pub struct Period {
+    #[pyo3(get)]
+    start: AtomicClock,
+    #[pyo3(get)]
+    end: AtomicClock,
+}
+
+impl Period {
+    fn nanos(&self) -> i128 {
+        duration_nanos(self.end.datetime.naive_utc() - self.start.datetime.naive_utc())
+    }
+}
+
+#[pymethods]
+impl Period {
+    #[new]
+    #[args(start, end = "None")]
+    fn new(start: RangeStart, end: Option<DateTimeLike>) -> PyResult<Self> {
+        let (start, end) = start.resolve_bounded(end)?;
+        if end.datetime < start.datetime {
+            return Err(exceptions::PyValueError::new_err("end is less than start"));
+        }
+        Ok(Self { start, end })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<Period [{}, {}]>", self.start.to_str(), self.end.to_str())
+    }
+
+    fn __richcmp__(&self, py: Python, other: PyRef<Self>, op: CompareOp) -> PyObject {
+        let left = (&self.start.datetime, &self.end.datetime);
+        let right = (&other.start.datetime, &other.end.datetime);
+        match op {
+            CompareOp::Eq => (left == right).into_py(py),
+            CompareOp::Ne => (left != right).into_py(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self, py: Python) -> PyResult<isize> {
+        Ok(self.start.__hash__(py)? ^ self.end.__hash__(py)?.rotate_left(1))
+    }
+
+    fn __iter__(&self, py: Python) -> PyResult<Py<DatetimeRangeIter>> {
+        self.range(py, Frame::Day)
+    }
+
+    /// The number of days of the default iteration, `start` included. The days are
+    /// steps of 24 hours, so they are counted from the elapsed time.
+    fn __len__(&self) -> usize {
+        self.in_days() as usize + 1
+    }
+
+    fn __contains__(&self, clock: DateTimeLike) -> PyResult<bool> {
+        self.contains(clock, Bounds::BothInclude)
+    }
+
+    #[pyo3(text_signature = "(frame)")]
+    fn range(&self, py: Python, frame: Frame) -> PyResult<Py<DatetimeRangeIter>> {
+        AtomicClock::range(
+            py,
+            frame,
+            RangeStart::DateTimeLike(DateTimeLike::AtomicClock(self.start.clone())),
+            Some(DateTimeLike::AtomicClock(self.end.clone())),
+            None,
+            false,
+            None,
+            None,
+        )
+    }
+
+    #[args(clock, bounds = "Bounds::BothInclude")]
+    #[pyo3(text_signature = "(clock, bounds=\"[]\")")]
+    fn contains(&self, clock: DateTimeLike, bounds: Bounds) -> PyResult<bool> {
+        Ok(bounds.is_between(
+            clock.naive_utc()?,
+            self.start.datetime.naive_utc(),
+            self.end.datetime.naive_utc(),
+        ))
+    }
+
+    fn in_weeks(&self) -> i64 {
+        (self.nanos() / (7 * NANOS_PER_DAY)) as i64
+    }
+
+    fn in_days(&self) -> i64 {
+        (self.nanos() / NANOS_PER_DAY) as i64
+    }
+
+    fn in_hours(&self) -> i64 {
+        (self.nanos() / 3_600_000_000_000) as i64
+    }
+
+    fn in_minutes(&self) -> i64 {
+        (self.nanos() / 60_000_000_000) as i64
+    }
+
+    fn in_seconds(&self) -> i64 {
+        (self.nanos() / 1_000_000_000) as i64
+    }
+}
+
 #[derive(Clone)]
 enum Bounds {
     BothInclude,
@@ -1135,13 +2573,14 @@ impl FromPyObject<'_> for Bounds {
     fn extract<'source>(ob: &pyo3::PyAny) -> PyResult<Self> {
         let bound = ob.extract::<&str>()?;
         let bound = match bound {
-            "[]" => Self::BothInclude,
-            "()" => Self::BothExclude,
-            "[)" => Self::StartInclude,
-            "(]" => Self::EndInclude,
+            "[]" | "both" => Self::BothInclude,
+            "()" | "neither" => Self::BothExclude,
+            "[)" | "left" | "start" => Self::StartInclude,
+            "(]" | "right" | "end" => Self::EndInclude,
             _ => {
                 return Err(exceptions::PyValueError::new_err(
-                    "invalid bound, valid bound should be '[]', '()', '[)' and '(]'",
+                    "invalid bound, valid bound should be '[]', '()', '[)', '(]', \
+                     'both', 'neither', 'left', 'start', 'right' and 'end'",
                 ))
             }
         };
@@ -1149,151 +2588,1017 @@ impl FromPyObject<'_> for Bounds {
     }
 }
 
-impl Bounds {
-    fn is_between(
-        &self,
-        dt: &DateTime<HybridTz>,
-        start: &DateTime<HybridTz>,
-        end: &DateTime<HybridTz>,
-    ) -> bool {
-        match self {
-            Self::BothInclude => {
-                start.timestamp_nanos() <= dt.timestamp_nanos()
-                    && dt.timestamp_nanos() <= end.timestamp_nanos()
-            }
-            Self::BothExclude => {
-                start.timestamp_nanos() < dt.timestamp_nanos()
-                    && dt.timestamp_nanos() < end.timestamp_nanos()
-            }
-            Self::StartInclude => {
-                start.timestamp_nanos() <= dt.timestamp_nanos()
-                    && dt.timestamp_nanos() < end.timestamp_nanos()
-            }
-            Self::EndInclude => {
-                start.timestamp_nanos() < dt.timestamp_nanos()
-                    && dt.timestamp_nanos() <= end.timestamp_nanos()
-            }
+impl Bounds {
+    fn is_between(&self, dt: NaiveDateTime, start: NaiveDateTime, end: NaiveDateTime) -> bool {
+        match self {
+            Self::BothInclude => start <= dt && dt <= end,
+            Self::BothExclude => start < dt && dt < end,
+            Self::StartInclude => start <= dt && dt < end,
+            Self::EndInclude => start < dt && dt <= end,
+        }
+    }
+}
+
+#[pyfunction(tzinfo = "PyTzLike::String(\"local\")")]
+#[pyo3(text_signature = "(tzinfo = \"local\")")]
+pub(crate) fn now(tzinfo: PyTzLike) -> PyResult<AtomicClock> {
+    AtomicClock::now(tzinfo)
+}
+
+#[pyfunction]
+pub(crate) fn utcnow() -> PyResult<AtomicClock> {
+    AtomicClock::utcnow()
+}
+
+#[pyfunction]
+#[pyo3(text_signature = "(interval)")]
+pub(crate) fn parse_interval(interval: &str) -> PyResult<(AtomicClock, AtomicClock)> {
+    parse_interval_str(interval)
+}
+
+#[pyfunction(py_args = "*", tzinfo = "None")]
+#[pyo3(text_signature = "(*args, tzinfo=None)")]
+pub(crate) fn get(
+    py_args: &PyTuple,
+    mut tzinfo: Option<PyTzLike>,
+) -> PyResult<Option<AtomicClock>> {
+    let datetime = match py_args.len() {
+        0 => AtomicClock::utcnow(),
+        1 => {
+            let arg = &py_args[0];
+
+            // a missing value passes through, as from a nullable column
+            if arg.is_none() {
+                return Ok(None);
+            } else if let Ok(dt) = arg.extract::<AtomicClock>() {
+                Ok(dt)
+            } else if let Ok(timestamp) = arg.extract::<f64>() {
+                AtomicClock::fromtimestamp(timestamp, PyTzLike::utc())
+            } else if let Ok(timestamp) = arg.extract::<i64>() {
+                AtomicClock::fromtimestamp(timestamp as f64, PyTzLike::utc())
+            } else if let Ok(datetime) = arg.extract::<&str>() {
+                return get_str(datetime, tzinfo).map(Some);
+            } else if let Ok(bytes) = arg.downcast::<PyBytes>() {
+                return get_str(str_from_utf8(bytes.as_bytes())?, tzinfo).map(Some);
+            } else if let Ok(bytes) = arg.downcast::<PyByteArray>() {
+                return get_str(str_from_utf8(&bytes.to_vec())?, tzinfo).map(Some);
+            } else if let Ok(tz) = arg.extract::<PyTzLike>() {
+                AtomicClock::now(tz)
+            } else if let Ok(datetime) = arg.extract::<&PyDateTime>() {
+                AtomicClock::fromdatetime(datetime, None)
+            } else if let Ok(date) = arg.extract::<&PyDate>() {
+                AtomicClock::fromdate(date, PyTzLike::String("UTC"))
+            } else if arg.hasattr("tm_year")? {
+                from_struct_time(arg)
+            } else if arg.is_instance_of::<PyTuple>()? || arg.is_instance_of::<PyList>()? {
+                let components = arg.iter()?.collect::<PyResult<Vec<_>>>()?;
+                if !(3..=8).contains(&components.len()) {
+                    return Err(ParserError::new_err(format!(
+                        "invalid datetime tuple of {} elements, expected 3 to 8 elements \
+                         (year, month, day[, hour, minute, second, microsecond, tzinfo])",
+                        components.len()
+                    )));
+                }
+                from_components(&components)
+            } else {
+                Err(unsupported_arg_error(arg)?)
+            }
+        }
+        2 => {
+            let arg1 = &py_args[0];
+            let arg2 = &py_args[1];
+
+            if let (Ok(datetime), Ok(tz)) =
+                (arg1.extract::<&PyDateTime>(), arg2.extract::<PyTzLike>())
+            {
+                AtomicClock::fromdatetime(datetime, Some(tz))
+            } else if let (Ok(date), Ok(time)) =
+                (arg1.extract::<&PyDate>(), arg2.extract::<&PyTime>())
+            {
+                // the time is on the wall clock of `tzinfo`, rather than converted to it
+                AtomicClock::combine(date, time, tzinfo.take())
+            } else if let (Ok(date), Ok(tz)) =
+                (arg1.extract::<&PyDate>(), arg2.extract::<PyTzLike>())
+            {
+                AtomicClock::fromdate(date, tz)
+            } else if let (Ok(datetime_str), Ok(fmt_str)) =
+                (arg1.extract::<&str>(), arg2.extract::<&str>())
+            {
+                AtomicClock::strptime(datetime_str, fmt_str, None)
+            } else {
+                Err(exceptions::PyTypeError::new_err(format!(
+                    "Cannot parse arguments of types {} and {}",
+                    arg1.get_type().name()?,
+                    arg2.get_type().name()?
+                )))
+            }
+        }
+        3..=8 => from_components(py_args.as_slice()),
+        _ => Err(ParserError::new_err("invalid args")),
+    }?;
+
+    if let Some(tzinfo) = tzinfo {
+        Ok(Some(datetime.to(tzinfo)?))
+    } else {
+        Ok(Some(datetime))
+    }
+}
+
+/// Like `get`, but returns `default` instead of raising. The errors raised by `get` are lazy
+/// and dropped here, so the failure path never builds a Python exception object.
+#[pyfunction(py_args = "*", tzinfo = "None", default = "None")]
+#[pyo3(text_signature = "(*args, tzinfo=None, default=None)")]
+pub(crate) fn try_get(
+    py: Python,
+    py_args: &PyTuple,
+    tzinfo: Option<PyTzLike>,
+    default: Option<PyObject>,
+) -> PyObject {
+    match get(py_args, tzinfo) {
+        Ok(datetime) => datetime.into_py(py),
+        Err(_) => default.unwrap_or_else(|| py.None()),
+    }
+}
+
+/// Like `get`, but unknown timezone names can fall back to UTC with a warning, so that a
+/// batch import isn't aborted by a single bad zone.
+#[pyfunction(py_args = "*", tzinfo = "None", on_unknown_tz = "OnUnknownTz::Error")]
+#[pyo3(text_signature = "(*args, tzinfo=None, on_unknown_tz=\"error\")")]
+pub(crate) fn get_safe(
+    py: Python,
+    py_args: &PyTuple,
+    tzinfo: Option<PyTzLike>,
+    on_unknown_tz: OnUnknownTz,
+) -> PyResult<Option<AtomicClock>> {
+    if let OnUnknownTz::Error = on_unknown_tz {
+        return get(py_args, tzinfo);
+    }
+
+    let tzinfo = match tzinfo {
+        Some(PyTzLike::String(name)) if warn_unknown_tz(py, name)? => Some(PyTzLike::utc()),
+        tzinfo => tzinfo,
+    };
+
+    // timezones are also accepted after a date, or as the last of 8 components
+    let mut args: Vec<&PyAny> = py_args.iter().collect();
+    let tz_index = match args.len() {
+        2 if args[0].is_instance_of::<PyDate>()? => Some(1),
+        8 => Some(7),
+        _ => None,
+    };
+    if let Some(index) = tz_index {
+        if let Ok(name) = args[index].extract::<&str>() {
+            if warn_unknown_tz(py, name)? {
+                args[index] = "UTC".into_py(py).into_ref(py);
+            }
+        }
+    }
+
+    get(PyTuple::new(py, args), tzinfo)
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum OnUnknownTz {
+    Error,
+    Utc,
+}
+
+impl FromPyObject<'_> for OnUnknownTz {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        match ob.extract::<&str>()? {
+            "error" => Ok(Self::Error),
+            "utc" => Ok(Self::Utc),
+            _ => Err(exceptions::PyValueError::new_err(
+                "invalid on_unknown_tz, valid values are 'error' and 'utc'",
+            )),
+        }
+    }
+}
+
+/// How `shift` treats the day of the month when shifting months.
+#[derive(Clone, Copy)]
+pub(crate) enum Anchor {
+    /// The same day, clamped to the end of shorter months.
+    Preserve,
+    /// The last day of the target month, when starting from the last day of a month.
+    MonthEnd,
+}
+
+impl FromPyObject<'_> for Anchor {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        match ob.extract::<&str>()? {
+            "preserve" => Ok(Self::Preserve),
+            "month_end" => Ok(Self::MonthEnd),
+            _ => Err(exceptions::PyValueError::new_err(
+                "invalid anchor, valid values are 'preserve' and 'month_end'",
+            )),
+        }
+    }
+}
+
+/// The numbering of `week_of_year`.
+#[derive(Clone, Copy)]
+pub(crate) enum WeekConvention {
+    Iso,
+    /// Weeks start on Sunday, days before the year's first Sunday are in week 0, as `%U`.
+    Sunday,
+    /// Weeks start on Monday, days before the year's first Monday are in week 0, as `%W`.
+    Monday,
+}
+
+impl FromPyObject<'_> for WeekConvention {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        match ob.extract::<&str>()? {
+            "iso" => Ok(Self::Iso),
+            "sunday" => Ok(Self::Sunday),
+            "monday" => Ok(Self::Monday),
+            _ => Err(exceptions::PyValueError::new_err(
+                "invalid convention, valid values are 'iso', 'sunday' and 'monday'",
+            )),
+        }
+    }
+}
+
+/// What `resolve` does with a wall time skipped by a transition.
+#[derive(Clone, Copy)]
+pub(crate) enum Nonexistent {
+    Raise,
+    /// The first instant after the gap.
+    ShiftForward,
+    /// The last nanosecond before the gap.
+    ShiftBackward,
+}
+
+impl FromPyObject<'_> for Nonexistent {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        match ob.extract::<&str>()? {
+            "raise" => Ok(Self::Raise),
+            "shift_forward" => Ok(Self::ShiftForward),
+            "shift_backward" => Ok(Self::ShiftBackward),
+            _ => Err(exceptions::PyValueError::new_err(
+                "invalid nonexistent, valid values are 'raise', 'shift_forward' and \
+                 'shift_backward'",
+            )),
+        }
+    }
+}
+
+/// What `resolve` does with a wall time repeated by a transition.
+#[derive(Clone, Copy)]
+pub(crate) enum Ambiguous {
+    Raise,
+    Earlier,
+    Later,
+}
+
+impl FromPyObject<'_> for Ambiguous {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        match ob.extract::<&str>()? {
+            "raise" => Ok(Self::Raise),
+            "earlier" => Ok(Self::Earlier),
+            "later" => Ok(Self::Later),
+            _ => Err(exceptions::PyValueError::new_err(
+                "invalid ambiguous, valid values are 'raise', 'earlier' and 'later'",
+            )),
+        }
+    }
+}
+
+/// A naive wall time for `resolve`, as a `datetime` or the fields
+/// `(year, month, day[, hour, minute, second, microsecond])`.
+#[derive(FromPyObject)]
+pub(crate) enum WallTime<'p> {
+    DateTime(&'p PyDateTime),
+    Components(Vec<i64>),
+}
+
+impl WallTime<'_> {
+    fn to_naive(&self) -> PyResult<NaiveDateTime> {
+        match self {
+            WallTime::DateTime(dt) => {
+                if !dt.getattr("tzinfo")?.is_none() {
+                    return Err(exceptions::PyValueError::new_err(
+                        "resolve() takes a naive datetime",
+                    ));
+                }
+                Ok(naive_from_datetime(dt))
+            }
+            WallTime::Components(components) => {
+                if !(3..=7).contains(&components.len()) {
+                    return Err(exceptions::PyValueError::new_err(format!(
+                        "invalid wall time of {} elements, expected 3 to 7 elements \
+                         (year, month, day[, hour, minute, second, microsecond])",
+                        components.len()
+                    )));
+                }
+                let field =
+                    |index: usize| u32::try_from(components.get(index).copied().unwrap_or(0)).ok();
+                i32::try_from(components[0])
+                    .ok()
+                    .and_then(|year| NaiveDate::from_ymd_opt(year, field(1)?, field(2)?))
+                    .and_then(|date| {
+                        date.and_hms_micro_opt(field(3)?, field(4)?, field(5)?, field(6)?)
+                    })
+                    .filter(|naive| naive.nanosecond() < 1_000_000_000)
+                    .ok_or_else(|| exceptions::PyValueError::new_err("invalid datetime"))
+            }
+        }
+    }
+}
+
+/// Whether `name` isn't a valid timezone, in which case a warning that UTC replaces it is
+/// issued.
+fn warn_unknown_tz(py: Python, name: &str) -> PyResult<bool> {
+    if name.parse::<HybridTz>().is_ok() {
+        return Ok(false);
+    }
+    PyErr::warn(
+        py,
+        py.import("builtins")?.getattr("UserWarning")?,
+        &format!("unknown timezone `{}`, using UTC instead", name),
+        1,
+    )?;
+    Ok(true)
+}
+
+/// Converts a timestamp in seconds to a naive UTC datetime, the exact value of the `f64`
+/// rounded to the nearest nanosecond.
+fn naive_from_timestamp(timestamp: f64) -> PyResult<NaiveDateTime> {
+    naive_from_units(timestamp, 0, NANOS_PER_SECOND)
+        .ok_or_else(|| RangeError::new_err(format!("timestamp {timestamp} is out of range")))
+}
+
+/// The nanoseconds since the Unix epoch of `datetime`, which don't fit in i64 for every
+/// supported year.
+fn timestamp_nanos<Tz: TimeZone>(datetime: &DateTime<Tz>) -> i128 {
+    datetime.timestamp() as i128 * NANOS_PER_SECOND + datetime.timestamp_subsec_nanos() as i128
+}
+
+fn iso_calendar<Tz: TimeZone>(datetime: &DateTime<Tz>) -> (u32, u32, u32) {
+    let iso_week = datetime.iso_week();
+    (
+        iso_week.year() as u32,
+        iso_week.week(),
+        datetime.weekday().number_from_monday(),
+    )
+}
+
+/// Converts a nanosecond timestamp to seconds, correctly rounded to the nearest `f64`.
+fn nanos_to_seconds(nanos: impl Into<i128>) -> f64 {
+    const MAX_EXACT: i128 = 1 << f64::MANTISSA_DIGITS;
+
+    let nanos = nanos.into();
+
+    // both operands are exact once the trailing zeros are dropped, so the division
+    // rounds only once
+    let (mut numerator, mut denominator) = (nanos, 1_000_000_000);
+    while denominator > 1 && numerator % 10 == 0 {
+        numerator /= 10;
+        denominator /= 10;
+    }
+    if numerator.abs() <= MAX_EXACT {
+        return numerator as f64 / denominator as f64;
+    }
+
+    // parsing a decimal is correctly rounded too
+    let sign = if nanos < 0 { "-" } else { "" };
+    let nanos = nanos.unsigned_abs();
+    format!(
+        "{sign}{}.{:09}",
+        nanos / 1_000_000_000,
+        nanos % 1_000_000_000
+    )
+    .parse()
+    .unwrap()
+}
+
+/// The microsecond of a `datetime` or `time`, which can't represent a leap second, so
+/// it's folded into the last microsecond of the 59th second.
+fn py_microsecond(nanosecond: u32) -> u32 {
+    (nanosecond / 1000).min(999_999)
+}
+
+/// Units of `unit_nanos` from the epoch `epoch_nanos` before the Unix epoch to `naive`.
+///
+/// The whole units and the fraction are split in integer nanoseconds, so the only
+/// error is the final rounding to an `f64`.
+fn units_since(naive: &NaiveDateTime, epoch_nanos: i128, unit_nanos: i128) -> f64 {
+    let nanos = naive.timestamp() as i128 * 1_000_000_000
+        + naive.timestamp_subsec_nanos() as i128
+        + epoch_nanos;
+
+    nanos.div_euclid(unit_nanos) as f64 + nanos.rem_euclid(unit_nanos) as f64 / unit_nanos as f64
+}
+
+/// The UTC time `units` of `unit_nanos` after the epoch `epoch_nanos` before the Unix
+/// epoch, rounded to the nearest nanosecond.
+fn naive_from_units(units: f64, epoch_nanos: i128, unit_nanos: i128) -> Option<NaiveDateTime> {
+    if !units.is_finite() {
+        return None;
+    }
+
+    // a decimal keeps only 17 significant digits of an `f64`, so the exact binary value
+    // is scaled instead: `units` is `mantissa * 2^exponent`
+    let bits = units.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = (bits & ((1 << 52) - 1)) as i128;
+    let (mantissa, exponent) = match biased_exponent {
+        0 => (fraction, -1074),
+        _ => (fraction | 1 << 52, biased_exponent - 1075),
+    };
+    let scaled = mantissa * unit_nanos;
+    let nanos = match exponent {
+        0.. => scaled.checked_mul(1_i128.checked_shl(exponent as u32)?)?,
+        -126..=-1 => {
+            let half = 1_i128 << (-exponent - 1);
+            (scaled + half) >> -exponent
+        }
+        _ => 0,
+    };
+    let nanos = if units.is_sign_negative() {
+        -nanos
+    } else {
+        nanos
+    } - epoch_nanos;
+
+    NaiveDateTime::from_timestamp_opt(
+        i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?,
+        nanos.rem_euclid(1_000_000_000) as u32,
+    )
+}
+
+/// The last day of the month of `date`.
+fn last_day_of_month(date: NaiveDate) -> NaiveDate {
+    let (year, month) = match date.month() {
+        12 => (date.year() + 1, 1),
+        month => (date.year(), month + 1),
+    };
+    NaiveDate::from_ymd(year, month, 1) - Duration::days(1)
+}
+
+/// Checks a weekday where Monday is 0 and Sunday is 6.
+fn check_weekday(weekday: i32) -> PyResult<i64> {
+    if !matches!(weekday, 0..=6) {
+        return Err(exceptions::PyIndexError::new_err(
+            "invalid weekday, valid weekday should be 0..6",
+        ));
+    }
+    Ok(weekday as i64)
+}
+
+/// The timezone of the wall time `naive` in `tzinfo`. "local" is the offset in effect at
+/// the wall time rather than the current one, `fold` picking it when the wall time is
+/// repeated.
+fn wall_time_tz(tzinfo: PyTzLike, naive: &NaiveDateTime, fold: i32) -> PyResult<HybridTz> {
+    if !tzinfo.is_local() {
+        return tzinfo.try_to_tz();
+    }
+    match HybridTz::local_from_local(naive) {
+        LocalResult::Single(tz) => Ok(tz),
+        LocalResult::Ambiguous(earlier, later) => Ok(if fold == 0 { earlier } else { later }),
+        LocalResult::None => Err(exceptions::PyValueError::new_err("invalid datetime")),
+    }
+}
+
+/// Resolves a wall time in `tz`, `fold` picks the earlier (0) or the later (1)
+/// instant when the wall time is repeated by a DST transition.
+fn resolve_local_datetime(
+    tz: &HybridTz,
+    naive: &NaiveDateTime,
+    fold: i32,
+) -> PyResult<DateTime<HybridTz>> {
+    let ambiguous = match fold {
+        0 => Ambiguous::Earlier,
+        1 => Ambiguous::Later,
+        _ => {
+            return Err(exceptions::PyValueError::new_err(
+                "invalid fold, valid fold should be 0 or 1",
+            ))
+        }
+    };
+    resolve_wall_time(tz, naive, Nonexistent::Raise, ambiguous)
+}
+
+/// The instant of the wall time `naive` in `tz`, with the policies for the wall times
+/// skipped and repeated by its transitions applied.
+fn resolve_wall_time(
+    tz: &HybridTz,
+    naive: &NaiveDateTime,
+    nonexistent: Nonexistent,
+    ambiguous: Ambiguous,
+) -> PyResult<DateTime<HybridTz>> {
+    let wall_time = || naive.format("%Y-%m-%dT%H:%M:%S%.f");
+    match (tz.from_local_datetime(naive), ambiguous) {
+        (LocalResult::Single(datetime), _) => Ok(datetime),
+        (LocalResult::Ambiguous(earlier, _), Ambiguous::Earlier) => Ok(earlier),
+        (LocalResult::Ambiguous(_, later), Ambiguous::Later) => Ok(later),
+        (LocalResult::Ambiguous(..), Ambiguous::Raise) => Err(exceptions::PyValueError::new_err(
+            format!("ambiguous datetime, {} occurs twice in {}", wall_time(), tz),
+        )),
+        (LocalResult::None, _) => {
+            if let Nonexistent::Raise = nonexistent {
+                return Err(exceptions::PyValueError::new_err(format!(
+                    "invalid datetime, {} is skipped in {}",
+                    wall_time(),
+                    tz
+                )));
+            }
+            // the transition is between the instants of the wall time at the offsets
+            // before and after the gap
+            let out_of_range = || RangeError::new_err("datetime is out of range");
+            let utc_at = |offset: i32| {
+                naive
+                    .checked_sub_signed(Duration::seconds(offset as i64))
+                    .map(|utc| DateTime::from_utc(utc, Utc))
+            };
+            let before = naive
+                .checked_sub_signed(Duration::days(1))
+                .map(|utc| tz.offset_at(&DateTime::from_utc(utc, Utc)))
+                .ok_or_else(out_of_range)?;
+            let after = naive
+                .checked_add_signed(Duration::days(1))
+                .map(|utc| tz.offset_at(&DateTime::from_utc(utc, Utc)))
+                .ok_or_else(out_of_range)?;
+            let (after, until) = utc_at(after).zip(utc_at(before)).ok_or_else(out_of_range)?;
+            let (transition, _, _) = tz
+                .next_transition(after, until)
+                .ok_or_else(|| exceptions::PyValueError::new_err("invalid datetime"))?;
+            let instant = match nonexistent {
+                Nonexistent::ShiftBackward => transition - Duration::nanoseconds(1),
+                _ => transition,
+            };
+            Ok(tz.from_utc_datetime(&instant.naive_utc()))
+        }
+    }
+}
+
+/// Like `resolve_local_datetime`, but a wall time skipped by a transition is moved forward
+/// by the length of the gap, as `dateutil.tz.resolve_imaginary`. `None` past chrono's range.
+fn resolve_imaginary(
+    tz: &HybridTz,
+    naive: &NaiveDateTime,
+    fold: bool,
+) -> Option<DateTime<HybridTz>> {
+    match tz.from_local_datetime(naive) {
+        LocalResult::Single(datetime) => Some(datetime),
+        LocalResult::Ambiguous(earlier, later) => Some(if fold { later } else { earlier }),
+        LocalResult::None => {
+            // the offset in effect before the gap
+            let before = naive.checked_sub_signed(Duration::days(1))?;
+            let offset = tz.offset_at(&DateTime::from_utc(before, Utc));
+            let utc = naive.checked_sub_signed(Duration::seconds(offset as i64))?;
+            Some(tz.from_utc_datetime(&utc))
+        }
+    }
+}
+
+/// Rewrites the `%z` and `%:z` directives of a strptime format to `%#z`, which also
+/// accepts `Z` and hour-only offsets like `+08`.
+fn permissive_offset_format(fmt: &str) -> String {
+    let mut result = String::with_capacity(fmt.len());
+    let mut chars = fmt.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('z') => result.push_str("%#z"),
+            Some(':') if chars.peek() == Some(&'z') => {
+                chars.next();
+                result.push_str("%#z");
+            }
+            Some(next) => {
+                result.push(c);
+                result.push(next);
+            }
+            None => result.push(c),
+        }
+    }
+    result
+}
+
+/// Rewrites the dotted `a.m.`/`p.m.` meridiems, which `%p` doesn't accept, to `AM`/`PM`.
+/// Returns `None` when `s` has none.
+fn dotted_meridiem(s: &str) -> Option<String> {
+    let mut result = String::with_capacity(s.len());
+    let mut rest = s;
+    let mut replaced = false;
+    while !rest.is_empty() {
+        let meridiem = rest.get(..4).map(str::to_ascii_lowercase);
+        match meridiem.as_deref() {
+            Some("a.m.") => result.push_str("AM"),
+            Some("p.m.") => result.push_str("PM"),
+            _ => {
+                let c = rest.chars().next().unwrap();
+                result.push(c);
+                rest = &rest[c.len_utf8()..];
+                continue;
+            }
+        }
+        replaced = true;
+        rest = &rest[4..];
+    }
+    replaced.then_some(result)
+}
+
+/// Drops the digits beyond nanoseconds of every fraction in `s`, returns `None` if there
+/// are none to drop.
+fn truncate_subnanosecond_digits(s: &str) -> Option<String> {
+    let mut result = String::with_capacity(s.len());
+    let mut truncated = false;
+    let mut digits = None;
+    for c in s.chars() {
+        match digits {
+            Some(n) if c.is_ascii_digit() => {
+                if n >= 9 {
+                    truncated = true;
+                    continue;
+                }
+                digits = Some(n + 1);
+            }
+            _ => digits = matches!(c, '.' | ',').then_some(0),
+        }
+        result.push(c);
+    }
+    truncated.then_some(result)
+}
+
+/// The wall time of `dt`, ignoring its `tzinfo`.
+pub(crate) fn naive_from_datetime(dt: &PyDateTime) -> NaiveDateTime {
+    NaiveDate::from_ymd(dt.get_year(), dt.get_month() as u32, dt.get_day() as u32).and_hms_micro(
+        dt.get_hour() as u32,
+        dt.get_minute() as u32,
+        dt.get_second() as u32,
+        dt.get_microsecond(),
+    )
+}
+
+/// The UTC time of a `datetime`, from its fields and offset. Naive datetimes are in UTC.
+fn naive_utc_from_datetime(dt: &PyDateTime) -> PyResult<NaiveDateTime> {
+    let naive = naive_from_datetime(dt);
+
+    let tzinfo = dt.getattr("tzinfo")?;
+    if tzinfo.is_none() {
+        return Ok(naive);
+    }
+    // a `Tz` resolves the wall time in Rust, which also rejects skipped wall times
+    if let Ok(tz) = tzinfo.extract::<PyRef<PyTz>>() {
+        let datetime = resolve_local_datetime(&tz.tz(), &naive, dt.get_fold() as i32)?;
+        return Ok(datetime.naive_utc());
+    }
+
+    let offset = dt.call_method0("utcoffset")?;
+    if offset.is_none() {
+        return Ok(naive);
+    }
+    let offset = offset.downcast::<PyDelta>()?;
+    Ok(naive
+        - Duration::days(offset.get_days() as i64)
+        - Duration::seconds(offset.get_seconds() as i64)
+        - Duration::microseconds(offset.get_microseconds() as i64))
+}
+
+/// The formats of the strings accepted by `get`, tried in order before RFC 2822 and
+/// HTTP-date. The full ISO 8601 forms come first, then the date-only and space-separated
+/// shapes, the missing fields default to midnight UTC.
+const GET_FORMATS: [&str; 9] = [
+    "%Y-%m-%dT%H:%M:%S%.f%z",
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y%m%dT%H%M%S%.f",
+    "%Y%m%dT%H%M%S%.f%z",
+    "%Y-%m-%d",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%d %H:%M",
+    "%Y/%m/%d",
+    "%Y%m%d",
+];
+
+/// Parses the ISO 8601, RFC 2822 and HTTP-date strings accepted by `get`.
+fn parse_datetime_str(datetime: &str) -> PyResult<AtomicClock> {
+    if let Some(date) = parse_iso_week(datetime)? {
+        return Ok(AtomicClock::from(
+            UTC.from_utc_datetime(&date.and_hms(0, 0, 0)),
+        ));
+    }
+
+    let parsed = GET_FORMATS
+        .iter()
+        .find_map(|fmt| AtomicClock::strptime(datetime, fmt, None).ok());
+    if let Some(clock) = parsed {
+        return Ok(clock);
+    }
+
+    AtomicClock::fromrfc2822(datetime)
+        .or_else(|_| AtomicClock::fromhttpdate(datetime))
+        .map_err(|_| {
+            ParserError::new_err(format!(
+                "could not match {:?} to an ISO week date or any of the formats {}, RFC 2822 \
+                 or HTTP-date",
+                datetime,
+                GET_FORMATS.join(", ")
+            ))
+        })
+}
+
+/// The error of `get` for a single argument of a type it doesn't accept.
+fn unsupported_arg_error(arg: &PyAny) -> PyResult<PyErr> {
+    Ok(exceptions::PyTypeError::new_err(format!(
+        "Cannot parse argument of type {}",
+        arg.get_type().name()?
+    )))
+}
+
+/// Parses UTF-8 encoded bytes like the strings accepted by `get`.
+fn str_from_utf8(datetime: &[u8]) -> PyResult<&str> {
+    std::str::from_utf8(datetime)
+        .map_err(|e| exceptions::PyValueError::new_err(format!("invalid UTF-8 datetime, {}", e)))
+}
+
+/// The single-string form of `get`. An ISO week date stands for midnight of its day in
+/// `tzinfo`, the other strings are converted to it.
+fn get_str(datetime: &str, tzinfo: Option<PyTzLike>) -> PyResult<AtomicClock> {
+    match (parse_iso_week(datetime)?, tzinfo) {
+        (Some(date), Some(tzinfo)) => {
+            let midnight = date.and_hms(0, 0, 0);
+            resolve_imaginary(&wall_time_tz(tzinfo, &midnight, 0)?, &midnight, false)
+                .map(AtomicClock::from)
+                .ok_or_else(|| RangeError::new_err(format!("{} is out of range", datetime)))
         }
+        (_, Some(tzinfo)) => parse_datetime_str(datetime)?.to(tzinfo),
+        (_, None) => parse_datetime_str(datetime),
     }
 }
 
-#[pyfunction(tzinfo = "PyTzLike::String(\"local\")")]
-#[pyo3(text_signature = "(tzinfo = \"local\")")]
-pub(crate) fn now(tzinfo: PyTzLike) -> PyResult<AtomicClock> {
-    AtomicClock::now(tzinfo)
+/// Parses the ISO 8601 week dates `YYYY-Www` and `YYYY-Www-D`, the first day of the week
+/// when it's left out. `None` for strings of another shape.
+fn parse_iso_week(datetime: &str) -> PyResult<Option<NaiveDate>> {
+    let is_digits = |s: &str, len: usize| s.len() == len && s.bytes().all(|b| b.is_ascii_digit());
+    let (year, week_day) = match datetime.split_once("-W") {
+        Some((year, week_day)) if is_digits(year, 4) => (year, week_day),
+        _ => return Ok(None),
+    };
+    let (week, day) = match week_day.split_once('-') {
+        Some((week, day)) if is_digits(week, 2) && is_digits(day, 1) => (week, day),
+        None if is_digits(week_day, 2) => (week_day, "1"),
+        _ => return Ok(None),
+    };
+    let (year, week, day): (i32, u32, i64) = (
+        year.parse().unwrap(),
+        week.parse().unwrap(),
+        day.parse().unwrap(),
+    );
+
+    // December 28th is always in the last week of its ISO year
+    let weeks = NaiveDate::from_ymd(year, 12, 28).iso_week().week();
+    if !(1..=weeks).contains(&week) {
+        return Err(ParserError::new_err(format!(
+            "invalid ISO week `{}`, {} has weeks 1 to {}",
+            datetime, year, weeks
+        )));
+    }
+    if !(1..=7).contains(&day) {
+        return Err(ParserError::new_err(format!(
+            "invalid ISO weekday `{}`, valid weekday should be 1..7",
+            datetime
+        )));
+    }
+
+    Ok(Some(
+        NaiveDate::from_isoywd(year, week, Weekday::Mon) + Duration::days(day - 1),
+    ))
 }
 
-#[pyfunction]
-pub(crate) fn utcnow() -> PyResult<AtomicClock> {
-    AtomicClock::utcnow()
+/// An endpoint of an ISO 8601 interval and its precision, from the `Frame`s of `year` to
+/// `second`, or `None` when it has fractional seconds.  Extended formats may leave out
+/// trailing components, they stand for the start of their unit.
+fn parse_interval_endpoint(endpoint: &str) -> PyResult<(AtomicClock, Option<Frame>)> {
+    let invalid = || ParserError::new_err(format!("invalid interval endpoint `{}`", endpoint));
+    let (date, time) = match endpoint.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (endpoint, None),
+    };
+    let (frame, padded) = match time {
+        None => match date.len() {
+            4 => (Some(Frame::Year), format!("{}-01-01T00:00:00", date)),
+            7 => (Some(Frame::Month), format!("{}-01T00:00:00", date)),
+            10 => (Some(Frame::Day), format!("{}T00:00:00", date)),
+            _ => return Err(invalid()),
+        },
+        Some(time) => {
+            let (clock, offset) = time.split_at(time.find(['Z', '+', '-']).unwrap_or(time.len()));
+            let (frame, padding) = match clock.len() {
+                2 => (Some(Frame::Hour), ":00:00"),
+                5 if clock.contains(':') => (Some(Frame::Minute), ":00"),
+                _ if clock.contains('.') || clock.contains(',') => (None, ""),
+                _ => (Some(Frame::Second), ""),
+            };
+            (frame, format!("{}T{}{}{}", date, clock, padding, offset))
+        }
+    };
+    let clock = parse_datetime_str(&padded).map_err(|_| invalid())?;
+    Ok((clock, frame))
 }
 
-#[pyfunction(py_args = "*", tzinfo = "None")]
-#[pyo3(text_signature = "(*args, tzinfo=None)")]
-pub(crate) fn get(py_args: &PyTuple, tzinfo: Option<PyTzLike>) -> PyResult<AtomicClock> {
-    let datetime = match py_args.len() {
-        0 => AtomicClock::utcnow(),
-        1 => {
-            let arg = &py_args[0];
+/// The components of an ISO 8601 duration, `PnYnMnWnDTnHnMnS` where only the seconds may
+/// have a fraction.
+#[derive(Default)]
+struct IsoDuration {
+    years: i32,
+    months: i64,
+    weeks: i64,
+    days: i64,
+    hours: i64,
+    minutes: i64,
+    seconds: i64,
+    nanoseconds: i64,
+}
 
-            if let Ok(dt) = arg.extract::<AtomicClock>() {
-                Ok(dt)
-            } else if let Ok(timestamp) = arg.extract::<f64>() {
-                AtomicClock::fromtimestamp(timestamp, PyTzLike::utc())
-            } else if let Ok(timestamp) = arg.extract::<i64>() {
-                AtomicClock::fromtimestamp(timestamp as f64, PyTzLike::utc())
-            } else if let Ok(datetime) = arg.extract::<&str>() {
-                AtomicClock::strptime(datetime, "%Y-%m-%dT%H:%M:%S%.f%z", None)
-                    .or_else(|_| AtomicClock::strptime(datetime, "%Y-%m-%dT%H:%M:%S%.f", None))
-                    .or_else(|_| AtomicClock::strptime(datetime, "%Y%m%dT%H%M%S%.f", None))
-                    .or_else(|_| AtomicClock::strptime(datetime, "%Y%m%dT%H%M%S%.f%z", None))
-            } else if let Ok(tz) = arg.extract::<PyTzLike>() {
-                AtomicClock::now(tz)
-            } else if let Ok(datetime) = arg.extract::<&PyDateTime>() {
-                AtomicClock::fromdatetime(datetime, None)
-            } else if let Ok(date) = arg.extract::<&PyDate>() {
-                AtomicClock::fromdate(date, PyTzLike::String("UTC"))
-            } else if let Ok((year, month, day)) = arg.extract::<(i32, u32, u32)>() {
-                AtomicClock::new(year, month, day, 0, 0, 0, 0, PyTzLike::utc())
-            } else {
-                Err(exceptions::PyValueError::new_err(
-                    "failed to parse datetime",
-                ))
-            }
+impl IsoDuration {
+    fn parse(duration: &str) -> PyResult<Self> {
+        let invalid = || ParserError::new_err(format!("invalid ISO 8601 duration `{}`", duration));
+        let body = duration.strip_prefix('P').ok_or_else(invalid)?;
+        let (date, time) = match body.split_once('T') {
+            Some((_, "")) => return Err(invalid()),
+            Some((date, time)) => (date, time),
+            None => (body, ""),
+        };
+        if date.is_empty() && time.is_empty() {
+            return Err(invalid());
         }
-        2 => {
-            let arg1 = &py_args[0];
-            let arg2 = &py_args[1];
 
-            if let (Ok(datetime), Ok(tz)) =
-                (arg1.extract::<&PyDateTime>(), arg2.extract::<PyTzLike>())
-            {
-                AtomicClock::fromdatetime(datetime, Some(tz))
-            } else if let (Ok(date), Ok(tz)) =
-                (arg1.extract::<&PyDate>(), arg2.extract::<PyTzLike>())
-            {
-                AtomicClock::fromdate(date, tz)
-            } else if let (Ok(datetime_str), Ok(fmt_str)) =
-                (arg1.extract::<&str>(), arg2.extract::<&str>())
-            {
-                AtomicClock::strptime(datetime_str, fmt_str, None)
-            } else {
-                Err(exceptions::PyValueError::new_err(
-                    "failed to parse datetime",
-                ))
+        let mut result = Self::default();
+        for (part, designators) in [(date, "YMWD"), (time, "HMS")] {
+            let mut rest = part;
+            // designators have to come in order, each at most once
+            let mut allowed = designators;
+            while !rest.is_empty() {
+                let end = rest
+                    .find(|c: char| c.is_ascii_alphabetic())
+                    .ok_or_else(invalid)?;
+                let (number, designator) = (&rest[..end], rest[end..].chars().next().unwrap());
+                rest = &rest[end + 1..];
+                let position = allowed.find(designator).ok_or_else(invalid)?;
+                allowed = &allowed[position + 1..];
+
+                if designator == 'S' && designators == "HMS" {
+                    let seconds = number.replace(',', ".");
+                    let (whole, fraction) = seconds.split_once('.').unwrap_or((&seconds, ""));
+                    let digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+                    if fraction.len() > 9 || !digits(whole) || !digits(fraction) {
+                        return Err(invalid());
+                    }
+                    result.seconds = whole.parse().map_err(|_| invalid())?;
+                    result.nanoseconds =
+                        format!("{:0<9}", fraction).parse().map_err(|_| invalid())?;
+                    continue;
+                }
+                if !number.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(invalid());
+                }
+                let value = number.parse::<i64>().map_err(|_| invalid())?;
+                match (designator, designators) {
+                    ('Y', _) => result.years = i32::try_from(value).map_err(|_| invalid())?,
+                    ('M', "YMWD") => result.months = value,
+                    ('W', _) => result.weeks = value,
+                    ('D', _) => result.days = value,
+                    ('H', _) => result.hours = value,
+                    ('M', _) => result.minutes = value,
+                    _ => return Err(invalid()),
+                }
             }
         }
-        3..=8 => {
-            let year = py_args[0].extract::<i32>()?;
-            let mut datetime_args = [0, 0, 0, 0, 0, 0, 0];
-            for (idx, arg) in py_args[1..].into_iter().enumerate() {
-                datetime_args[idx] = arg.extract::<u32>()?;
-                if idx == 5 {
-                    break;
+        Ok(result)
+    }
+
+    /// `clock` moved by the duration, backwards with `backwards`.
+    fn shift(&self, clock: &AtomicClock, backwards: bool) -> PyResult<AtomicClock> {
+        let sign = if backwards { -1 } else { 1 };
+        let months = i128::from(self.years) * 12 + i128::from(self.months);
+        let nanos = elapsed_nanos(
+            self.weeks,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+            0,
+        ) + i128::from(self.nanoseconds);
+        clock.shifted(months * sign, nanos * sign, None, false, false, false)
+    }
+}
+
+/// Parses an ISO 8601 interval, `start/end`, `start/duration` or `duration/end`.  When the
+/// endpoints have different precisions the coarser end stands for the end of its unit.
+fn parse_interval_str(interval: &str) -> PyResult<(AtomicClock, AtomicClock)> {
+    let (start, end) = interval.split_once('/').ok_or_else(|| {
+        ParserError::new_err(format!(
+            "invalid interval `{}`, expected `start/end`, `start/duration` or `duration/end`",
+            interval
+        ))
+    })?;
+
+    match (start.starts_with('P'), end.starts_with('P')) {
+        (true, true) => Err(ParserError::new_err(format!(
+            "invalid interval `{}`, at most one side can be a duration",
+            interval
+        ))),
+        (false, true) => {
+            let (start, _) = parse_interval_endpoint(start)?;
+            let end = IsoDuration::parse(end)?.shift(&start, false)?;
+            Ok((start, end))
+        }
+        (true, false) => {
+            let (end, _) = parse_interval_endpoint(end)?;
+            let start = IsoDuration::parse(start)?.shift(&end, true)?;
+            Ok((start, end))
+        }
+        (false, false) => {
+            let (start, start_frame) = parse_interval_endpoint(start)?;
+            let (mut end, end_frame) = parse_interval_endpoint(end)?;
+            if let Some(frame) = end_frame {
+                let coarser = match &start_frame {
+                    Some(start_frame) => frame.precision() < start_frame.precision(),
+                    None => true,
+                };
+                if coarser {
+                    end = end.ceil(frame, 1, 1, 1, None)?;
                 }
             }
-            let tz = {
-                if py_args.len() == 8 {
-                    py_args[7].extract::<PyTzLike>()?
-                } else {
-                    PyTzLike::utc()
-                }
-            };
-
-            AtomicClock::new(
-                year,
-                datetime_args[0],
-                datetime_args[1],
-                datetime_args[2],
-                datetime_args[3],
-                datetime_args[4],
-                datetime_args[5],
-                tz,
-            )
-        }
-        _ => Err(exceptions::PyValueError::new_err("invalid args")),
-    }?;
+            if end.datetime < start.datetime {
+                return Err(ParserError::new_err(format!(
+                    "invalid interval `{}`, end is less than start",
+                    interval
+                )));
+            }
+            Ok((start, end))
+        }
+    }
+}
 
-    if let Some(tzinfo) = tzinfo {
-        Ok(datetime.to(tzinfo)?)
-    } else {
-        Ok(datetime)
+/// Builds an `AtomicClock` from 3 to 8 components, in the order of
+/// `(year, month, day, hour, minute, second, microsecond, tzinfo)`.
+fn from_components(components: &[&PyAny]) -> PyResult<AtomicClock> {
+    let year = components[0].extract::<i32>()?;
+    let mut datetime_args = [0, 0, 0, 0, 0, 0];
+    for (idx, arg) in components[1..].iter().take(6).enumerate() {
+        datetime_args[idx] = arg.extract::<u32>()?;
     }
+    let tz = {
+        if components.len() == 8 {
+            components[7].extract::<PyTzLike>()?
+        } else {
+            PyTzLike::utc()
+        }
+    };
+
+    AtomicClock::new(
+        year,
+        datetime_args[0],
+        datetime_args[1],
+        datetime_args[2],
+        datetime_args[3],
+        datetime_args[4],
+        datetime_args[5],
+        tz,
+        0,
+        None,
+    )
+}
+
+/// Builds an `AtomicClock` from a `time.struct_time`, the offset is taken from
+/// `tm_gmtoff` when it's available, otherwise UTC is assumed.
+fn from_struct_time(struct_time: &PyAny) -> PyResult<AtomicClock> {
+    let tz = match struct_time.getattr("tm_gmtoff")?.extract::<Option<i32>>()? {
+        Some(offset) => {
+            let offset = FixedOffset::east_opt(offset)
+                .ok_or_else(|| TimezoneError::new_err("invalid tm_gmtoff"))?;
+            PyTzLike::PyTz(PyTz::new(HybridTz::Offset(offset)))
+        }
+        None => PyTzLike::utc(),
+    };
+
+    AtomicClock::fromstructtime(struct_time, tz)
 }
 
 struct DatetimeRangeGenerator {
     start: AtomicClock,
-    end_timestamp: f64,
+    /// The last UTC time of the values, unbounded if `None`.
+    end: Option<NaiveDateTime>,
     frame: RelativeDelta,
     limit: u64,
     count: u64,
 }
 
 impl DatetimeRangeGenerator {
-    fn new(start: AtomicClock, end_timestamp: f64, frame: RelativeDelta, limit: u64) -> Self {
+    fn new(
+        start: AtomicClock,
+        end: Option<NaiveDateTime>,
+        frame: RelativeDelta,
+        limit: u64,
+    ) -> Self {
         Self {
             start,
-            end_timestamp,
+            end,
             frame,
             limit,
             count: 0,
@@ -1304,22 +3609,48 @@ impl DatetimeRangeGenerator {
         if self.count == self.limit {
             return None;
         }
-        let datetime = AtomicClock {
-            datetime: self.start.datetime + self.frame * self.count as f64,
-        };
+        let datetime =
+            AtomicClock::from(self.start.datetime.clone() + self.frame * self.count as f64);
 
-        if datetime.timestamp() <= self.end_timestamp {
+        if self
+            .end
+            .is_none_or(|end| datetime.datetime.naive_utc() <= end)
+        {
             self.count += 1;
             Some(datetime)
         } else {
             None
         }
     }
+
+    /// Whether the UTC time `target` is one of the values, consumed or not. The step is
+    /// estimated from the average length of the frame, then checked exactly around it.
+    fn contains(&self, target: NaiveDateTime) -> bool {
+        let start = self.start.datetime.naive_utc();
+        if target < start || self.end.is_some_and(|end| target > end) {
+            return false;
+        }
+
+        let frame = &self.frame;
+        let frame_seconds = (frame.years() as i64 * 12 + frame.months()) as f64 * 2_629_746.0
+            + (frame.days() * 86_400 + frame.hours() * 3_600 + frame.minutes() * 60) as f64
+            + frame.seconds() as f64
+            + frame.nanoseconds() as f64 / 1e9;
+        let steps = duration_nanos(target - start) as f64 / 1e9 / frame_seconds;
+        let estimate = steps.round() as i64;
+
+        (estimate - 2..=estimate + 2)
+            .filter(|&step| step >= 0 && (step as u64) < self.limit)
+            .any(|step| {
+                (self.start.datetime.clone() + self.frame * step as f64).naive_utc() == target
+            })
+    }
 }
 
 #[pyclass]
 struct DatetimeRangeIter {
     generator: DatetimeRangeGenerator,
+    chunk_size: Option<usize>,
 }
 
 #[pymethods]
@@ -1328,8 +3659,23 @@ impl DatetimeRangeIter {
         slf
     }
 
-    fn __next__(mut slf: PyRefMut<Self>) -> Option<AtomicClock> {
-        slf.generator.next()
+    fn __next__(mut slf: PyRefMut<Self>, py: Python) -> PyResult<Option<PyObject>> {
+        match slf.chunk_size {
+            Some(chunk_size) => {
+                let generator = &mut slf.generator;
+                next_chunk(py, chunk_size, || Ok(generator.next()))
+            }
+            None => Ok(slf.generator.next().map(|clock| clock.into_py(py))),
+        }
+    }
+
+    fn __contains__(&self, clock: DateTimeLike) -> PyResult<bool> {
+        self.contains(clock)
+    }
+
+    #[pyo3(text_signature = "(clock)")]
+    fn contains(&self, clock: DateTimeLike) -> PyResult<bool> {
+        Ok(self.generator.contains(clock.naive_utc()?))
     }
 }
 
@@ -1343,22 +3689,27 @@ enum Frame {
     Second,
     Microsecond,
     Week,
+    /// Weeks starting on Monday whatever the `week_start`, as in `isocalendar`.
+    IsoWeek,
     Quarter,
 }
 
 impl FromPyObject<'_> for Frame {
     fn extract(ob: &PyAny) -> PyResult<Self> {
         let frame = ob.extract::<&str>()?;
+        // plurals and common abbreviations map to the canonical names, single letters are
+        // left out since `m` could be a month or a minute
         let frame = match frame {
-            "year" => Self::Year,
-            "month" => Self::Month,
-            "day" => Self::Day,
-            "hour" => Self::Hour,
-            "minute" => Self::Minute,
-            "second" => Self::Second,
-            "microsecond" => Self::Microsecond,
-            "week" => Self::Week,
-            "quarter" => Self::Quarter,
+            "year" | "years" | "yr" | "yrs" => Self::Year,
+            "month" | "months" | "mon" | "mons" => Self::Month,
+            "day" | "days" => Self::Day,
+            "hour" | "hours" | "hr" | "hrs" => Self::Hour,
+            "minute" | "minutes" | "min" | "mins" => Self::Minute,
+            "second" | "seconds" | "sec" | "secs" => Self::Second,
+            "microsecond" | "microseconds" | "us" | "usec" | "usecs" => Self::Microsecond,
+            "week" | "weeks" | "wk" | "wks" => Self::Week,
+            "isoweek" | "isoweeks" => Self::IsoWeek,
+            "quarter" | "quarters" | "qtr" | "qtrs" => Self::Quarter,
             _ => return Err(exceptions::PyValueError::new_err("invalid frame")),
         };
         Ok(frame)
@@ -1366,6 +3717,135 @@ impl FromPyObject<'_> for Frame {
 }
 
 impl Frame {
+    /// All frames, from the coarsest to the finest.
+    const DESCENDING: [Frame; 9] = [
+        Frame::Year,
+        Frame::Quarter,
+        Frame::Month,
+        Frame::Week,
+        Frame::Day,
+        Frame::Hour,
+        Frame::Minute,
+        Frame::Second,
+        Frame::Microsecond,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Frame::Year => "year",
+            Frame::Month => "month",
+            Frame::Day => "day",
+            Frame::Hour => "hour",
+            Frame::Minute => "minute",
+            Frame::Second => "second",
+            Frame::Microsecond => "microsecond",
+            Frame::Week => "week",
+            Frame::IsoWeek => "isoweek",
+            Frame::Quarter => "quarter",
+        }
+    }
+
+    /// Checks that spans of `n` frames can be aligned to the boundaries of the parent frame,
+    /// which `n` has to evenly divide.
+    fn check_multiple(&self, n: u32) -> PyResult<()> {
+        if n < 1 {
+            return Err(exceptions::PyValueError::new_err(
+                "n has to be a positive int",
+            ));
+        }
+        let (units, parent) = match self {
+            Frame::Year => return Ok(()),
+            Frame::Month => (12, "a year"),
+            Frame::Quarter => (4, "a year"),
+            Frame::Hour => (24, "a day"),
+            Frame::Minute => (60, "an hour"),
+            Frame::Second => (60, "a minute"),
+            _ if n == 1 => return Ok(()),
+            _ => {
+                return Err(exceptions::PyValueError::new_err(format!(
+                    "frame `{}` doesn't support n > 1",
+                    self.name()
+                )))
+            }
+        };
+        if units % n != 0 {
+            return Err(exceptions::PyValueError::new_err(format!(
+                "n has to evenly divide the {} {}s of {}",
+                units,
+                self.name(),
+                parent
+            )));
+        }
+        Ok(())
+    }
+
+    /// Whether spans of `count` times `n` frames can be counted from an anchor, which takes
+    /// frames of a fixed length on the wall clock.
+    fn check_anchor(&self, count: i64, n: u32) -> PyResult<()> {
+        if n < 1 {
+            return Err(exceptions::PyValueError::new_err(
+                "n has to be a positive int",
+            ));
+        }
+        if count < 1 {
+            return Err(exceptions::PyValueError::new_err(
+                "count has to be a positive int with an anchor",
+            ));
+        }
+        match self {
+            Frame::Day
+            | Frame::Week
+            | Frame::IsoWeek
+            | Frame::Hour
+            | Frame::Minute
+            | Frame::Second => Ok(()),
+            _ => Err(exceptions::PyValueError::new_err(format!(
+                "frame `{}` doesn't support an anchor, its length varies",
+                self.name()
+            ))),
+        }
+    }
+
+    /// The rank of the frames used as precisions of ISO 8601 interval endpoints, from `year`.
+    fn precision(&self) -> u8 {
+        match self {
+            Frame::Year => 0,
+            Frame::Quarter => 1,
+            Frame::Month => 2,
+            Frame::Week | Frame::IsoWeek => 3,
+            Frame::Day => 4,
+            Frame::Hour => 5,
+            Frame::Minute => 6,
+            Frame::Second => 7,
+            Frame::Microsecond => 8,
+        }
+    }
+
+    /// The most frames of a span, more would be longer than the supported years.
+    fn max_frames(&self) -> u64 {
+        // the days from 0001-01-01 to 9999-12-31
+        const DAYS: u64 = 3_652_059;
+        match self {
+            Frame::Year => 9_999,
+            Frame::Quarter => 4 * 9_999,
+            Frame::Month => 12 * 9_999,
+            Frame::Week | Frame::IsoWeek => DAYS / 7,
+            Frame::Day => DAYS,
+            Frame::Hour => DAYS * 24,
+            Frame::Minute => DAYS * 1_440,
+            Frame::Second => DAYS * 86_400,
+            Frame::Microsecond => DAYS * 86_400_000_000,
+        }
+    }
+
+    /// Frames of whole days, which span wall-clock days rather than elapsed time.
+    fn is_calendar(&self) -> bool {
+        !matches!(
+            self,
+            Frame::Hour | Frame::Minute | Frame::Second | Frame::Microsecond
+        )
+    }
+
     fn duration(self) -> RelativeDelta {
         match self {
             Frame::Year => RelativeDelta::with_years(1).new(),
@@ -1375,14 +3855,14 @@ impl Frame {
             Frame::Minute => RelativeDelta::with_minutes(1).new(),
             Frame::Second => RelativeDelta::with_seconds(1).new(),
             Frame::Microsecond => RelativeDelta::with_nanoseconds(1000).new(),
-            Frame::Week => RelativeDelta::with_days(7).new(),
+            Frame::Week | Frame::IsoWeek => RelativeDelta::with_days(7).new(),
             Frame::Quarter => RelativeDelta::with_months(3).new(),
         }
     }
 }
 
 #[derive(FromPyObject)]
-enum DateTimeLike<'p> {
+pub(crate) enum DateTimeLike<'p> {
     AtomicClock(AtomicClock),
     PyDateTime(&'p PyDateTime),
 }
@@ -1394,13 +3874,68 @@ impl DateTimeLike<'_> {
             DateTimeLike::PyDateTime(dt) => AtomicClock::fromdatetime(dt, None),
         }
     }
+
+    /// The UTC time, without building an `AtomicClock` for a `datetime`.
+    fn naive_utc(&self) -> PyResult<NaiveDateTime> {
+        match self {
+            DateTimeLike::AtomicClock(dt) => Ok(dt.datetime.naive_utc()),
+            DateTimeLike::PyDateTime(dt) => naive_utc_from_datetime(dt),
+        }
+    }
+
+    /// Like `naive_utc`, for the operand of a subtraction, see `AtomicClock::operand_naive_utc`.
+    fn operand_naive_utc(&self, assume_utc: bool) -> PyResult<NaiveDateTime> {
+        match self {
+            DateTimeLike::AtomicClock(dt) => Ok(dt.datetime.naive_utc()),
+            DateTimeLike::PyDateTime(dt) => AtomicClock::operand_naive_utc(dt, assume_utc),
+        }
+    }
+
+    pub(crate) fn to_utc(&self) -> PyResult<DateTime<Utc>> {
+        Ok(DateTime::from_utc(self.naive_utc()?, Utc))
+    }
+}
+
+/// The start of a range, an ISO 8601 interval string stands for both the start and the end.
+#[derive(FromPyObject)]
+pub(crate) enum RangeStart<'p> {
+    DateTimeLike(DateTimeLike<'p>),
+    Interval(&'p str),
+}
+
+impl RangeStart<'_> {
+    /// Like `resolve`, for the ranges that need an end.
+    fn resolve_bounded(&self, end: Option<DateTimeLike>) -> PyResult<(AtomicClock, AtomicClock)> {
+        match self.resolve(end)? {
+            (start, Some(end)) => Ok((start, end)),
+            (_, None) => Err(exceptions::PyTypeError::new_err(
+                "end is required unless start is an ISO 8601 interval",
+            )),
+        }
+    }
+
+    fn resolve(&self, end: Option<DateTimeLike>) -> PyResult<(AtomicClock, Option<AtomicClock>)> {
+        match (self, end) {
+            (RangeStart::DateTimeLike(start), end) => Ok((
+                start.to_atomic_clock()?,
+                end.map(|end| end.to_atomic_clock()).transpose()?,
+            )),
+            (RangeStart::Interval(interval), None) => {
+                let (start, end) = parse_interval_str(interval)?;
+                Ok((start, Some(end)))
+            }
+            (RangeStart::Interval(_), Some(_)) => Err(exceptions::PyTypeError::new_err(
+                "end has to be omitted when start is an ISO 8601 interval",
+            )),
+        }
+    }
 }
 
 #[pyclass(name = "RelativeDelta", module = "atomic_clock")]
 #[pyo3(
     text_signature = "(*, years = 0, months = 0, days = 0, hours = 0, minutes = 0, seconds = 0, microseconds = 0, weeks = 0, quarters = 0)"
 )]
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct PyRelativeDelta {
     #[pyo3(get, set)]
     years: i32,
@@ -1482,6 +4017,32 @@ impl PyRelativeDelta {
                 self.years, self.months, self.days, self.hours, self.minutes, self.seconds, self.microseconds, self.weeks, self.quarters, self.weekday.map_or("None".to_string(), |w| w.to_string()))
     }
 
+    fn __richcmp__(&self, py: Python, other: PyRef<Self>, op: CompareOp) -> PyObject {
+        match op {
+            CompareOp::Eq => (*self == *other).into_py(py),
+            CompareOp::Ne => (*self != *other).into_py(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self, py: Python) -> PyResult<isize> {
+        // the hash of the field tuple, fields are settable so don't mutate a delta used as a key
+        let fields: PyObject = (
+            self.years,
+            self.months,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+            self.microseconds,
+            self.weeks,
+            self.quarters,
+            self.weekday,
+        )
+            .into_py(py);
+        fields.as_ref(py).hash()
+    }
+
     fn __neg__(&self) -> Self {
         Self {
             years: -self.years,
@@ -1498,6 +4059,38 @@ impl PyRelativeDelta {
     }
 }
 
+#[derive(FromPyObject)]
+enum TzLikeOrOffset<'p> {
+    TzLike(PyTzLike<'p>),
+    OffsetMinutes(i32),
+}
+
+impl TzLikeOrOffset<'_> {
+    fn try_to_tz(self) -> PyResult<HybridTz> {
+        match self {
+            TzLikeOrOffset::TzLike(tz) => tz.try_to_tz(),
+            TzLikeOrOffset::OffsetMinutes(minutes) => {
+                let offset = (minutes.abs() < 24 * 60)
+                    .then(|| FixedOffset::east_opt(minutes * 60))
+                    .flatten()
+                    .ok_or_else(|| {
+                        TimezoneError::new_err(
+                            "invalid offset minutes, valid offset should be -1439..1439",
+                        )
+                    })?;
+                Ok(HybridTz::Offset(offset))
+            }
+        }
+    }
+}
+
+/// The right-hand side of a clock comparison, strings are parsed like `get` does.
+#[derive(FromPyObject)]
+enum CompareOperand<'p> {
+    DateTimeLike(DateTimeLike<'p>),
+    Str(&'p str),
+}
+
 #[derive(FromPyObject)]
 enum DeltaLike<'p> {
     RelativeDelta(PyRelativeDelta),
@@ -1515,28 +4108,88 @@ struct DatetimeSpanRangeIter {
     generator: DatetimeRangeGenerator,
     frame: Frame,
     interval: i64,
+    n: u32,
     bounds: Bounds,
     exact: bool,
+    week_start: u32,
+    /// Counts the spans from this instant instead of the calendar.
+    anchor: Option<AtomicClock>,
     end: AtomicClock,
+    chunk_size: Option<usize>,
+    /// Yields the floor of each span instead of the `(floor, ceil)` tuple.
+    starts_only: bool,
 }
 
 impl DatetimeSpanRangeIter {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         generator: DatetimeRangeGenerator,
         frame: Frame,
         interval: i64,
+        n: u32,
         bounds: Bounds,
         exact: bool,
+        week_start: u32,
+        anchor: Option<AtomicClock>,
         end: AtomicClock,
+        chunk_size: Option<usize>,
+        starts_only: bool,
     ) -> Self {
         Self {
             generator,
             frame,
             interval,
+            n,
             bounds,
             exact,
+            week_start,
+            anchor,
             end,
+            chunk_size,
+            starts_only,
+        }
+    }
+
+    fn next(&mut self) -> PyResult<Option<(AtomicClock, AtomicClock)>> {
+        let dt = match self.generator.next() {
+            Some(dt) => dt,
+            None => return Ok(None),
+        };
+
+        let (floor, mut ceil) = dt.span(
+            self.frame.clone(),
+            self.interval,
+            self.n,
+            self.bounds.clone(),
+            self.exact,
+            self.week_start,
+            self.anchor.clone().map(DateTimeLike::AtomicClock),
+        )?;
+
+        if !self.exact {
+            return Ok(Some((floor, ceil)));
+        }
+
+        // the last instant a span may reach, `end` itself is excluded by an open end bound
+        let limit = match &self.bounds {
+            Bounds::BothExclude | Bounds::StartInclude => {
+                self.end.datetime.clone() - Duration::microseconds(1)
+            }
+            Bounds::BothInclude | Bounds::EndInclude => self.end.datetime.clone(),
+        };
+        if ceil.datetime > limit {
+            // the final span is truncated at `end`, a span starting at `end` is empty
+            if dt.datetime >= self.end.datetime {
+                return Ok(None);
+            }
+
+            ceil = AtomicClock::from(limit);
+            // nothing is left of a span once its bounds are applied
+            if floor.datetime > ceil.datetime {
+                return Ok(None);
+            }
         }
+        Ok(Some((floor, ceil)))
     }
 }
 
@@ -1546,46 +4199,207 @@ impl DatetimeSpanRangeIter {
         slf
     }
 
-    fn __next__(mut slf: PyRefMut<Self>) -> Option<(AtomicClock, AtomicClock)> {
-        let dt = slf.generator.next()?;
-
-        let (floor, mut ceil) = dt
-            .span(
-                slf.frame.clone(),
-                slf.interval,
-                slf.bounds.clone(),
-                slf.exact,
-                1,
-            )
-            .unwrap();
-
-        if slf.exact && ceil.timestamp() > slf.end.timestamp() {
-            if floor.timestamp() == slf.end.timestamp()
-                || floor
-                    .shift(0, 0, 0, 0, 0, 0, -1, 0, 0, None)
-                    .unwrap()
-                    .timestamp()
-                    == slf.end.timestamp()
-            {
-                return None;
+    fn __next__(mut slf: PyRefMut<Self>, py: Python) -> PyResult<Option<PyObject>> {
+        let starts_only = slf.starts_only;
+        match slf.chunk_size {
+            Some(chunk_size) => {
+                let iter = &mut *slf;
+                if starts_only {
+                    next_chunk(py, chunk_size, || Ok(iter.next()?.map(|span| span.0)))
+                } else {
+                    next_chunk(py, chunk_size, || iter.next())
+                }
             }
+            None if starts_only => Ok(slf.next()?.map(|span| span.0.into_py(py))),
+            None => Ok(slf.next()?.map(|span| span.into_py(py))),
+        }
+    }
+}
 
-            ceil = slf.end.clone();
-            if matches!(&slf.bounds, Bounds::BothExclude | Bounds::StartInclude) {
-                ceil = ceil.shift(0, 0, 0, 0, 0, 0, -1, 0, 0, None).unwrap()
+fn check_chunk_size(chunk_size: Option<usize>) -> PyResult<()> {
+    if chunk_size == Some(0) {
+        return Err(exceptions::PyValueError::new_err(
+            "chunk_size has to be a positive int",
+        ));
+    }
+    Ok(())
+}
+
+/// Collects up to `chunk_size` values of `next` into a list, without holding the GIL as
+/// the values are computed in Rust. Returns `None` once `next` is exhausted, an error of
+/// `next` is raised.
+fn next_chunk<T, F>(py: Python, chunk_size: usize, mut next: F) -> PyResult<Option<PyObject>>
+where
+    T: IntoPy<PyObject> + Send,
+    F: FnMut() -> PyResult<Option<T>> + Send,
+{
+    let chunk: Vec<T> = py.allow_threads(|| {
+        std::iter::from_fn(|| next().transpose())
+            .take(chunk_size)
+            .collect::<PyResult<_>>()
+    })?;
+    if chunk.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(chunk.into_py(py)))
+    }
+}
+
+/// Parses the three HTTP-date formats of RFC 7231: IMF-fixdate, the obsolete
+/// RFC 850 format and ANSI C's asctime() format, all of them in GMT.
+fn parse_http_date(s: &str) -> chrono::ParseResult<NaiveDateTime> {
+    use chrono::format::{parse, Parsed, StrftimeItems};
+
+    let mut parsed = Parsed::new();
+    parse(
+        &mut parsed,
+        s,
+        StrftimeItems::new("%a, %d %b %Y %H:%M:%S GMT"),
+    )
+    .or_else(|_| {
+        parsed = Parsed::new();
+        parse(
+            &mut parsed,
+            s,
+            StrftimeItems::new("%A, %d-%b-%y %H:%M:%S GMT"),
+        )?;
+        // a two-digit year which appears to be more than 50 years in the future
+        // represents the most recent year in the past with the same last two digits
+        if let Some(year_mod_100) = parsed.year_mod_100 {
+            let this_year = Utc::now().year();
+            let mut year = this_year - this_year % 100 + year_mod_100;
+            if year > this_year + 50 {
+                year -= 100;
             }
+            parsed.set_year(year as i64)?;
         }
-        Some((floor, ceil))
-    }
+        Ok(())
+    })
+    .or_else(|_: chrono::ParseError| {
+        parsed = Parsed::new();
+        parse(&mut parsed, s, StrftimeItems::new("%a %b %e %H:%M:%S %Y"))
+    })?;
+
+    parsed.to_naive_datetime_with_offset(0)
 }
 
-fn normalize_duration(duration: Duration) -> (i64, i64, i64) {
+/// The nanoseconds of elapsed time in the fixed-length units of a shift.
+fn elapsed_nanos(
+    weeks: i64,
+    days: i64,
+    hours: i64,
+    minutes: i64,
+    seconds: i64,
+    microseconds: i64,
+) -> i128 {
+    (i128::from(days) + i128::from(weeks) * 7) * NANOS_PER_DAY
+        + i128::from(hours) * 3_600_000_000_000
+        + i128::from(minutes) * 60_000_000_000
+        + i128::from(seconds) * 1_000_000_000
+        + i128::from(microseconds) * 1_000
+}
+
+/// The exact nanoseconds of a `timedelta`, which don't fit in i64 for the largest ones.
+fn timedelta_nanos(delta: &PyDelta) -> i128 {
+    i128::from(delta.get_days()) * NANOS_PER_DAY
+        + i128::from(delta.get_seconds()) * 1_000_000_000
+        + i128::from(delta.get_microseconds()) * 1_000
+}
+
+/// The whole nanoseconds of `duration`, which only fit in i64 for about 292 years.
+fn duration_nanos(duration: Duration) -> i128 {
+    let seconds = duration.num_seconds();
+    let nanos = (duration - Duration::seconds(seconds))
+        .num_nanoseconds()
+        .unwrap_or(0);
+    seconds as i128 * 1_000_000_000 + nanos as i128
+}
+
+pub(crate) fn normalize_duration(duration: Duration) -> (i64, i64, i64) {
     let mut duration = duration;
     let days = duration.num_days();
     duration = duration - Duration::days(days);
     let seconds = duration.num_seconds();
     duration = duration - Duration::seconds(seconds);
-    let microseconds = duration.num_microseconds().or(Some(0)).unwrap();
+    let microseconds = duration.num_microseconds().unwrap_or(0);
 
     (days, seconds, microseconds)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clock(year: i32, month: u32, day: u32, nanosecond: u32) -> AtomicClock {
+        let naive = NaiveDate::from_ymd(year, month, day).and_hms_nano(12, 30, 45, nanosecond);
+        AtomicClock::from(UTC.from_utc_datetime(&naive))
+    }
+
+    fn clocks() -> Vec<AtomicClock> {
+        vec![
+            clock(1, 1, 1, 0),
+            clock(1677, 9, 21, 5),
+            clock(1969, 12, 31, 999_999_999),
+            clock(1970, 1, 1, 1),
+            clock(2021, 1, 3, 123_456_789),
+            clock(2262, 4, 12, 0),
+            clock(2300, 1, 1, 500),
+            clock(9999, 12, 31, 999_999_999),
+        ]
+    }
+
+    #[test]
+    fn cached_timestamp_nanos_match_uncached() {
+        let epoch = NaiveDate::from_ymd(1970, 1, 1).and_hms(0, 0, 0);
+        for clock in clocks() {
+            let expected = duration_nanos(clock.datetime.naive_utc() - epoch);
+
+            assert_eq!(timestamp_nanos(&clock.datetime), expected);
+            assert_eq!(clock.timestamp_nanos(), expected);
+            // the second call reads the cache
+            assert_eq!(clock.timestamp_nanos(), expected);
+            assert_eq!(clock.clone().timestamp_nanos(), expected);
+            assert_eq!(clock.timestamp(), nanos_to_seconds(expected));
+        }
+    }
+
+    #[test]
+    fn timestamp_beyond_i64_nanos() {
+        assert_eq!(clock(2300, 1, 1, 0).timestamp(), 10_413_837_045.0);
+        assert_eq!(clock(1600, 1, 1, 0).timestamp(), -11_676_050_955.0);
+        assert!(clock(9999, 12, 31, 0).timestamp() > 0.0);
+    }
+
+    #[test]
+    fn cached_iso_calendar_matches_uncached() {
+        for clock in clocks() {
+            let expected = iso_calendar(&clock.datetime);
+
+            assert_eq!(clock.iso_calendar(), expected);
+            assert_eq!(clock.iso_calendar(), expected);
+            assert_eq!(clock.clone().iso_calendar(), expected);
+        }
+        // 2021-01-03 is the Sunday of the last ISO week of 2020
+        assert_eq!(clock(2021, 1, 3, 0).iso_calendar(), (2020, 53, 7));
+    }
+
+    #[test]
+    fn timestamp_nanos_are_exact() {
+        let nanos = |timestamp: f64| {
+            let naive = naive_from_timestamp(timestamp).ok().unwrap();
+            naive.timestamp() as i128 * NANOS_PER_SECOND + naive.timestamp_subsec_nanos() as i128
+        };
+
+        assert_eq!(nanos(0.0), 0);
+        assert_eq!(nanos(-0.0), 0);
+        assert_eq!(nanos(f64::from_bits(1)), 0);
+        assert_eq!(nanos(0.1), 100_000_000);
+        assert_eq!(nanos(-1.5), -1_500_000_000);
+        // 2^-10 seconds is 976562.5 nanoseconds, halves are rounded away from zero
+        assert_eq!(nanos(2f64.powi(-10)), 976_563);
+        assert_eq!(nanos(-(2f64.powi(-10))), -976_563);
+        // the binary value of the decimal literal is a nanosecond above it
+        assert_eq!(nanos(1_647_335_445.123_456), 1_647_335_445_123_456_001);
+        assert_eq!(nanos(1e10), 10_000_000_000 * NANOS_PER_SECOND);
+    }
+}
@@ -0,0 +1,181 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use chrono::{DateTime, LocalResult, NaiveDateTime, Utc};
+use pyo3::{
+    exceptions,
+    prelude::*,
+    types::{PyDelta, PyDeltaAccess},
+};
+
+use crate::{atomic_clock::DateTimeLike, errors::TimezoneError};
+
+/// A timezone installed by `register_timezone`, resolvable by its name for the rest of the
+/// process.
+///
+/// Clocks share the zone, so they keep it after it's unregistered or replaced under the
+/// same name.
+#[derive(PartialEq, Eq, Hash, Debug)]
+pub(crate) struct CustomTz {
+    name: String,
+    /// The UTC timestamps at which offsets in seconds east of UTC take effect, in increasing
+    /// order.  The first offset also applies before its timestamp.
+    offsets: Vec<(i64, i32)>,
+}
+
+impl CustomTz {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn offset_at(&self, utc: &NaiveDateTime) -> i32 {
+        let timestamp = utc.timestamp();
+        let index = self.offsets.partition_point(|&(at, _)| at <= timestamp);
+        self.offsets[index.saturating_sub(1)].1
+    }
+
+    /// The changes of the offset, as the instant they take effect with the offsets before and
+    /// after it.
+    pub fn transitions(&self) -> impl DoubleEndedIterator<Item = (DateTime<Utc>, i32, i32)> + '_ {
+        self.offsets
+            .windows(2)
+            .filter(|pair| pair[0].1 != pair[1].1)
+            .map(|pair| {
+                let instant = DateTime::from_utc(NaiveDateTime::from_timestamp(pair[1].0, 0), Utc);
+                (instant, pair[0].1, pair[1].1)
+            })
+    }
+
+    /// The offsets with which the wall time `local` is in effect, in the order of the
+    /// instants they give.
+    pub fn offset_at_local(&self, local: &NaiveDateTime) -> LocalResult<i32> {
+        let timestamp = local.timestamp();
+        let mut matches = self
+            .offsets
+            .iter()
+            .enumerate()
+            .filter(|&(index, &(at, offset))| {
+                let utc = timestamp - offset as i64;
+                let end = self
+                    .offsets
+                    .get(index + 1)
+                    .map_or(i64::MAX, |&(end, _)| end);
+                (index == 0 || at <= utc) && utc < end
+            })
+            .map(|(_, &(_, offset))| offset);
+        match (matches.next(), matches.next_back()) {
+            (None, _) => LocalResult::None,
+            (Some(offset), None) => LocalResult::Single(offset),
+            (Some(earlier), Some(later)) => LocalResult::Ambiguous(earlier, later),
+        }
+    }
+}
+
+lazy_static! {
+    // it's only locked with the GIL held, so a forked child can't inherit it locked
+    static ref REGISTRY: RwLock<HashMap<String, Arc<CustomTz>>> = RwLock::new(HashMap::new());
+}
+
+/// The zone registered as `name`, which takes precedence over the tz database.
+pub(crate) fn lookup(name: &str) -> Option<Arc<CustomTz>> {
+    REGISTRY.read().unwrap().get(name).cloned()
+}
+
+#[derive(FromPyObject)]
+pub(crate) enum OffsetLike<'p> {
+    Seconds(i32),
+    Delta(&'p PyDelta),
+}
+
+impl OffsetLike<'_> {
+    fn seconds(&self) -> PyResult<i32> {
+        let seconds = match self {
+            OffsetLike::Seconds(seconds) => *seconds as i64,
+            OffsetLike::Delta(delta) => {
+                if delta.get_microseconds() != 0 {
+                    return Err(exceptions::PyValueError::new_err(
+                        "timezone offset must be whole seconds",
+                    ));
+                }
+                delta.get_days() as i64 * 86400 + delta.get_seconds() as i64
+            }
+        };
+        if seconds.abs() >= 86400 {
+            return Err(exceptions::PyValueError::new_err(
+                "timezone offset out of range",
+            ));
+        }
+        Ok(seconds as i32)
+    }
+}
+
+#[derive(FromPyObject)]
+pub(crate) enum ZoneRules<'p> {
+    Offset(OffsetLike<'p>),
+    Transitions(Vec<(DateTimeLike<'p>, OffsetLike<'p>)>),
+}
+
+/// Installs a zone resolvable by `name`, with a fixed offset or a list of
+/// `(instant, offset)` pairs.
+#[pyfunction]
+#[pyo3(text_signature = "(name, offset_or_rules)")]
+pub fn register_timezone(name: &str, offset_or_rules: ZoneRules) -> PyResult<()> {
+    if matches!(name, "" | "utc" | "UTC" | "local") {
+        return Err(exceptions::PyValueError::new_err(format!(
+            "can't register the timezone {:?}",
+            name
+        )));
+    }
+
+    let offsets = match offset_or_rules {
+        ZoneRules::Offset(offset) => vec![(i64::MIN, offset.seconds()?)],
+        ZoneRules::Transitions(transitions) => {
+            let mut offsets = Vec::with_capacity(transitions.len());
+            for (instant, offset) in transitions {
+                offsets.push((instant.to_utc()?.timestamp(), offset.seconds()?));
+            }
+            if offsets.is_empty() {
+                return Err(exceptions::PyValueError::new_err(
+                    "timezone rules are empty",
+                ));
+            }
+            if offsets.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+                return Err(exceptions::PyValueError::new_err(
+                    "transition instants must be increasing",
+                ));
+            }
+            offsets
+        }
+    };
+
+    let tz = Arc::new(CustomTz {
+        name: name.to_owned(),
+        offsets,
+    });
+    REGISTRY.write().unwrap().insert(name.to_owned(), tz);
+    Ok(())
+}
+
+/// Removes a zone installed by `register_timezone`, clocks in it are unaffected.
+#[pyfunction]
+#[pyo3(text_signature = "(name)")]
+pub fn unregister_timezone(name: &str) -> PyResult<()> {
+    match REGISTRY.write().unwrap().remove(name) {
+        Some(_) => Ok(()),
+        None => Err(TimezoneError::new_err(format!(
+            "no timezone {:?} is registered",
+            name
+        ))),
+    }
+}
+
+/// The names of the zones installed by `register_timezone`, sorted.
+#[pyfunction]
+#[pyo3(text_signature = "()")]
+pub fn custom_timezones() -> Vec<String> {
+    let mut names: Vec<String> = REGISTRY.read().unwrap().keys().cloned().collect();
+    names.sort();
+    names
+}
@@ -0,0 +1,90 @@
+// pyo3's exception macros check a `cfg` that newer compilers don't know about
+#![allow(unexpected_cfgs)]
+
+use pyo3::{
+    create_exception,
+    exceptions::{PyException, PyOverflowError, PyValueError},
+};
+
+create_exception!(
+    atomic_clock,
+    AtomicClockError,
+    PyException,
+    "Base class of the errors raised by atomic_clock."
+);
+
+/// Like `create_exception!`, but the exception derives from several bases, so that the errors
+/// are also instances of the builtin exceptions raised before they existed.
+macro_rules! create_exception_with_bases {
+    ($module: ident, $name: ident, ($($base: ty),+), $doc: expr) => {
+        #[repr(transparent)]
+        #[doc = $doc]
+        pub struct $name(pyo3::PyAny);
+
+        pyo3::impl_exception_boilerplate!($name);
+
+        pyo3::pyobject_native_type_core!(
+            $name,
+            *$name::type_object_raw(pyo3::Python::assume_gil_acquired()),
+            #module=Some(stringify!($module))
+        );
+
+        impl $name {
+            fn type_object_raw(py: pyo3::Python<'_>) -> *mut pyo3::ffi::PyTypeObject {
+                use pyo3::{
+                    once_cell::GILOnceCell,
+                    types::{PyTuple, PyType},
+                    AsPyPointer, Py,
+                };
+                use std::ffi::CString;
+
+                static TYPE_OBJECT: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+                TYPE_OBJECT
+                    .get_or_init(py, || {
+                        // `PyErr::new_type` takes a single base, the C API accepts a tuple
+                        let bases = PyTuple::new(py, [$(py.get_type::<$base>()),+]);
+                        let name =
+                            CString::new(concat!(stringify!($module), ".", stringify!($name)))
+                                .unwrap();
+                        let doc = CString::new($doc).unwrap();
+                        unsafe {
+                            Py::from_owned_ptr_or_err(
+                                py,
+                                pyo3::ffi::PyErr_NewExceptionWithDoc(
+                                    name.as_ptr(),
+                                    doc.as_ptr(),
+                                    bases.as_ptr(),
+                                    std::ptr::null_mut(),
+                                ),
+                            )
+                        }
+                        .expect("Failed to initialize new exception type.")
+                    })
+                    .as_ptr() as *mut pyo3::ffi::PyTypeObject
+            }
+        }
+    };
+}
+
+create_exception_with_bases!(
+    atomic_clock,
+    ParserError,
+    (AtomicClockError, PyValueError),
+    "Raised when a string or other input can't be parsed into a datetime."
+);
+
+create_exception_with_bases!(
+    atomic_clock,
+    TimezoneError,
+    (AtomicClockError, PyValueError),
+    "Raised when a timezone expression is unknown or invalid."
+);
+
+create_exception_with_bases!(
+    atomic_clock,
+    RangeError,
+    // `ValueError` too, as out of range inputs used to raise it
+    (AtomicClockError, PyOverflowError, PyValueError),
+    "Raised when a datetime, ordinal or timestamp is outside of the supported range."
+);
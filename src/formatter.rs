@@ -0,0 +1,293 @@
+use chrono::{DateTime, Datelike, Offset, TimeZone, Timelike};
+
+use crate::{hybrid_tz::HybridTz, locales::Locale};
+
+/// Arrow-style tokens, longer tokens first so that e.g. `MMMM` isn't read as `MM` twice.
+const TOKENS: [&str; 40] = [
+    "YYYY", "YY", "Qo", "MMMM", "MMM", "MM", "Mo", "M", "DDDD", "DDDo", "DDD", "DD", "Do", "D",
+    "dddd", "ddd", "d", "HH", "H", "hh", "h", "mm", "m", "ss", "s", "SSSSSS", "SSSSS", "SSSS",
+    "SSS", "SS", "S", "ZZZ", "ZZ", "Z", "a", "A", "X", "x", "Wo", "W",
+];
+
+/// Formats `datetime` with Arrow-style tokens like `YYYY-MM-DD`, text in square brackets is
+/// kept as-is.
+pub(crate) fn format_tokens(datetime: &DateTime<HybridTz>, fmt: &str, locale: &Locale) -> String {
+    let mut result = String::with_capacity(fmt.len() * 2);
+    let mut rest = fmt;
+
+    while let Some(c) = rest.chars().next() {
+        if c == '[' {
+            if let Some(end) = rest.find(']') {
+                result.push_str(&rest[1..end]);
+                rest = &rest[end + 1..];
+                continue;
+            }
+        }
+
+        match TOKENS.iter().find(|token| rest.starts_with(*token)) {
+            Some(token) => {
+                result.push_str(&format_token(datetime, token, locale));
+                rest = &rest[token.len()..];
+            }
+            None => {
+                result.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    result
+}
+
+fn format_token(datetime: &DateTime<HybridTz>, token: &str, locale: &Locale) -> String {
+    match token {
+        "YYYY" => format!("{:04}", datetime.year()),
+        "YY" => format!("{:02}", datetime.year().rem_euclid(100)),
+        "Qo" => (locale.ordinal)((datetime.month() - 1) / 3 + 1),
+
+        "MMMM" => locale.month_name(datetime.month()).to_string(),
+        "MMM" => locale.month_abbreviation(datetime.month()).to_string(),
+        "MM" => format!("{:02}", datetime.month()),
+        "Mo" => (locale.ordinal)(datetime.month()),
+        "M" => datetime.month().to_string(),
+
+        "DDDD" => format!("{:03}", datetime.ordinal()),
+        "DDDo" => (locale.ordinal)(datetime.ordinal()),
+        "DDD" => datetime.ordinal().to_string(),
+        "DD" => format!("{:02}", datetime.day()),
+        "Do" => (locale.ordinal)(datetime.day()),
+        "D" => datetime.day().to_string(),
+
+        "dddd" => locale.day_names[datetime.weekday().num_days_from_monday() as usize].to_string(),
+        "ddd" => {
+            locale.day_abbreviations[datetime.weekday().num_days_from_monday() as usize].to_string()
+        }
+        "d" => datetime.weekday().number_from_monday().to_string(),
+
+        "HH" => format!("{:02}", datetime.hour()),
+        "H" => datetime.hour().to_string(),
+        "hh" => format!("{:02}", datetime.hour12().1),
+        "h" => datetime.hour12().1.to_string(),
+        "mm" => format!("{:02}", datetime.minute()),
+        "m" => datetime.minute().to_string(),
+        "ss" => format!("{:02}", datetime.second()),
+        "s" => datetime.second().to_string(),
+
+        "SSSSSS" | "SSSSS" | "SSSS" | "SSS" | "SS" | "S" => {
+            // fractions are truncated, not rounded
+            let digits = token.len();
+            let fraction = datetime.nanosecond() % 1_000_000_000 / 10u32.pow(9 - digits as u32);
+            format!("{:0digits$}", fraction)
+        }
+
+        "ZZZ" => datetime.format("%Z").to_string(),
+        "ZZ" => datetime.format("%:z").to_string(),
+        "Z" => datetime.format("%z").to_string(),
+
+        "a" => locale.meridian(datetime.hour12().0, false).to_string(),
+        "A" => locale.meridian(datetime.hour12().0, true).to_string(),
+
+        "X" => datetime.timestamp().to_string(),
+        "x" => (datetime.timestamp() as i128 * 1_000_000
+            + datetime.timestamp_subsec_micros() as i128)
+            .to_string(),
+
+        "Wo" => (locale.ordinal)(datetime.iso_week().week()),
+        "W" => {
+            let week = datetime.iso_week();
+            format!(
+                "{}-W{:02}-{}",
+                week.year(),
+                week.week(),
+                datetime.weekday().number_from_monday()
+            )
+        }
+
+        _ => unreachable!("unknown token {}", token),
+    }
+}
+
+/// Formats `datetime` with chrono's strftime directives, the day, month and meridian names
+/// are taken from `locale`.
+pub(crate) fn strftime(datetime: &DateTime<HybridTz>, fmt: &str, locale: &Locale) -> String {
+    let mut localized = String::with_capacity(fmt.len());
+    let mut chars = fmt.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            localized.push(c);
+            continue;
+        }
+
+        let weekday = datetime.weekday().num_days_from_monday() as usize;
+        let (is_pm, _) = datetime.hour12();
+        let name = match chars.next() {
+            Some('A') => locale.day_names[weekday],
+            Some('a') => locale.day_abbreviations[weekday],
+            Some('B') => locale.month_name(datetime.month()),
+            Some('b') | Some('h') => locale.month_abbreviation(datetime.month()),
+            Some('p') => locale.meridian(is_pm, true),
+            Some('P') => locale.meridian(is_pm, false),
+            Some(next) => {
+                localized.push(c);
+                localized.push(next);
+                continue;
+            }
+            None => {
+                localized.push(c);
+                continue;
+            }
+        };
+        localized.push_str(&name.replace('%', "%%"));
+    }
+
+    datetime.format(&localized).to_string()
+}
+
+/// The time fields `format_iso` writes, as the `timespec` of `isoformat`.
+#[derive(Clone, Copy)]
+pub(crate) enum Timespec {
+    Hours,
+    Minutes,
+    Seconds,
+    /// The seconds with a fraction of 3, 6 or 9 digits when it isn't zero, as `%.f`.
+    Auto,
+    Milliseconds,
+    Nanoseconds,
+}
+
+impl Timespec {
+    /// The strftime format `format_iso` matches.
+    pub fn strftime(self, sep: &str) -> String {
+        match self {
+            Timespec::Hours => format!("%Y-%m-%d{sep}%H%:z"),
+            Timespec::Minutes => format!("%Y-%m-%d{sep}%H:%M%:z"),
+            Timespec::Seconds => format!("%Y-%m-%d{sep}%H:%M:%S%:z"),
+            Timespec::Auto => format!("%Y-%m-%d{sep}%H:%M:%S%.f%:z"),
+            Timespec::Milliseconds => format!("%Y-%m-%d{sep}%H:%M:%S%.3f%:z"),
+            Timespec::Nanoseconds => format!("%Y-%m-%d{sep}%H:%M:%S%.9f%:z"),
+        }
+    }
+}
+
+/// A stack buffer for the fixed-width fields of ISO 8601 text.
+struct AsciiBuf {
+    bytes: [u8; 32],
+    len: usize,
+}
+
+impl AsciiBuf {
+    fn new() -> Self {
+        Self {
+            bytes: [0; 32],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, byte: u8) {
+        self.bytes[self.len] = byte;
+        self.len += 1;
+    }
+
+    /// Writes the last `width` digits of `value`, padded with zeros.
+    fn push_digits(&mut self, mut value: u32, width: usize) {
+        for byte in self.bytes[self.len..self.len + width].iter_mut().rev() {
+            *byte = b'0' + (value % 10) as u8;
+            value /= 10;
+        }
+        self.len += width;
+    }
+
+    fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+/// Formats an offset of `seconds` east of UTC as `±HH:MM`, or `±HHMM` without the
+/// `colon`, adding the seconds of the offsets that have them.
+pub(crate) fn format_offset(seconds: i32, colon: bool) -> String {
+    let mut text = AsciiBuf::new();
+    text.push(if seconds < 0 { b'-' } else { b'+' });
+    let seconds = seconds.unsigned_abs();
+    text.push_digits(seconds / 3600, 2);
+    if colon {
+        text.push(b':');
+    }
+    text.push_digits(seconds / 60 % 60, 2);
+    if !seconds.is_multiple_of(60) {
+        if colon {
+            text.push(b':');
+        }
+        text.push_digits(seconds % 60, 2);
+    }
+    text.as_str().to_owned()
+}
+
+/// Formats `datetime` as `timespec.strftime(sep)` does, writing the digits directly
+/// instead of interpreting chrono's format items.  This is also `to_rfc3339()` with the
+/// `Auto` timespec and `T`.
+///
+/// Returns `None` for the years outside 0 to 9999 and the leap seconds, which chrono
+/// writes differently depending on the format.
+pub(crate) fn format_iso<Tz: TimeZone>(
+    datetime: &DateTime<Tz>,
+    sep: &str,
+    timespec: Timespec,
+) -> Option<String> {
+    let year = datetime.year();
+    let nanosecond = datetime.nanosecond();
+    if !(0..=9999).contains(&year) || nanosecond >= 1_000_000_000 {
+        return None;
+    }
+
+    let mut date = AsciiBuf::new();
+    date.push_digits(year as u32, 4);
+    date.push(b'-');
+    date.push_digits(datetime.month(), 2);
+    date.push(b'-');
+    date.push_digits(datetime.day(), 2);
+
+    let mut time = AsciiBuf::new();
+    time.push_digits(datetime.hour(), 2);
+    if !matches!(timespec, Timespec::Hours) {
+        time.push(b':');
+        time.push_digits(datetime.minute(), 2);
+    }
+    if !matches!(timespec, Timespec::Hours | Timespec::Minutes) {
+        time.push(b':');
+        time.push_digits(datetime.second(), 2);
+    }
+    match timespec {
+        Timespec::Auto if nanosecond == 0 => {}
+        Timespec::Auto if nanosecond.is_multiple_of(1_000_000) => {
+            time.push(b'.');
+            time.push_digits(nanosecond / 1_000_000, 3);
+        }
+        Timespec::Auto if nanosecond.is_multiple_of(1_000) => {
+            time.push(b'.');
+            time.push_digits(nanosecond / 1_000, 6);
+        }
+        Timespec::Auto | Timespec::Nanoseconds => {
+            time.push(b'.');
+            time.push_digits(nanosecond, 9);
+        }
+        Timespec::Milliseconds => {
+            time.push(b'.');
+            time.push_digits(nanosecond / 1_000_000, 3);
+        }
+        Timespec::Hours | Timespec::Minutes | Timespec::Seconds => {}
+    }
+
+    // like chrono, the seconds of an offset are truncated
+    let offset = datetime.offset().fix().local_minus_utc();
+    time.push(if offset < 0 { b'-' } else { b'+' });
+    let offset = offset.unsigned_abs();
+    time.push_digits(offset / 3600, 2);
+    time.push(b':');
+    time.push_digits(offset / 60 % 60, 2);
+
+    let mut result = String::with_capacity(date.len + sep.len() + time.len);
+    result.push_str(date.as_str());
+    result.push_str(sep);
+    result.push_str(time.as_str());
+    Some(result)
+}
@@ -1,39 +1,225 @@
-use std::{fmt::Display, str::FromStr};
+use std::{fmt::Display, str::FromStr, sync::Arc};
 
-use chrono::{DateTime, Duration, FixedOffset, Local, Offset, TimeZone, Utc};
-use chrono_tz::{OffsetComponents, Tz, TzOffset};
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, Local, LocalResult, NaiveDateTime, Offset, TimeZone,
+    Timelike, Utc,
+};
+use chrono_tz::{OffsetComponents, TimeSpans, Tz, TzOffset};
 use pyo3::{
     exceptions,
+    once_cell::GILOnceCell,
     prelude::*,
     pyclass::CompareOp,
-    types::{PyDateTime, PyDelta, PyTzInfo},
+    types::{PyDateTime, PyDelta, PyTimeAccess, PyTzInfo},
+};
+
+use crate::{
+    atomic_clock::{
+        naive_from_datetime, AtomicClock, DateTimeLike, MAX_NAIVE_DATETIME, MIN_NAIVE_DATETIME,
+    },
+    custom_tz::{self, CustomTz},
+    errors::TimezoneError,
+    posix_tz::PosixTz,
 };
 
 lazy_static! {
     pub(crate) static ref UTC: HybridTz = HybridTz::Timespan(Tz::UTC);
-    pub(crate) static ref LOCAL: HybridTz = HybridTz::Offset(Local::now().offset().fix());
-    pub(crate) static ref UTC_NOW: DateTime<Utc> = Utc::now();
 }
 
-#[derive(PartialEq, Eq, Hash, Clone, Debug, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
 pub(crate) enum HybridTz {
     Offset(FixedOffset),
     Timespan(Tz),
+    Posix(PosixTz),
+    /// A zone installed by `register_timezone`.
+    Custom(Arc<CustomTz>),
 }
 
 impl HybridTz {
-    pub fn dst_offset(&self) -> Duration {
+    /// The current offset of the local timezone, it's looked up on every call so that
+    /// long-running processes follow DST transitions.
+    pub fn local() -> Self {
+        HybridTz::Offset(Local::now().offset().fix())
+    }
+
+    /// The offset of the local timezone at the UTC time `utc`, also looked up on every call.
+    pub fn local_at(utc: &NaiveDateTime) -> Self {
+        HybridTz::Offset(Local.offset_from_utc_datetime(utc).fix())
+    }
+
+    /// The offsets of the local timezone at the wall time `local`, which may be ambiguous or
+    /// skipped around DST transitions.
+    pub fn local_from_local(local: &NaiveDateTime) -> LocalResult<Self> {
+        Local
+            .offset_from_local_datetime(local)
+            .map(|offset| HybridTz::Offset(offset.fix()))
+    }
+
+    /// The offset from UTC at `instant`, in seconds.
+    pub fn offset_at(&self, instant: &DateTime<Utc>) -> i32 {
+        instant.with_timezone(self).offset().fix().local_minus_utc()
+    }
+
+    /// An instant at which the wall time `local` is in effect, `fold` picks the later of two
+    /// ambiguous times.  Like `zoneinfo`, a skipped wall time takes the offset from before the
+    /// transition, or from after it with `fold`, so a day away is returned instead.
+    pub fn instant_of_local(&self, local: &NaiveDateTime, fold: bool) -> DateTime<Utc> {
+        match self.from_local_datetime(local) {
+            LocalResult::Single(datetime) => datetime.with_timezone(&Utc),
+            LocalResult::Ambiguous(earlier, later) => {
+                if fold {
+                    later.with_timezone(&Utc)
+                } else {
+                    earlier.with_timezone(&Utc)
+                }
+            }
+            LocalResult::None => {
+                let day = if fold {
+                    Duration::days(1)
+                } else {
+                    Duration::days(-1)
+                };
+                DateTime::from_utc(*local + day, Utc)
+            }
+        }
+    }
+
+    /// The first change of the offset from UTC in `(after, until]`, as the instant it takes
+    /// effect with the offsets before and after it.  POSIX rules are probed a day apart,
+    /// then bisected to the second, so two changes within a day are missed.
+    pub fn next_transition(
+        &self,
+        after: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Option<(DateTime<Utc>, i32, i32)> {
+        match self {
+            HybridTz::Offset(_) => return None,
+            HybridTz::Timespan(tz) => {
+                return timespan_transitions(tz)
+                    .find(|&(instant, _, _)| after < instant && instant <= until)
+            }
+            HybridTz::Posix(posix) if posix.offset(true) == posix.offset(false) => return None,
+            HybridTz::Custom(custom) => {
+                return custom
+                    .transitions()
+                    .find(|&(instant, _, _)| after < instant && instant <= until)
+            }
+            _ => {}
+        }
+        // transitions fall on whole seconds
+        let mut lo = after - Duration::nanoseconds(after.timestamp_subsec_nanos() as i64);
+        let offset = self.offset_at(&lo);
+        while lo < until {
+            let hi = std::cmp::min(lo + Duration::days(1), until);
+            if self.offset_at(&hi) != offset {
+                return Some(self.bisect_transition(lo, hi));
+            }
+            lo = hi;
+        }
+        None
+    }
+
+    /// The last change of the offset from UTC in `[until, before)`, as `next_transition`.
+    pub fn prev_transition(
+        &self,
+        before: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Option<(DateTime<Utc>, i32, i32)> {
+        match self {
+            HybridTz::Offset(_) => return None,
+            HybridTz::Timespan(tz) => {
+                return timespan_transitions(tz)
+                    .rev()
+                    .find(|&(instant, _, _)| until <= instant && instant < before)
+            }
+            HybridTz::Posix(posix) if posix.offset(true) == posix.offset(false) => return None,
+            HybridTz::Custom(custom) => {
+                return custom
+                    .transitions()
+                    .rev()
+                    .find(|&(instant, _, _)| until <= instant && instant < before)
+            }
+            _ => {}
+        }
+        let mut hi = before - Duration::nanoseconds(before.timestamp_subsec_nanos() as i64);
+        if hi == before {
+            hi = hi - Duration::seconds(1);
+        }
+        let offset = self.offset_at(&hi);
+        while hi > until {
+            let lo = std::cmp::max(hi - Duration::days(1), until - Duration::seconds(1));
+            if self.offset_at(&lo) != offset {
+                return Some(self.bisect_transition(lo, hi));
+            }
+            hi = lo;
+        }
+        None
+    }
+
+    /// The first second after `lo` with the offset of `hi`, which differs from the one of `lo`.
+    fn bisect_transition(
+        &self,
+        mut lo: DateTime<Utc>,
+        mut hi: DateTime<Utc>,
+    ) -> (DateTime<Utc>, i32, i32) {
+        let before = self.offset_at(&lo);
+        while hi - lo > Duration::seconds(1) {
+            let mid = lo + Duration::seconds((hi - lo).num_seconds() / 2);
+            if self.offset_at(&mid) == before {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        (hi, before, self.offset_at(&hi))
+    }
+
+    pub fn dst_offset_at(&self, instant: &DateTime<Utc>) -> Duration {
         match self {
-            HybridTz::Offset(_) => Duration::seconds(0),
-            HybridTz::Timespan(timespan) => UTC_NOW.with_timezone(timespan).offset().dst_offset(),
+            HybridTz::Offset(_) | HybridTz::Custom(_) => Duration::seconds(0),
+            HybridTz::Timespan(timespan) => instant.with_timezone(timespan).offset().dst_offset(),
+            HybridTz::Posix(posix) => {
+                let is_dst = posix.is_dst_at(&instant.naive_utc());
+                Duration::seconds(
+                    (posix.offset(is_dst).local_minus_utc() - posix.offset(false).local_minus_utc())
+                        as i64,
+                )
+            }
         }
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// The changes of the offset from UTC listed in the tz data of `tz`, as
+/// `HybridTz::next_transition`. The list covers every change the zone has.
+fn timespan_transitions(tz: &Tz) -> impl DoubleEndedIterator<Item = (DateTime<Utc>, i32, i32)> {
+    let spans = tz.timespans();
+    spans
+        .rest
+        .iter()
+        .enumerate()
+        .map(move |(index, &(at, after))| {
+            let before = match index {
+                0 => spans.first,
+                _ => spans.rest[index - 1].1,
+            };
+            let instant = DateTime::from_utc(NaiveDateTime::from_timestamp(at, 0), Utc);
+            (
+                instant,
+                before.utc_offset + before.dst_offset,
+                after.utc_offset + after.dst_offset,
+            )
+        })
+        .filter(|&(_, before, after)| before != after)
+}
+
+#[derive(Debug, Clone)]
 pub(crate) enum HybridTzOffset {
     FixedOffset(FixedOffset),
     TzOffset(TzOffset),
+    /// A POSIX TZ offset, along with whether DST is in effect.
+    Posix(PosixTz, bool),
+    /// The offset of a registered zone, in seconds east of UTC.
+    Custom(Arc<CustomTz>, i32),
 }
 
 impl Offset for HybridTzOffset {
@@ -41,6 +227,8 @@ impl Offset for HybridTzOffset {
         match self {
             HybridTzOffset::FixedOffset(offset) => *offset,
             HybridTzOffset::TzOffset(offset) => offset.fix(),
+            HybridTzOffset::Posix(posix, is_dst) => posix.offset(*is_dst),
+            HybridTzOffset::Custom(_, offset) => FixedOffset::east(*offset),
         }
     }
 }
@@ -50,6 +238,8 @@ impl Display for HybridTzOffset {
         match self {
             HybridTzOffset::FixedOffset(offset) => offset.fmt(f),
             HybridTzOffset::TzOffset(tz_offset) => tz_offset.fmt(f),
+            HybridTzOffset::Posix(posix, is_dst) => f.write_str(posix.abbreviation(*is_dst)),
+            HybridTzOffset::Custom(_, _) => self.fix().fmt(f),
         }
     }
 }
@@ -61,6 +251,8 @@ impl TimeZone for HybridTz {
         match offset {
             HybridTzOffset::FixedOffset(offset) => Self::Offset(FixedOffset::from_offset(offset)),
             HybridTzOffset::TzOffset(offset) => Self::Timespan(Tz::from_offset(offset)),
+            HybridTzOffset::Posix(posix, _) => Self::Posix(posix.clone()),
+            HybridTzOffset::Custom(custom, _) => Self::Custom(custom.clone()),
         }
     }
 
@@ -75,6 +267,9 @@ impl TimeZone for HybridTz {
             HybridTz::Timespan(timespan) => timespan
                 .offset_from_local_date(local)
                 .map(HybridTzOffset::TzOffset),
+            HybridTz::Posix(_) | HybridTz::Custom(_) => {
+                self.offset_from_local_datetime(&local.and_hms(0, 0, 0))
+            }
         }
     }
 
@@ -89,6 +284,12 @@ impl TimeZone for HybridTz {
             HybridTz::Timespan(timespan) => timespan
                 .offset_from_local_datetime(local)
                 .map(HybridTzOffset::TzOffset),
+            HybridTz::Posix(posix) => posix
+                .is_dst_at_local(local)
+                .map(|is_dst| HybridTzOffset::Posix(posix.clone(), is_dst)),
+            HybridTz::Custom(custom) => custom
+                .offset_at_local(local)
+                .map(|offset| HybridTzOffset::Custom(custom.clone(), offset)),
         }
     }
 
@@ -100,6 +301,9 @@ impl TimeZone for HybridTz {
             HybridTz::Timespan(timespan) => {
                 HybridTzOffset::TzOffset(timespan.offset_from_utc_date(utc))
             }
+            HybridTz::Posix(_) | HybridTz::Custom(_) => {
+                self.offset_from_utc_datetime(&utc.and_hms(0, 0, 0))
+            }
         }
     }
 
@@ -111,6 +315,10 @@ impl TimeZone for HybridTz {
             HybridTz::Timespan(timespan) => {
                 HybridTzOffset::TzOffset(timespan.offset_from_utc_datetime(utc))
             }
+            HybridTz::Posix(posix) => HybridTzOffset::Posix(posix.clone(), posix.is_dst_at(utc)),
+            HybridTz::Custom(custom) => {
+                HybridTzOffset::Custom(custom.clone(), custom.offset_at(utc))
+            }
         }
     }
 }
@@ -120,6 +328,8 @@ impl Display for HybridTz {
         match self {
             HybridTz::Offset(offset) => offset.fmt(f),
             HybridTz::Timespan(timespan) => timespan.fmt(f),
+            HybridTz::Posix(posix) => posix.fmt(f),
+            HybridTz::Custom(custom) => f.write_str(custom.name()),
         }
     }
 }
@@ -128,24 +338,58 @@ impl FromStr for HybridTz {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "utc" | "UTC" => Ok(*UTC),
-            "local" => Ok(*LOCAL),
+            "utc" | "UTC" => Ok(UTC.clone()),
+            "local" => Ok(HybridTz::local()),
             _ => {
-                if let Ok(timespan) = Tz::from_str(s) {
+                if let Some(custom) = custom_tz::lookup(s) {
+                    Ok(Self::Custom(custom))
+                } else if let Ok(timespan) = Tz::from_str(s) {
                     Ok(Self::Timespan(timespan))
+                } else if let Some(posix) = PosixTz::parse(s) {
+                    Ok(Self::Posix(posix))
                 } else {
-                    let tmp_datetime = DateTime::parse_from_str(
-                        &format!("1970-01-01T00:00:00{s}"),
-                        "%Y-%m-%dT%H:%M:%S%z",
-                    )
-                    .map_err(|_| "unknown timezone")?;
-                    Ok(Self::Offset(*tmp_datetime.offset()))
+                    let seconds = parse_offset(s).ok_or("unknown timezone")?;
+                    let offset =
+                        FixedOffset::east_opt(seconds).ok_or("timezone offset out of range")?;
+                    Ok(Self::Offset(offset))
                 }
             }
         }
     }
 }
 
+/// Parses an ISO 8601 style offset (`+08`, `+0800` or `+08:00`) into seconds east of UTC,
+/// the range is checked by the caller.
+fn parse_offset(s: &str) -> Option<i32> {
+    let (sign, digits) = match s.as_bytes().first()? {
+        b'+' => (1, &s[1..]),
+        b'-' => (-1, &s[1..]),
+        _ => return None,
+    };
+    if !digits.is_ascii() {
+        return None;
+    }
+    let (hours, minutes) = match digits.len() {
+        2 => (digits, "00"),
+        4 => digits.split_at(2),
+        5 if digits.as_bytes()[2] == b':' => (&digits[..2], &digits[3..]),
+        _ => return None,
+    };
+    if !hours
+        .bytes()
+        .chain(minutes.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let (hours, minutes) = (hours.parse::<i32>().ok()?, minutes.parse::<i32>().ok()?);
+    if minutes >= 60 {
+        return None;
+    }
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
 impl TryFrom<&str> for HybridTz {
     type Error = String;
 
@@ -158,16 +402,113 @@ impl TryFrom<&str> for HybridTz {
 #[derive(PartialEq, Eq, Hash, Clone)]
 pub(crate) struct PyTz {
     tz: HybridTz,
-    dst_offset: Duration,
 }
 
 impl PyTz {
     pub fn new(tz: HybridTz) -> Self {
-        Self {
-            tz,
-            dst_offset: tz.dst_offset(),
+        Self { tz }
+    }
+
+    /// The instant of `dt`, whose wall time is in this zone, or now without `dt`.
+    fn instant_of(&self, dt: Option<&PyDateTime>) -> DateTime<Utc> {
+        match dt {
+            Some(dt) => self
+                .tz
+                .instant_of_local(&naive_from_datetime(dt), dt.get_fold()),
+            None => Utc::now(),
         }
     }
+
+    pub fn tz(&self) -> HybridTz {
+        self.tz.clone()
+    }
+
+    /// A transition found by `HybridTz::next_transition`, with the instant in this zone.
+    fn py_transition(
+        &self,
+        (instant, before, after): (DateTime<Utc>, i32, i32),
+    ) -> (AtomicClock, i32, i32) {
+        (
+            AtomicClock::from(instant.with_timezone(&self.tz)),
+            before,
+            after,
+        )
+    }
+
+    /// Compares with a foreign `tzinfo` by its offset at the reference instant, a named zone
+    /// is also equal to a `tzinfo` with the same `key`, like `zoneinfo.ZoneInfo`.
+    fn richcmp_tzinfo(&self, tzinfo: &PyTzInfo, op: CompareOp) -> PyResult<bool> {
+        if let (HybridTz::Timespan(tz), CompareOp::Eq | CompareOp::Ne) = (&self.tz, op) {
+            let key = tzinfo.getattr("key").and_then(|key| key.extract::<&str>());
+            if key.is_ok_and(|key| key == tz.name()) {
+                return Ok(matches!(op, CompareOp::Eq));
+            }
+        }
+
+        let now = Utc::now();
+        let reference = now.naive_utc();
+        let reference = PyDateTime::new(
+            tzinfo.py(),
+            reference.year(),
+            reference.month() as u8,
+            reference.day() as u8,
+            reference.hour() as u8,
+            reference.minute() as u8,
+            reference.second() as u8,
+            0,
+            None,
+        )?;
+        let offset = tzinfo.call_method1("utcoffset", (reference,))?;
+        if offset.is_none() {
+            return match op {
+                CompareOp::Eq => Ok(false),
+                CompareOp::Ne => Ok(true),
+                _ => Err(exceptions::PyTypeError::new_err(
+                    "can't order a Tz and a tzinfo without offset",
+                )),
+            };
+        }
+
+        let left = self.tz.offset_at(&now);
+        let right = offset
+            .call_method0("total_seconds")?
+            .extract::<f64>()?
+            .round() as i32;
+        Ok(match op {
+            CompareOp::Eq => left == right,
+            CompareOp::Ne => left != right,
+            CompareOp::Lt => left < right,
+            CompareOp::Le => left <= right,
+            CompareOp::Gt => left > right,
+            CompareOp::Ge => left >= right,
+        })
+    }
+}
+
+/// The `Tz` of UTC, shared by every UTC clock and exported as `atomic_clock.UTC`.
+pub(crate) fn utc_tzinfo(py: Python) -> PyResult<Py<PyTz>> {
+    static UTC_TZINFO: GILOnceCell<Py<PyTz>> = GILOnceCell::new();
+
+    if let Some(utc) = UTC_TZINFO.get(py) {
+        return Ok(utc.clone_ref(py));
+    }
+    let utc = Py::new(py, PyTz::new(UTC.clone()))?;
+    let _ = UTC_TZINFO.set(py, utc.clone_ref(py));
+    Ok(utc)
+}
+
+/// The release of the tz database the named zones are built from, like `2021e`.
+#[pyfunction]
+#[pyo3(text_signature = "()")]
+pub(crate) fn tzdata_version() -> &'static str {
+    chrono_tz::IANA_TZDB_VERSION
+}
+
+/// The right-hand side of a `Tz` comparison, other objects give `NotImplemented`.
+#[derive(FromPyObject)]
+enum TzOperand<'p> {
+    PyTz(PyTz),
+    PyTzInfo(&'p PyTzInfo),
 }
 
 #[pymethods]
@@ -178,31 +519,82 @@ impl PyTz {
         Ok(Self::new(tz))
     }
 
-    fn tzname(&self) -> Option<&'static str> {
-        match self.tz {
+    /// The abbreviation in effect at `dt`, as `%Z` shows it, or the name of the zone
+    /// without `dt`.
+    #[args(dt = "None")]
+    #[pyo3(text_signature = "(dt = None)")]
+    fn tzname(&self, dt: Option<&PyDateTime>) -> Option<String> {
+        if let Some(dt) = dt {
+            let instant = self.instant_of(Some(dt));
+            return Some(
+                self.tz
+                    .offset_from_utc_datetime(&instant.naive_utc())
+                    .to_string(),
+            );
+        }
+        match &self.tz {
             HybridTz::Offset(_) => None,
-            HybridTz::Timespan(tz) => Some(tz.name()),
+            HybridTz::Timespan(tz) => Some(tz.name().to_owned()),
+            HybridTz::Posix(posix) => Some(posix.spec().to_owned()),
+            HybridTz::Custom(custom) => Some(custom.name().to_owned()),
         }
     }
 
     fn dst<'p>(&self, py: Python<'p>, dt: Option<&'p PyDateTime>) -> Option<&'p PyDelta> {
-        dt?;
-        Some(PyDelta::new(py, 0, self.dst_offset.num_seconds() as i32, 0, true).unwrap())
-    }
-
-    fn utcoffset<'p>(&self, py: Python<'p>, _dt: &'p PyDateTime) -> &'p PyDelta {
-        let seconds = match self.tz {
-            HybridTz::Offset(offset) => offset.local_minus_utc(),
-            HybridTz::Timespan(timespan) => UTC_NOW
-                .with_timezone(&timespan)
-                .offset()
-                .fix()
-                .local_minus_utc(),
-        };
+        let seconds = self
+            .tz
+            .dst_offset_at(&self.instant_of(Some(dt?)))
+            .num_seconds();
+        Some(PyDelta::new(py, 0, seconds as i32, 0, true).unwrap())
+    }
 
+    fn utcoffset<'p>(&self, py: Python<'p>, dt: Option<&'p PyDateTime>) -> &'p PyDelta {
+        let seconds = self.tz.offset_at(&self.instant_of(dt));
         PyDelta::new(py, 0, seconds, 0, true).unwrap()
     }
 
+    /// The wall time in this zone of `dt`, whose fields are in UTC, with the `fold` of the
+    /// later of two repeated wall times set, which `tzinfo.fromutc` can't tell apart.
+    fn fromutc<'p>(slf: &'p PyCell<Self>, dt: &'p PyDateTime) -> PyResult<&'p PyDateTime> {
+        if !dt.getattr("tzinfo")?.is(slf) {
+            return Err(exceptions::PyValueError::new_err(
+                "fromutc: dt.tzinfo is not self",
+            ));
+        }
+        let tz = slf.borrow().tz.clone();
+        let local = tz.from_utc_datetime(&naive_from_datetime(dt));
+        let fold = matches!(
+            tz.from_local_datetime(&local.naive_local()),
+            LocalResult::Ambiguous(_, later) if later == local
+        );
+        PyDateTime::new_with_fold(
+            slf.py(),
+            local.year(),
+            local.month() as u8,
+            local.day() as u8,
+            local.hour() as u8,
+            local.minute() as u8,
+            local.second() as u8,
+            local.nanosecond() / 1000,
+            Some(&slf.to_object(slf.py())),
+            fold,
+        )
+    }
+
+    /// The arguments `tzinfo.__reduce__` recreates the zone with, so that a `datetime` in it
+    /// can be pickled.
+    fn __getinitargs__(&self) -> (String,) {
+        (self.__str__(),)
+    }
+
+    fn __setattr__(&mut self, _name: &str, _value: &PyAny) -> PyResult<()> {
+        Err(exceptions::PyAttributeError::new_err("Tz is immutable"))
+    }
+
+    fn __delattr__(&mut self, _name: &str) -> PyResult<()> {
+        Err(exceptions::PyAttributeError::new_err("Tz is immutable"))
+    }
+
     fn __repr__(&self) -> String {
         format!("<Tz [{}]>", self.__str__())
     }
@@ -211,22 +603,123 @@ impl PyTz {
         self.tz.to_string()
     }
 
-    fn __richcmp__(&self, py_tz: PyTz, op: CompareOp) -> PyResult<bool> {
+    #[args(datetime = "None")]
+    #[pyo3(text_signature = "(datetime = None)")]
+    fn key_at(&self, datetime: Option<DateTimeLike>) -> PyResult<i32> {
+        let instant = match datetime {
+            Some(datetime) => datetime.to_utc()?,
+            None => Utc::now(),
+        };
+        Ok(self.tz.offset_at(&instant))
+    }
+
+    #[args(start_year, end_year = "None")]
+    #[pyo3(text_signature = "(start_year, end_year = None)")]
+    fn transitions(
+        &self,
+        start_year: i32,
+        end_year: Option<i32>,
+    ) -> PyResult<Vec<(AtomicClock, i32, i32)>> {
+        let end_year = end_year.unwrap_or(start_year);
+        if !(1..=9999).contains(&start_year) || !(1..=9999).contains(&end_year) {
+            return Err(exceptions::PyValueError::new_err(
+                "invalid year, valid year should be 1..9999",
+            ));
+        }
+        if end_year < start_year {
+            return Err(exceptions::PyValueError::new_err(
+                "end_year is less than start_year",
+            ));
+        }
+
+        let start = Utc.ymd(start_year, 1, 1).and_hms(0, 0, 0);
+        let until = Utc.ymd(end_year, 12, 31).and_hms(23, 59, 59);
+        let mut transitions = vec![];
+        let mut after = start - Duration::seconds(1);
+        while let Some(transition) = self.tz.next_transition(after, until) {
+            after = transition.0;
+            transitions.push(self.py_transition(transition));
+        }
+        Ok(transitions)
+    }
+
+    #[args(after = "None")]
+    #[pyo3(text_signature = "(after = None)")]
+    fn next_transition(
+        &self,
+        after: Option<DateTimeLike>,
+    ) -> PyResult<Option<(AtomicClock, i32, i32)>> {
+        let after = match after {
+            Some(after) => after.to_utc()?,
+            None => Utc::now(),
+        };
+        let until = DateTime::from_utc(*MAX_NAIVE_DATETIME, Utc);
+        Ok(self
+            .tz
+            .next_transition(after, until)
+            .map(|transition| self.py_transition(transition)))
+    }
+
+    #[args(before = "None")]
+    #[pyo3(text_signature = "(before = None)")]
+    fn prev_transition(
+        &self,
+        before: Option<DateTimeLike>,
+    ) -> PyResult<Option<(AtomicClock, i32, i32)>> {
+        let before = match before {
+            Some(before) => before.to_utc()?,
+            None => Utc::now(),
+        };
+        let until = DateTime::from_utc(*MIN_NAIVE_DATETIME, Utc) + Duration::seconds(1);
+        Ok(self
+            .tz
+            .prev_transition(before, until)
+            .map(|transition| self.py_transition(transition)))
+    }
+
+    fn __sub__<'p>(&self, py: Python<'p>, py_tz: PyTz) -> PyResult<&'p PyDelta> {
+        let now = Utc::now();
+        let seconds = self.tz.offset_at(&now) - py_tz.tz.offset_at(&now);
+        PyDelta::new(py, 0, seconds, 0, true)
+    }
+
+    fn __richcmp__(&self, other: TzOperand, op: CompareOp) -> PyResult<bool> {
+        let py_tz = match other {
+            TzOperand::PyTz(py_tz) => py_tz,
+            TzOperand::PyTzInfo(tzinfo) => return self.richcmp_tzinfo(tzinfo, op),
+        };
+        // zones are ordered by their current offset, which may change with DST
+        let offsets = || {
+            let now = Utc::now();
+            (self.tz.offset_at(&now), py_tz.tz.offset_at(&now))
+        };
         match op {
-            CompareOp::Eq => match (self.tz, py_tz.tz) {
-                (HybridTz::Offset(l), HybridTz::Offset(r)) => Ok(l == r),
-                (HybridTz::Offset(l), HybridTz::Timespan(r)) => {
-                    Ok(l == UTC_NOW.with_timezone(&r).offset().fix())
-                }
-                (HybridTz::Timespan(l), HybridTz::Offset(r)) => {
-                    Ok(UTC_NOW.with_timezone(&l).offset().fix() == r)
-                }
+            CompareOp::Eq => match (&self.tz, &py_tz.tz) {
                 (HybridTz::Timespan(l), HybridTz::Timespan(r)) => Ok(l == r),
+                (HybridTz::Posix(l), HybridTz::Posix(r)) => Ok(l == r),
+                (HybridTz::Custom(l), HybridTz::Custom(r)) => Ok(l == r),
+                (l, r) => {
+                    let now = Utc::now();
+                    Ok(l.offset_at(&now) == r.offset_at(&now))
+                }
             },
-            CompareOp::Ne => Ok(!(self.__richcmp__(py_tz, CompareOp::Eq)?)),
-            _ => Err(exceptions::PyTypeError::new_err(
-                "only support op '==' and '!='",
-            )),
+            CompareOp::Ne => Ok(!(self.__richcmp__(TzOperand::PyTz(py_tz), CompareOp::Eq)?)),
+            CompareOp::Lt => {
+                let (left, right) = offsets();
+                Ok(left < right)
+            }
+            CompareOp::Le => {
+                let (left, right) = offsets();
+                Ok(left <= right)
+            }
+            CompareOp::Gt => {
+                let (left, right) = offsets();
+                Ok(left > right)
+            }
+            CompareOp::Ge => {
+                let (left, right) = offsets();
+                Ok(left >= right)
+            }
         }
     }
 }
@@ -239,15 +732,18 @@ pub(crate) enum PyTzLike<'p> {
 }
 
 impl<'p> PyTzLike<'p> {
+    /// Whether this is the `"local"` timezone, whose offset depends on the instant.
+    pub fn is_local(&self) -> bool {
+        matches!(self, PyTzLike::String("local"))
+    }
+
     pub fn try_to_tz(self) -> PyResult<HybridTz> {
         match self {
-            PyTzLike::String(tz) => tz.try_into().map_err(exceptions::PyValueError::new_err),
+            PyTzLike::String(tz) => tz.try_into().map_err(TimezoneError::new_err),
             PyTzLike::PyTz(tz) => Ok(tz.tz),
             PyTzLike::PyTzInfo(tz) => {
                 if let Ok(tz_name) = tz.call_method0("tzname").map(|v| v.extract::<&str>()) {
-                    Ok(tz_name?
-                        .try_into()
-                        .map_err(exceptions::PyValueError::new_err)?)
+                    Ok(tz_name?.try_into().map_err(TimezoneError::new_err)?)
                 } else {
                     let dummy_datetime = PyDateTime::new(tz.py(), 1970, 1, 1, 0, 0, 0, 0, None)?;
                     let offset = tz
@@ -262,10 +758,11 @@ impl<'p> PyTzLike<'p> {
     }
 
     pub fn utc() -> Self {
-        PyTzLike::PyTz(PyTz::new(*UTC))
+        PyTzLike::PyTz(PyTz::new(UTC.clone()))
     }
 
+    /// `"local"`, so that `is_local` lets constructors resolve its offset at their instant.
     pub fn local() -> Self {
-        PyTzLike::PyTz(PyTz::new(*LOCAL))
+        PyTzLike::String("local")
     }
 }
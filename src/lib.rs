@@ -1,23 +1,58 @@
+// the `#[pymethods]` of pyo3 0.16 expand to impls nested in a const item, which newer
+// compilers lint as non-local
+#[allow(non_local_definitions)]
 mod atomic_clock;
+mod custom_tz;
+mod errors;
+mod formatter;
+#[allow(non_local_definitions)]
 mod hybrid_tz;
+mod locales;
+mod posix_tz;
+#[allow(non_local_definitions)]
+mod stopwatch;
 
 #[macro_use]
 extern crate lazy_static;
 
-use hybrid_tz::PyTz;
+use custom_tz::{custom_timezones, register_timezone, unregister_timezone};
+use hybrid_tz::{tzdata_version, utc_tzinfo, PyTz};
 use pyo3::prelude::*;
+use stopwatch::{monotonic, Stopwatch, MONOTONIC_EPOCH};
 
-use atomic_clock::{get, now, utcnow, AtomicClock, PyRelativeDelta};
+use atomic_clock::{
+    get, get_safe, now, parse_interval, try_get, utcnow, AtomicClock, Period, PyRelativeDelta,
+};
 
 /// A Python module implemented in Rust.
 #[pymodule]
-fn atomic_clock(_py: Python, m: &PyModule) -> PyResult<()> {
+fn atomic_clock(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AtomicClock>()?;
+    m.add_class::<Period>()?;
     m.add_class::<PyRelativeDelta>()?;
     m.add_class::<PyTz>()?;
+    m.add_class::<Stopwatch>()?;
+    m.add_function(wrap_pyfunction!(custom_timezones, m)?)?;
     m.add_function(wrap_pyfunction!(get, m)?)?;
+    m.add_function(wrap_pyfunction!(try_get, m)?)?;
+    m.add_function(wrap_pyfunction!(get_safe, m)?)?;
+    m.add_function(wrap_pyfunction!(locales::locales, m)?)?;
+    m.add_function(wrap_pyfunction!(monotonic, m)?)?;
     m.add_function(wrap_pyfunction!(now, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_interval, m)?)?;
+    m.add_function(wrap_pyfunction!(register_timezone, m)?)?;
+    m.add_function(wrap_pyfunction!(tzdata_version, m)?)?;
+    m.add_function(wrap_pyfunction!(unregister_timezone, m)?)?;
     m.add_function(wrap_pyfunction!(utcnow, m)?)?;
+    m.add(
+        "AtomicClockError",
+        py.get_type::<errors::AtomicClockError>(),
+    )?;
+    m.add("ParserError", py.get_type::<errors::ParserError>())?;
+    m.add("TimezoneError", py.get_type::<errors::TimezoneError>())?;
+    m.add("RangeError", py.get_type::<errors::RangeError>())?;
+    lazy_static::initialize(&MONOTONIC_EPOCH);
+    m.add("UTC", utc_tzinfo(py)?)?;
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
     Ok(())
 }
@@ -0,0 +1,252 @@
+use pyo3::{exceptions, prelude::*};
+
+pub(crate) struct Locale {
+    pub name: &'static str,
+    pub month_names: [&'static str; 12],
+    pub month_abbreviations: [&'static str; 12],
+    /// Day names from Monday to Sunday.
+    pub day_names: [&'static str; 7],
+    pub day_abbreviations: [&'static str; 7],
+    /// The `am` and `pm` meridians, in lowercase and in uppercase.
+    pub meridians: [&'static str; 2],
+    pub meridians_upper: [&'static str; 2],
+    pub ordinal: fn(u32) -> String,
+    pub now: &'static str,
+    pub past: &'static str,
+    pub future: &'static str,
+    pub timeframes: Timeframes,
+}
+
+/// Singular and plural phrases of each timeframe, `{0}` is replaced by the count.
+pub(crate) struct Timeframes {
+    pub second: [&'static str; 2],
+    pub minute: [&'static str; 2],
+    pub hour: [&'static str; 2],
+    pub day: [&'static str; 2],
+    pub week: [&'static str; 2],
+    pub month: [&'static str; 2],
+    pub year: [&'static str; 2],
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Timeframe {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl Locale {
+    pub fn month_name(&self, month: u32) -> &'static str {
+        self.month_names[month as usize - 1]
+    }
+
+    pub fn month_abbreviation(&self, month: u32) -> &'static str {
+        self.month_abbreviations[month as usize - 1]
+    }
+
+    pub fn meridian(&self, is_pm: bool, upper: bool) -> &'static str {
+        let meridians = if upper {
+            &self.meridians_upper
+        } else {
+            &self.meridians
+        };
+        meridians[is_pm as usize]
+    }
+
+    /// Describes `count` timeframes in the past or in the future, e.g. "2 hours ago".
+    pub fn relative(&self, timeframe: Timeframe, count: u64, future: bool) -> String {
+        let [singular, plural] = match timeframe {
+            Timeframe::Second => self.timeframes.second,
+            Timeframe::Minute => self.timeframes.minute,
+            Timeframe::Hour => self.timeframes.hour,
+            Timeframe::Day => self.timeframes.day,
+            Timeframe::Week => self.timeframes.week,
+            Timeframe::Month => self.timeframes.month,
+            Timeframe::Year => self.timeframes.year,
+        };
+        let phrase = if count == 1 { singular } else { plural }.replace("{0}", &count.to_string());
+        let template = if future { self.future } else { self.past };
+        template.replace("{0}", &phrase)
+    }
+}
+
+static EN: Locale = Locale {
+    name: "en",
+    month_names: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    month_abbreviations: [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    day_names: [
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "Sunday",
+    ],
+    day_abbreviations: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+    meridians: ["am", "pm"],
+    meridians_upper: ["AM", "PM"],
+    ordinal: english_ordinal,
+    now: "just now",
+    past: "{0} ago",
+    future: "in {0}",
+    timeframes: Timeframes {
+        second: ["a second", "{0} seconds"],
+        minute: ["a minute", "{0} minutes"],
+        hour: ["an hour", "{0} hours"],
+        day: ["a day", "{0} days"],
+        week: ["a week", "{0} weeks"],
+        month: ["a month", "{0} months"],
+        year: ["a year", "{0} years"],
+    },
+};
+
+static FR: Locale = Locale {
+    name: "fr",
+    month_names: [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    month_abbreviations: [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+        "déc.",
+    ],
+    day_names: [
+        "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+    ],
+    day_abbreviations: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+    meridians: ["am", "pm"],
+    meridians_upper: ["AM", "PM"],
+    ordinal: |n| {
+        if n == 1 {
+            "1er".to_string()
+        } else {
+            format!("{n}e")
+        }
+    },
+    now: "maintenant",
+    past: "il y a {0}",
+    future: "dans {0}",
+    timeframes: Timeframes {
+        second: ["une seconde", "{0} secondes"],
+        minute: ["une minute", "{0} minutes"],
+        hour: ["une heure", "{0} heures"],
+        day: ["un jour", "{0} jours"],
+        week: ["une semaine", "{0} semaines"],
+        month: ["un mois", "{0} mois"],
+        year: ["un an", "{0} ans"],
+    },
+};
+
+static ES: Locale = Locale {
+    name: "es",
+    month_names: [
+        "enero",
+        "febrero",
+        "marzo",
+        "abril",
+        "mayo",
+        "junio",
+        "julio",
+        "agosto",
+        "septiembre",
+        "octubre",
+        "noviembre",
+        "diciembre",
+    ],
+    month_abbreviations: [
+        "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sep", "oct", "nov", "dic",
+    ],
+    day_names: [
+        "lunes",
+        "martes",
+        "miércoles",
+        "jueves",
+        "viernes",
+        "sábado",
+        "domingo",
+    ],
+    day_abbreviations: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+    meridians: ["am", "pm"],
+    meridians_upper: ["AM", "PM"],
+    ordinal: |n| format!("{n}º"),
+    now: "ahora",
+    past: "hace {0}",
+    future: "en {0}",
+    timeframes: Timeframes {
+        second: ["un segundo", "{0} segundos"],
+        minute: ["un minuto", "{0} minutos"],
+        hour: ["una hora", "{0} horas"],
+        day: ["un día", "{0} días"],
+        week: ["una semana", "{0} semanas"],
+        month: ["un mes", "{0} meses"],
+        year: ["un año", "{0} años"],
+    },
+};
+
+static LOCALES: [&Locale; 3] = [&EN, &ES, &FR];
+
+fn english_ordinal(n: u32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{n}{suffix}")
+}
+
+/// Looks up a locale by name, case-insensitively. Regional names like `en_US` or `fr-CA`
+/// fall back to their language.
+pub(crate) fn get_locale(name: &str) -> PyResult<&'static Locale> {
+    let normalized = name.to_lowercase().replace('_', "-");
+    let language = normalized.split('-').next().unwrap_or_default();
+
+    LOCALES
+        .iter()
+        .find(|locale| locale.name == normalized)
+        .or_else(|| LOCALES.iter().find(|locale| locale.name == language))
+        .copied()
+        .ok_or_else(|| {
+            exceptions::PyValueError::new_err(format!(
+                "unknown locale `{}`, available locales: {}",
+                name,
+                locales().join(", ")
+            ))
+        })
+}
+
+#[pyfunction]
+pub(crate) fn locales() -> Vec<&'static str> {
+    LOCALES.iter().map(|locale| locale.name).collect()
+}
@@ -0,0 +1,310 @@
+use std::{fmt::Display, sync::Arc};
+
+use chrono::{Datelike, Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime};
+
+/// A timezone described by a POSIX TZ string like `EST5EDT,M3.2.0,M11.1.0`, as found in
+/// the `TZ` environment variable of systems without tzdata.
+///
+/// The offsets are stored in seconds east of UTC, POSIX strings use the opposite sign.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub(crate) struct PosixTz {
+    spec: Arc<str>,
+    std_name: Arc<str>,
+    std_offset: i32,
+    dst: Option<PosixDst>,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+struct PosixDst {
+    name: Arc<str>,
+    offset: i32,
+    start: Transition,
+    end: Transition,
+}
+
+/// A DST transition, `time` is the local wall time in seconds after midnight of the day
+/// given by `rule`, it may be negative or exceed a day.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+struct Transition {
+    rule: Rule,
+    time: i32,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+enum Rule {
+    /// `Jn`, the day of the year from 1 to 365, February 29 is never counted.
+    Julian(u32),
+    /// `n`, the zero-based day of the year from 0 to 365, February 29 is counted.
+    ZeroBasedJulian(u32),
+    /// `Mm.w.d`, the day `d` (0 is Sunday) of the week `w` of month `m`, week 5 is the last.
+    MonthWeekDay(u32, u32, u32),
+}
+
+const DEFAULT_TRANSITION_TIME: i32 = 2 * 3600;
+
+impl PosixTz {
+    pub fn parse(spec: &str) -> Option<Self> {
+        let mut rest = spec;
+        let std_name = parse_name(&mut rest)?;
+        // POSIX offsets are positive west of UTC
+        let std_offset = -parse_time(&mut rest, 24)?;
+        if std_offset.abs() >= 86400 {
+            return None;
+        }
+
+        let dst = if rest.is_empty() {
+            None
+        } else {
+            let name = parse_name(&mut rest)?;
+            let offset = if rest.is_empty() || rest.starts_with(',') {
+                std_offset + 3600
+            } else {
+                -parse_time(&mut rest, 24)?
+            };
+            if offset.abs() >= 86400 {
+                return None;
+            }
+
+            let (start, end) = if rest.is_empty() {
+                // the US rules, as glibc assumes when they are omitted
+                (
+                    Transition {
+                        rule: Rule::MonthWeekDay(3, 2, 0),
+                        time: DEFAULT_TRANSITION_TIME,
+                    },
+                    Transition {
+                        rule: Rule::MonthWeekDay(11, 1, 0),
+                        time: DEFAULT_TRANSITION_TIME,
+                    },
+                )
+            } else {
+                rest = rest.strip_prefix(',')?;
+                let start = parse_transition(&mut rest)?;
+                rest = rest.strip_prefix(',')?;
+                let end = parse_transition(&mut rest)?;
+                (start, end)
+            };
+            Some((name, offset, start, end))
+        };
+
+        if !rest.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            spec: spec.into(),
+            std_name: std_name.into(),
+            std_offset,
+            dst: dst.map(|(name, offset, start, end)| PosixDst {
+                name: name.into(),
+                offset,
+                start,
+                end,
+            }),
+        })
+    }
+
+    /// The TZ string this timezone was parsed from.
+    pub fn spec(&self) -> &str {
+        &self.spec
+    }
+
+    pub fn offset(&self, is_dst: bool) -> FixedOffset {
+        let seconds = match &self.dst {
+            Some(dst) if is_dst => dst.offset,
+            _ => self.std_offset,
+        };
+        FixedOffset::east(seconds)
+    }
+
+    pub fn abbreviation(&self, is_dst: bool) -> &str {
+        match &self.dst {
+            Some(dst) if is_dst => &dst.name,
+            _ => &self.std_name,
+        }
+    }
+
+    /// Whether DST is in effect at the instant `utc`.
+    pub fn is_dst_at(&self, utc: &NaiveDateTime) -> bool {
+        let dst = match &self.dst {
+            Some(dst) => dst,
+            None => return false,
+        };
+
+        let year = (*utc + Duration::seconds(self.std_offset as i64)).year();
+        // the start is given in standard time and the end in DST
+        let start = dst.start.to_utc(year, self.std_offset);
+        let end = dst.end.to_utc(year, dst.offset);
+        if start < end {
+            start <= *utc && *utc < end
+        } else {
+            // southern hemisphere, DST spans the new year
+            !(end <= *utc && *utc < start)
+        }
+    }
+
+    /// Resolves a local wall time to whether it's in DST, there are two candidates when the
+    /// wall time is repeated and none when it's skipped.
+    pub fn is_dst_at_local(&self, local: &NaiveDateTime) -> LocalResult<bool> {
+        let dst = match &self.dst {
+            Some(dst) => dst,
+            None => return LocalResult::Single(false),
+        };
+
+        let mut candidates = [(self.std_offset, false), (dst.offset, true)];
+        // the larger offset gives the earlier instant
+        candidates.sort_by_key(|(offset, _)| -offset);
+        let valid: Vec<bool> = candidates
+            .into_iter()
+            .filter(|&(offset, is_dst)| {
+                self.is_dst_at(&(*local - Duration::seconds(offset as i64))) == is_dst
+            })
+            .map(|(_, is_dst)| is_dst)
+            .collect();
+
+        match valid[..] {
+            [] => LocalResult::None,
+            [is_dst] => LocalResult::Single(is_dst),
+            [earlier, later, ..] => LocalResult::Ambiguous(earlier, later),
+        }
+    }
+}
+
+impl Display for PosixTz {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.spec())
+    }
+}
+
+impl Transition {
+    fn to_utc(self, year: i32, offset: i32) -> NaiveDateTime {
+        self.rule.date(year).and_hms(0, 0, 0) + Duration::seconds((self.time - offset) as i64)
+    }
+}
+
+impl Rule {
+    fn date(self, year: i32) -> NaiveDate {
+        match self {
+            Rule::Julian(day) => {
+                let leap_day = NaiveDate::from_ymd_opt(year, 2, 29).is_some() && day >= 60;
+                NaiveDate::from_yo(year, day + leap_day as u32)
+            }
+            Rule::ZeroBasedJulian(day) => {
+                NaiveDate::from_ymd(year, 1, 1) + Duration::days(day as i64)
+            }
+            Rule::MonthWeekDay(month, week, weekday) => {
+                let first = NaiveDate::from_ymd(year, month, 1);
+                let first_weekday = first.weekday().num_days_from_sunday();
+                let mut day = 1 + (weekday + 7 - first_weekday) % 7 + (week - 1) * 7;
+                // week 5 is the last week, which may be the 4th
+                while NaiveDate::from_ymd_opt(year, month, day).is_none() {
+                    day -= 7;
+                }
+                NaiveDate::from_ymd(year, month, day)
+            }
+        }
+    }
+}
+
+/// Parses a zone abbreviation, either 3 or more letters, or any characters quoted in `<>`
+/// like `<+08>`.
+fn parse_name<'a>(rest: &mut &'a str) -> Option<&'a str> {
+    let (name, remaining) = if let Some(quoted) = rest.strip_prefix('<') {
+        let end = quoted.find('>')?;
+        (&quoted[..end], &quoted[end + 1..])
+    } else {
+        let end = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        rest.split_at(end)
+    };
+
+    if name.len() < 3
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-')
+    {
+        return None;
+    }
+    *rest = remaining;
+    Some(name)
+}
+
+/// Parses `[+|-]hh[:mm[:ss]]` into seconds.
+fn parse_time(rest: &mut &str, max_hours: i32) -> Option<i32> {
+    let sign = match rest.chars().next()? {
+        '-' => -1,
+        '+' => 1,
+        _ => 0,
+    };
+    if sign != 0 {
+        *rest = &rest[1..];
+    }
+
+    let mut seconds = 0;
+    for (idx, (multiplier, max)) in [(3600, max_hours), (60, 59), (1, 59)].iter().enumerate() {
+        if idx > 0 {
+            match rest.strip_prefix(':') {
+                Some(remaining) => *rest = remaining,
+                None => break,
+            }
+        }
+        let value = parse_number(rest)?;
+        if value > *max {
+            return None;
+        }
+        seconds += value * multiplier;
+    }
+    Some(if sign < 0 { -seconds } else { seconds })
+}
+
+fn parse_number(rest: &mut &str) -> Option<i32> {
+    let end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    if end == 0 || end > 3 {
+        return None;
+    }
+    let value = rest[..end].parse().ok()?;
+    *rest = &rest[end..];
+    Some(value)
+}
+
+/// Parses `rule[/time]`.
+fn parse_transition(rest: &mut &str) -> Option<Transition> {
+    let rule = if let Some(remaining) = rest.strip_prefix('J') {
+        *rest = remaining;
+        let day = parse_number(rest)? as u32;
+        if !(1..=365).contains(&day) {
+            return None;
+        }
+        Rule::Julian(day)
+    } else if let Some(remaining) = rest.strip_prefix('M') {
+        *rest = remaining;
+        let month = parse_number(rest)? as u32;
+        *rest = rest.strip_prefix('.')?;
+        let week = parse_number(rest)? as u32;
+        *rest = rest.strip_prefix('.')?;
+        let weekday = parse_number(rest)? as u32;
+        if !(1..=12).contains(&month) || !(1..=5).contains(&week) || weekday > 6 {
+            return None;
+        }
+        Rule::MonthWeekDay(month, week, weekday)
+    } else {
+        let day = parse_number(rest)? as u32;
+        if day > 365 {
+            return None;
+        }
+        Rule::ZeroBasedJulian(day)
+    };
+
+    let time = match rest.strip_prefix('/') {
+        Some(remaining) => {
+            *rest = remaining;
+            // RFC 8536 extends the transition time to ±167 hours
+            parse_time(rest, 167)?
+        }
+        None => DEFAULT_TRANSITION_TIME,
+    };
+    Some(Transition { rule, time })
+}
@@ -0,0 +1,132 @@
+use std::time::Instant;
+
+use chrono::Duration;
+use pyo3::{exceptions, prelude::*, types::PyDelta};
+
+use crate::atomic_clock::normalize_duration;
+
+lazy_static! {
+    /// The origin of [`monotonic`], taken when the module is imported.
+    pub(crate) static ref MONOTONIC_EPOCH: Instant = Instant::now();
+}
+
+/// Nanoseconds since [`MONOTONIC_EPOCH`], unaffected by changes to the system clock.
+fn monotonic_nanos() -> i128 {
+    MONOTONIC_EPOCH.elapsed().as_nanos() as i128
+}
+
+fn nanos_to_delta<'p>(py: Python<'p>, nanos: i128) -> PyResult<&'p PyDelta> {
+    let nanos = i64::try_from(nanos)
+        .map_err(|_| exceptions::PyOverflowError::new_err("elapsed time is out of range"))?;
+    let (days, seconds, microseconds) = normalize_duration(Duration::nanoseconds(nanos));
+    PyDelta::new(py, days as i32, seconds as i32, microseconds as i32, true)
+}
+
+/// Returns the seconds elapsed on a monotonic clock since the module was imported.
+#[pyfunction]
+#[pyo3(text_signature = "()")]
+pub fn monotonic() -> f64 {
+    monotonic_nanos() as f64 / 1e9
+}
+
+#[derive(FromPyObject)]
+enum InstantLike {
+    Stopwatch(Stopwatch),
+    Seconds(f64),
+}
+
+#[pyclass(module = "atomic_clock")]
+#[pyo3(text_signature = "()")]
+#[derive(Clone, Default)]
+pub struct Stopwatch {
+    /// Both in nanoseconds since [`MONOTONIC_EPOCH`].
+    started: Option<i128>,
+    stopped: Option<i128>,
+}
+
+impl Stopwatch {
+    fn started(&self) -> PyResult<i128> {
+        self.started
+            .ok_or_else(|| exceptions::PyValueError::new_err("stopwatch isn't started"))
+    }
+
+    fn elapsed_nanos(&self) -> i128 {
+        match (self.started, self.stopped) {
+            (Some(started), Some(stopped)) => stopped - started,
+            (Some(started), None) => monotonic_nanos() - started,
+            (None, _) => 0,
+        }
+    }
+}
+
+#[pymethods]
+impl Stopwatch {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn __repr__(&self) -> String {
+        let state = match (self.started, self.stopped) {
+            (None, _) => "not started",
+            (Some(_), None) => "running",
+            (Some(_), Some(_)) => "stopped",
+        };
+        format!("<Stopwatch [{}]>", state)
+    }
+
+    #[getter]
+    fn running(&self) -> bool {
+        self.started.is_some() && self.stopped.is_none()
+    }
+
+    /// Starts counting from zero, a running or stopped stopwatch is restarted.
+    #[pyo3(text_signature = "()")]
+    fn start(mut slf: PyRefMut<Self>) -> PyRefMut<Self> {
+        slf.started = Some(monotonic_nanos());
+        slf.stopped = None;
+        slf
+    }
+
+    /// Freezes the elapsed time and returns it, stopping twice keeps the first stop.
+    #[pyo3(text_signature = "()")]
+    fn stop<'p>(&mut self, py: Python<'p>) -> PyResult<&'p PyDelta> {
+        self.started()?;
+        if self.stopped.is_none() {
+            self.stopped = Some(monotonic_nanos());
+        }
+        nanos_to_delta(py, self.elapsed_nanos())
+    }
+
+    #[pyo3(text_signature = "()")]
+    fn elapsed<'p>(&self, py: Python<'p>) -> PyResult<&'p PyDelta> {
+        nanos_to_delta(py, self.elapsed_nanos())
+    }
+
+    #[pyo3(text_signature = "()")]
+    fn elapsed_ns(&self) -> i128 {
+        self.elapsed_nanos()
+    }
+
+    /// The time from the start of `other`, or a `monotonic()` reading, to the start of
+    /// this stopwatch.
+    #[pyo3(text_signature = "(other)")]
+    fn elapsed_since<'p>(&self, py: Python<'p>, other: InstantLike) -> PyResult<&'p PyDelta> {
+        let other = match other {
+            InstantLike::Stopwatch(other) => other.started()?,
+            InstantLike::Seconds(seconds) if seconds.is_finite() => (seconds * 1e9).round() as i128,
+            InstantLike::Seconds(seconds) => {
+                return Err(exceptions::PyValueError::new_err(format!(
+                    "invalid monotonic reading {}, it has to be finite",
+                    seconds
+                )))
+            }
+        };
+        // a reading past the i128 range saturates and must not wrap around
+        let nanos = self
+            .started()?
+            .checked_sub(other)
+            .ok_or_else(|| exceptions::PyOverflowError::new_err("elapsed time is out of range"))?;
+        nanos_to_delta(py, nanos)
+    }
+}
@@ -1,3 +1,4 @@
+import os
 import time
 
 from datetime import datetime
@@ -42,3 +43,24 @@ def time_1975_12_25(request):
         1975, 12, 25, 14, 15, 16, tzinfo=tz.gettz("America/New_York")
     )
     request.cls.atomic_clock = atomic_clock.fromdatetime(request.cls.datetime)
+
+
+@pytest.fixture
+def local_tz():
+    """Switches the process local timezone, restoring it afterwards."""
+    if not hasattr(time, "tzset"):
+        pytest.skip("time.tzset is not available on this platform")
+
+    original = os.environ.get("TZ")
+
+    def set_local_tz(name):
+        os.environ["TZ"] = name
+        time.tzset()
+
+    yield set_local_tz
+
+    if original is None:
+        os.environ.pop("TZ", None)
+    else:
+        os.environ["TZ"] = original
+    time.tzset()
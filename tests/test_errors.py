@@ -0,0 +1,104 @@
+from datetime import datetime
+
+import pytest
+
+import atomic_clock
+
+from atomic_clock import AtomicClock
+from atomic_clock import AtomicClockError
+from atomic_clock import ParserError
+from atomic_clock import RangeError
+from atomic_clock import TimezoneError
+from atomic_clock import Tz
+
+
+@pytest.mark.parametrize(
+    "error,builtin",
+    (
+        (ParserError, ValueError),
+        (TimezoneError, ValueError),
+        (RangeError, OverflowError),
+        (RangeError, ValueError),
+    ),
+)
+def test_hierarchy(error, builtin):
+    assert issubclass(error, AtomicClockError)
+    assert issubclass(error, builtin)
+    assert issubclass(AtomicClockError, Exception)
+    assert not issubclass(AtomicClockError, ValueError)
+    assert error.__module__ == "atomic_clock"
+
+
+@pytest.mark.parametrize(
+    "call",
+    (
+        lambda: atomic_clock.get("not a date"),
+        lambda: atomic_clock.get((2022, 1)),
+        lambda: atomic_clock.get("2022-01-05", "%H"),
+        lambda: AtomicClock.strptime("2022-13-01", "%Y-%m-%d"),
+        lambda: AtomicClock.fromrfc2822("not a date"),
+        lambda: AtomicClock.fromhttpdate("not a date"),
+    ),
+)
+def test_parser_error(call):
+    with pytest.raises(ParserError):
+        call()
+
+
+@pytest.mark.parametrize(
+    "call",
+    (
+        lambda: Tz("Bogus/Zone"),
+        lambda: Tz("+25:00"),
+        lambda: AtomicClock(2022, 1, 1, tzinfo="Bogus/Zone"),
+        lambda: atomic_clock.get("2022-01-01T00:00:00", tzinfo="Bogus/Zone"),
+        lambda: atomic_clock.now("Bogus/Zone"),
+        lambda: AtomicClock(2022, 1, 1).to("Bogus/Zone"),
+        lambda: AtomicClock(2022, 1, 1).replace(tzinfo=24 * 60),
+    ),
+)
+def test_timezone_error(call):
+    with pytest.raises(TimezoneError, match="unknown timezone|out of range|offset"):
+        call()
+
+
+@pytest.mark.parametrize(
+    "call",
+    (
+        lambda: AtomicClock.fromordinal(0),
+        lambda: AtomicClock.fromordinal(4_000_000),
+        lambda: AtomicClock.fromtimestamp(1e20, "UTC"),
+        lambda: AtomicClock.utcfromtimestamp(-1e20),
+        lambda: AtomicClock.utcfromtimestamp(float("nan")),
+    ),
+)
+def test_range_error(call):
+    with pytest.raises(RangeError, match="out of range"):
+        call()
+
+
+def test_builtin_errors_still_caught():
+    with pytest.raises(ValueError):
+        atomic_clock.get("not a date")
+
+    with pytest.raises(ValueError):
+        Tz("Bogus/Zone")
+
+    with pytest.raises(ValueError):
+        AtomicClock.fromordinal(0)
+
+    with pytest.raises(OverflowError):
+        AtomicClock.fromtimestamp(1e20, "UTC")
+
+
+def test_invalid_components_are_value_errors():
+    with pytest.raises(ValueError) as excinfo:
+        AtomicClock(2022, 13, 1)
+
+    assert not isinstance(excinfo.value, AtomicClockError)
+
+
+def test_negative_fractional_timestamp():
+    result = AtomicClock.utcfromtimestamp(-1.5)
+
+    assert result == datetime(1969, 12, 31, 23, 59, 58, 500000, tzinfo=Tz("UTC"))
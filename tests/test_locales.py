@@ -0,0 +1,71 @@
+import pytest
+
+import atomic_clock
+from atomic_clock import AtomicClock
+
+CLOCK = AtomicClock(2022, 3, 1, 15, 4, 5)
+
+
+def test_locales():
+    assert atomic_clock.locales() == ["en", "es", "fr"]
+
+
+@pytest.mark.parametrize(
+    "locale,expected",
+    (
+        ("en", "Tuesday, March 1st 2022 | Tue, Mar | 3 PM pm"),
+        ("es", "martes, marzo 1º 2022 | mar, mar | 3 PM pm"),
+        ("fr", "mardi, mars 1er 2022 | mar., mars | 3 PM pm"),
+    ),
+)
+def test_format_tokens(locale, expected):
+    result = CLOCK.format(
+        "dddd, MMMM Do YYYY | ddd, MMM | h A a", locale=locale, tokens=True
+    )
+
+    assert result == expected
+
+
+@pytest.mark.parametrize(
+    "locale,expected",
+    (
+        ("en", "Tuesday 01 March 2022, Tue Mar 03:04 PM pm"),
+        ("es", "martes 01 marzo 2022, mar mar 03:04 PM pm"),
+        ("fr", "mardi 01 mars 2022, mar. mars 03:04 PM pm"),
+    ),
+)
+def test_format_strftime(locale, expected):
+    result = CLOCK.format("%A %d %B %Y, %a %b %I:%M %p %P", locale=locale)
+
+    assert result == expected
+
+
+@pytest.mark.parametrize(
+    "locale,past,future",
+    (
+        ("en", "2 hours ago", "in a day"),
+        ("es", "hace 2 horas", "en un día"),
+        ("fr", "il y a 2 heures", "dans un jour"),
+    ),
+)
+def test_humanize(locale, past, future):
+    assert CLOCK.humanize(CLOCK.shift(hours=2), locale=locale) == past
+    assert CLOCK.humanize(CLOCK.shift(days=-1), locale=locale) == future
+
+
+@pytest.mark.parametrize("locale", ("EN", "en_US", "en-gb", "fr_CA"))
+def test_regional_names(locale):
+    language = locale[:2].lower()
+
+    result = CLOCK.format("MMMM", locale=locale, tokens=True)
+
+    assert result == CLOCK.format("MMMM", locale=language, tokens=True)
+
+
+@pytest.mark.parametrize("locale", ("xx", "", "english"))
+def test_unknown_locale(locale):
+    with pytest.raises(ValueError, match="available locales: en, es, fr"):
+        CLOCK.format("MMMM", locale=locale)
+
+    with pytest.raises(ValueError, match="available locales: en, es, fr"):
+        CLOCK.humanize(locale=locale)
@@ -85,3 +85,49 @@ from atomic_clock import RelativeDelta
 )
 def test_relative_delta(dt, delta, expected):
     assert dt + delta == expected
+
+
+def test_eq():
+    delta = RelativeDelta(years=1, days=-30, weekday=2)
+
+    assert delta == RelativeDelta(years=1, days=-30, weekday=2)
+    assert delta == delta.clone()
+    assert delta == -(-delta)
+    assert not delta != RelativeDelta(years=1, days=-30, weekday=2)
+
+
+@pytest.mark.parametrize(
+    "other",
+    (
+        RelativeDelta(years=1, days=-30),
+        RelativeDelta(years=1, days=-30, weekday=3),
+        RelativeDelta(years=1, days=-29, weekday=2),
+        RelativeDelta(weeks=1),
+        None,
+        1,
+    ),
+)
+def test_ne(other):
+    delta = RelativeDelta(years=1, days=-30, weekday=2)
+
+    assert delta != other
+    assert not delta == other
+
+
+def test_fields_not_normalized():
+    assert RelativeDelta(weeks=1) != RelativeDelta(days=7)
+    assert RelativeDelta(quarters=1) != RelativeDelta(months=3)
+
+
+def test_hash():
+    first = RelativeDelta(months=1, weekday=0)
+    second = RelativeDelta(months=1, weekday=0)
+
+    assert hash(first) == hash(second)
+    assert len({first, second, RelativeDelta(months=1)}) == 2
+    assert {first: "monthly"}[second] == "monthly"
+
+
+def test_ordering_unsupported():
+    with pytest.raises(TypeError):
+        RelativeDelta(days=1) < RelativeDelta(days=2)
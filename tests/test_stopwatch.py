@@ -0,0 +1,103 @@
+import time
+
+from datetime import timedelta
+
+import atomic_clock
+import pytest
+
+from atomic_clock import Stopwatch
+
+
+def test_monotonic():
+    readings = [atomic_clock.monotonic() for _ in range(1000)]
+
+    assert readings == sorted(readings)
+    assert readings[0] >= 0
+
+
+def test_monotonic_follows_sleep():
+    start = atomic_clock.monotonic()
+    time.sleep(0.05)
+
+    assert 0.05 <= atomic_clock.monotonic() - start < 1
+
+
+def test_not_started():
+    sw = Stopwatch()
+
+    assert not sw.running
+    assert sw.elapsed() == timedelta(0)
+    assert sw.elapsed_ns() == 0
+    assert repr(sw) == "<Stopwatch [not started]>"
+    with pytest.raises(ValueError, match="stopwatch isn't started"):
+        sw.stop()
+
+
+def test_elapsed():
+    sw = Stopwatch().start()
+    time.sleep(0.05)
+
+    elapsed = sw.elapsed()
+
+    assert sw.running
+    assert repr(sw) == "<Stopwatch [running]>"
+    assert timedelta(milliseconds=50) <= elapsed < timedelta(seconds=1)
+    assert sw.elapsed() >= elapsed
+
+
+def test_stop():
+    sw = Stopwatch().start()
+    time.sleep(0.01)
+
+    elapsed = sw.stop()
+    time.sleep(0.01)
+
+    assert not sw.running
+    assert repr(sw) == "<Stopwatch [stopped]>"
+    assert sw.elapsed() == elapsed
+    assert sw.stop() == elapsed
+    assert sw.elapsed_ns() // 1000 == elapsed // timedelta(microseconds=1)
+
+
+def test_restart():
+    sw = Stopwatch().start()
+    time.sleep(0.05)
+    first = sw.stop()
+
+    assert sw.start() is sw
+    assert sw.running
+    assert sw.elapsed() < first
+
+    time.sleep(0.01)
+    assert sw.start().elapsed() < timedelta(milliseconds=10)
+
+
+def test_elapsed_since():
+    first = Stopwatch().start()
+    reading = atomic_clock.monotonic()
+    time.sleep(0.01)
+    second = Stopwatch().start()
+
+    assert timedelta(milliseconds=10) <= second.elapsed_since(first) < timedelta(1)
+    assert second.elapsed_since(reading) <= second.elapsed_since(first)
+    assert first.elapsed_since(second) == -second.elapsed_since(first)
+
+
+def test_elapsed_since_not_started():
+    with pytest.raises(ValueError, match="stopwatch isn't started"):
+        Stopwatch().elapsed_since(Stopwatch().start())
+
+    with pytest.raises(ValueError, match="stopwatch isn't started"):
+        Stopwatch().start().elapsed_since(Stopwatch())
+
+
+@pytest.mark.parametrize("reading", [float("nan"), float("inf"), float("-inf")])
+def test_elapsed_since_not_finite(reading):
+    with pytest.raises(ValueError, match="has to be finite"):
+        Stopwatch().start().elapsed_since(reading)
+
+
+@pytest.mark.parametrize("reading", [1e20, -1e20, 1e40, -1e40])
+def test_elapsed_since_out_of_range(reading):
+    with pytest.raises(OverflowError, match="elapsed time is out of range"):
+        Stopwatch().start().elapsed_since(reading)
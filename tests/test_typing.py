@@ -0,0 +1,144 @@
+import ast
+import enum
+import inspect
+import os
+
+import pytest
+
+import atomic_clock
+
+
+TESTS_DIR = os.path.dirname(os.path.abspath(__file__))
+STUB_PATH = os.path.join(
+    os.path.dirname(TESTS_DIR), "python", "atomic_clock", "__init__.pyi"
+)
+SAMPLE_PATH = os.path.join(TESTS_DIR, "typing_sample.py")
+
+
+def parse_stub():
+    with open(STUB_PATH, encoding="utf-8") as f:
+        tree = ast.parse(f.read())
+
+    def collect(body):
+        names = {}
+        for node in body:
+            if isinstance(node, (ast.FunctionDef, ast.ClassDef)):
+                names.setdefault(node.name, []).append(node)
+            elif isinstance(node, ast.AnnAssign) and isinstance(node.target, ast.Name):
+                names.setdefault(node.target.id, []).append(node)
+            elif isinstance(node, ast.Assign):
+                for target in node.targets:
+                    if isinstance(target, ast.Name):
+                        names.setdefault(target.id, []).append(node)
+        return names
+
+    module = collect(tree.body)
+    classes = {
+        name: collect(nodes[0].body)
+        for name, nodes in module.items()
+        if isinstance(nodes[0], ast.ClassDef)
+    }
+    return module, classes
+
+
+def stub_arities(nodes):
+    """The parameter counts of every overload, ``None`` if any takes ``*args``."""
+    arities = set()
+    for node in nodes:
+        if not isinstance(node, ast.FunctionDef):
+            continue
+        args = node.args
+        if args.vararg or args.kwarg:
+            arities.add(None)
+            continue
+        params = [
+            arg.arg
+            for arg in args.posonlyargs + args.args + args.kwonlyargs
+            if arg.arg not in ("self", "cls")
+        ]
+        arities.add(len(params))
+    return arities
+
+
+def runtime_arity(obj):
+    try:
+        signature = inspect.signature(obj)
+    except (TypeError, ValueError):
+        # only methods with a text signature can be introspected
+        return None
+    return len(
+        [
+            name
+            for name in signature.parameters
+            if name.lstrip("$") not in ("self", "cls")
+        ]
+    )
+
+
+def public_names(cls):
+    for name, value in vars(cls).items():
+        if name.startswith("_"):
+            continue
+        # inherited interfaces like `tzinfo.utcoffset` are covered by the stub's base class
+        if any(name in vars(base) for base in cls.__mro__[1:]):
+            continue
+        yield name, value
+
+
+MODULE_STUB, CLASS_STUBS = parse_stub()
+
+
+@pytest.mark.parametrize("name", atomic_clock.__all__)
+def test_module_names_in_stub(name):
+    assert name in MODULE_STUB, f"`{name}` is missing from the stub"
+
+    obj = getattr(atomic_clock, name)
+    if inspect.isroutine(obj):
+        arity = runtime_arity(obj)
+        arities = stub_arities(MODULE_STUB[name])
+        assert arity is None or None in arities or arity in arities, name
+
+
+@pytest.mark.parametrize(
+    "name",
+    [
+        name
+        for name in atomic_clock.__all__
+        if inspect.isclass(getattr(atomic_clock, name))
+    ],
+)
+def test_class_members_in_stub(name):
+    cls = getattr(atomic_clock, name)
+    stub = CLASS_STUBS[name]
+
+    for member, value in public_names(cls):
+        assert member in stub, f"`{name}.{member}` is missing from the stub"
+
+        if callable(value) and not isinstance(value, enum.Enum):
+            arity = runtime_arity(getattr(cls, member))
+            arities = stub_arities(stub[member])
+            assert arity is None or None in arities or arity in arities, member
+
+
+@pytest.mark.parametrize(
+    "name",
+    [
+        name
+        for name in atomic_clock.__all__
+        if inspect.isclass(getattr(atomic_clock, name))
+    ],
+)
+def test_stub_members_exist(name):
+    cls = getattr(atomic_clock, name)
+
+    for member in CLASS_STUBS[name]:
+        if not member.startswith("_"):
+            assert hasattr(cls, member), f"`{name}.{member}` doesn't exist"
+
+
+def test_mypy_strict():
+    api = pytest.importorskip("mypy.api")
+
+    stdout, stderr, status = api.run(["--strict", SAMPLE_PATH])
+
+    assert status == 0, stdout + stderr
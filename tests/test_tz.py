@@ -0,0 +1,607 @@
+import os
+import subprocess
+import sys
+import textwrap
+import time
+
+from datetime import datetime
+from datetime import timedelta
+from datetime import timezone
+
+import atomic_clock
+import pytest
+
+from atomic_clock import AtomicClock
+from atomic_clock import Tz
+
+
+@pytest.mark.parametrize(
+    "offset,expected",
+    (
+        ("+14:00", timedelta(hours=14)),
+        ("-12:00", timedelta(hours=-12)),
+        ("+23:59", timedelta(hours=23, minutes=59)),
+        ("+0530", timedelta(hours=5, minutes=30)),
+        ("+05", timedelta(hours=5)),
+    ),
+)
+def test_offset(offset, expected):
+    assert AtomicClock(2022, 1, 1, tzinfo=offset).utcoffset() == expected
+
+
+@pytest.mark.parametrize("offset", ("+25:00", "-24:00", "+99:00"))
+def test_offset_out_of_range(offset):
+    with pytest.raises(ValueError, match="timezone offset out of range"):
+        Tz(offset)
+
+    with pytest.raises(ValueError, match="timezone offset out of range"):
+        AtomicClock(2022, 1, 1, tzinfo=offset)
+
+
+@pytest.mark.parametrize("offset", ("+05:60", "+5", "+05:3", "+ab:cd", "+€1"))
+def test_offset_invalid(offset):
+    with pytest.raises(ValueError, match="unknown timezone"):
+        Tz(offset)
+
+
+def test_sort_by_current_offset():
+    zones = [Tz("UTC"), Tz("Asia/Tokyo"), Tz("America/New_York")]
+
+    result = [str(tz) for tz in sorted(zones)]
+
+    assert result == ["America/New_York", "UTC", "Asia/Tokyo"]
+    assert Tz("UTC") < Tz("Asia/Tokyo")
+    assert Tz("Asia/Tokyo") >= Tz("+09:00")
+    assert Tz("Asia/Tokyo") <= Tz("+09:00")
+    assert not Tz("UTC") > Tz("UTC")
+
+
+@pytest.mark.parametrize(
+    "when,expected",
+    (
+        (AtomicClock(2022, 1, 1), -5 * 3600),
+        (AtomicClock(2022, 7, 1), -4 * 3600),
+        (datetime(2022, 7, 1), -4 * 3600),
+    ),
+)
+def test_key_at(when, expected):
+    assert Tz("America/New_York").key_at(when) == expected
+    assert Tz("Asia/Tokyo").key_at(when) == 9 * 3600
+    assert Tz("-03:30").key_at(when) == -(3 * 3600 + 30 * 60)
+
+
+def test_key_at_now():
+    assert Tz("Asia/Tokyo").key_at() == 9 * 3600
+    assert Tz("UTC").key_at() == 0
+
+
+def test_transitions():
+    result = Tz("America/New_York").transitions(2022)
+
+    assert [(t.to("UTC"), before, after) for t, before, after in result] == [
+        (AtomicClock(2022, 3, 13, 7), -5 * 3600, -4 * 3600),
+        (AtomicClock(2022, 11, 6, 6), -4 * 3600, -5 * 3600),
+    ]
+    assert [t.isoformat() for t, _, _ in result] == [
+        "2022-03-13T03:00:00-04:00",
+        "2022-11-06T01:00:00-05:00",
+    ]
+
+
+def test_transitions_years():
+    result = Tz("EST+05EDT,M3.2.0,M11.1.0").transitions(2021, 2023)
+
+    assert [t.date() for t, _, _ in result] == [
+        datetime(2021, 3, 14).date(),
+        datetime(2021, 11, 7).date(),
+        datetime(2022, 3, 13).date(),
+        datetime(2022, 11, 6).date(),
+        datetime(2023, 3, 12).date(),
+        datetime(2023, 11, 5).date(),
+    ]
+
+
+@pytest.mark.parametrize("name", ["Asia/Tokyo", "UTC", "+05:30", "JST-9"])
+def test_transitions_none(name):
+    assert Tz(name).transitions(2022) == []
+
+
+@pytest.mark.parametrize("years", [(0,), (2022, 10000), (2022, 2021)])
+def test_transitions_invalid_years(years):
+    with pytest.raises(ValueError):
+        Tz("America/New_York").transitions(*years)
+
+
+def test_next_prev_transition():
+    new_york = Tz("America/New_York")
+    march = (AtomicClock(2022, 3, 13, 3, tzinfo="America/New_York"), -18000, -14400)
+    november = (AtomicClock(2022, 11, 6, 1, tzinfo="-05:00"), -14400, -18000)
+
+    assert new_york.next_transition(AtomicClock(2022, 6, 1)) == november
+    assert new_york.prev_transition(AtomicClock(2022, 6, 1)) == march
+    # the bounds are exclusive
+    assert new_york.next_transition(march[0]) == november
+    assert new_york.prev_transition(november[0]) == march
+    assert new_york.next_transition(march[0].shift(microseconds=-1)) == march
+    assert new_york.prev_transition(march[0].shift(microseconds=1)) == march
+    assert new_york.next_transition(datetime(2022, 6, 1)) == november
+
+
+def test_next_prev_transition_none():
+    assert Tz("+05:30").next_transition() is None
+    assert Tz("+05:30").prev_transition() is None
+    assert Tz("Asia/Tokyo").next_transition(AtomicClock(2022, 1, 1)) is None
+    assert Tz("Asia/Tokyo").prev_transition(AtomicClock(2022, 1, 1))[1:] == (
+        10 * 3600,
+        9 * 3600,
+    )
+    assert Tz("UTC").next_transition() is None
+    assert Tz("UTC").prev_transition() is None
+    assert Tz("JST-9").next_transition() is None
+    assert Tz("JST-9").prev_transition() is None
+
+
+def test_next_prev_transition_tz_data():
+    lord_howe = Tz("Australia/Lord_Howe")
+
+    # the DST offset of Lord Howe Island is only 30 minutes
+    april = lord_howe.next_transition(AtomicClock(2022, 1, 1))
+    assert april[1:] == (39600, 37800)
+    assert lord_howe.prev_transition(april[0].shift(seconds=1)) == april
+    assert lord_howe.next_transition(AtomicClock(2200, 1, 1)) is None
+
+
+def test_sub():
+    new_york = Tz("America/New_York")
+
+    assert Tz("Asia/Tokyo") - Tz("UTC") == timedelta(hours=9)
+    assert Tz("UTC") - Tz("Asia/Tokyo") == timedelta(hours=-9)
+    assert Tz("UTC") - new_york == timedelta(seconds=-new_york.key_at())
+    assert Tz("Asia/Tokyo") - new_york == timedelta(
+        seconds=9 * 3600 - new_york.key_at()
+    )
+
+
+@pytest.mark.parametrize(
+    "tz,other",
+    (
+        (Tz("UTC"), timezone.utc),
+        (Tz("+08:00"), timezone(timedelta(hours=8))),
+        (Tz("Asia/Shanghai"), timezone(timedelta(hours=8))),
+    ),
+)
+def test_eq_tzinfo(tz, other):
+    assert tz == other
+    assert other == tz
+    assert not tz != other
+    assert tz <= other
+    assert tz >= other
+
+
+def test_eq_zoneinfo():
+    zoneinfo = pytest.importorskip("zoneinfo")
+
+    assert Tz("UTC") == zoneinfo.ZoneInfo("UTC")
+    assert zoneinfo.ZoneInfo("UTC") == Tz("UTC")
+    assert Tz("Asia/Shanghai") == zoneinfo.ZoneInfo("Asia/Shanghai")
+    assert Tz("Asia/Tokyo") != zoneinfo.ZoneInfo("Asia/Shanghai")
+    assert Tz("Asia/Tokyo") > zoneinfo.ZoneInfo("Asia/Shanghai")
+
+
+def test_ne_tzinfo():
+    assert Tz("UTC") != timezone(timedelta(hours=8))
+    assert timezone(timedelta(hours=8)) != Tz("UTC")
+    assert Tz("UTC") < timezone(timedelta(hours=8))
+
+
+def test_utc_tzinfo_is_shared():
+    clock = AtomicClock.utcnow()
+
+    assert clock.tzinfo == timezone.utc
+    assert clock.tzinfo is clock.tzinfo
+    assert clock.tzinfo is AtomicClock(2022, 1, 1).tzinfo
+    assert AtomicClock(2022, 1, 1, tzinfo="+08:00").tzinfo is not clock.tzinfo
+
+
+def test_utc_singleton():
+    assert atomic_clock.utcnow().tzinfo is atomic_clock.UTC
+    assert atomic_clock.get(tzinfo=atomic_clock.UTC).tzinfo is atomic_clock.UTC
+    assert AtomicClock(2022, 1, 1, tzinfo=atomic_clock.UTC).tzinfo is atomic_clock.UTC
+    assert AtomicClock(2022, 1, 1).to(atomic_clock.UTC) == AtomicClock(2022, 1, 1)
+    assert atomic_clock.UTC == Tz("UTC")
+    assert repr(atomic_clock.UTC) == "<Tz [UTC]>"
+    assert repr(atomic_clock.UTC) == repr(atomic_clock.utcnow().tzinfo)
+
+
+def test_local_singleton():
+    clock = atomic_clock.now(atomic_clock.LOCAL)
+
+    assert isinstance(atomic_clock.LOCAL, Tz)
+    assert clock.tzinfo == atomic_clock.LOCAL
+    assert (
+        AtomicClock(2022, 1, 1, tzinfo=atomic_clock.LOCAL).tzinfo == atomic_clock.LOCAL
+    )
+    assert repr(atomic_clock.LOCAL) == repr(atomic_clock.LOCAL)
+
+
+@pytest.mark.skipif(not hasattr(time, "tzset"), reason="requires time.tzset")
+def test_local_follows_tzset():
+    # a fresh interpreter, so nothing captured at import hides behind earlier tests
+    script = textwrap.dedent(
+        """
+        import os
+        import time
+
+        from datetime import timedelta
+
+        import atomic_clock
+
+        assert atomic_clock.now().utcoffset() == timedelta(hours=8)
+        assert atomic_clock.LOCAL.utcoffset(None) == timedelta(hours=8)
+
+        os.environ["TZ"] = "EST+05"
+        time.tzset()
+
+        assert atomic_clock.now().utcoffset() == timedelta(hours=-5)
+        assert atomic_clock.LOCAL.utcoffset(None) == timedelta(hours=-5)
+        assert atomic_clock.now(atomic_clock.LOCAL).utcoffset() == timedelta(hours=-5)
+        """
+    )
+    env = dict(os.environ, TZ="CST-08", PYTHONPATH=os.pathsep.join(sys.path))
+
+    subprocess.run([sys.executable, "-c", script], env=env, check=True)
+
+
+@pytest.mark.skipif(not hasattr(time, "tzset"), reason="requires time.tzset")
+def test_local_constructors_offset_at_instant():
+    # one of the two timestamps is on the other side of DST from now, whenever now is
+    script = textwrap.dedent(
+        """
+        import time
+
+        from datetime import timedelta
+
+        import atomic_clock
+
+        for timestamp, hours in [(1641038400, -5), (1656676800, -4)]:
+            offset = timedelta(hours=hours)
+            expected = atomic_clock.AtomicClock.utcfromtimestamp(timestamp).to("local")
+
+            for clock in [
+                atomic_clock.AtomicClock.fromtimestamp(timestamp),
+                atomic_clock.AtomicClock.fromtimestamp(timestamp, "local"),
+                atomic_clock.AtomicClock.fromtimestamp_ns(timestamp * 10**9),
+                atomic_clock.AtomicClock.fromstructtime(time.localtime(timestamp)),
+            ]:
+                assert clock.utcoffset() == offset
+                assert clock == expected
+                assert clock.isoformat() == expected.isoformat()
+        """
+    )
+    env = dict(
+        os.environ, TZ="EST+05EDT,M3.2.0,M11.1.0", PYTHONPATH=os.pathsep.join(sys.path)
+    )
+
+    subprocess.run([sys.executable, "-c", script], env=env, check=True)
+
+
+@pytest.mark.parametrize(
+    "when, offset, dst",
+    [
+        (datetime(2022, 1, 1), timedelta(hours=-5), timedelta(0)),
+        (datetime(2022, 7, 1), timedelta(hours=-4), timedelta(hours=1)),
+        (datetime(2022, 11, 6, 1, 30), timedelta(hours=-4), timedelta(hours=1)),
+        (datetime(2022, 11, 6, 1, 30, fold=1), timedelta(hours=-5), timedelta(0)),
+        (datetime(2022, 3, 13, 2, 30), timedelta(hours=-5), timedelta(0)),
+        (datetime(2022, 3, 13, 2, 30, fold=1), timedelta(hours=-4), timedelta(hours=1)),
+    ],
+)
+def test_utcoffset_at_wall_time(when, offset, dst):
+    tz = Tz("America/New_York")
+
+    assert tz.utcoffset(when) == offset
+    assert tz.dst(when) == dst
+
+
+@pytest.mark.parametrize("name", ["America/New_York", "EST5EDT,M3.2.0,M11.1.0"])
+def test_dst_per_instant(name):
+    # one instance answers for each datetime, nothing is kept from the first lookup
+    tz = Tz(name)
+    winter = datetime(2022, 1, 1)
+    summer = datetime(2022, 7, 1)
+
+    for when, dst in [(winter, 0), (summer, 1), (winter, 0), (summer, 1)]:
+        assert tz.dst(when) == timedelta(hours=dst)
+        assert tz.utcoffset(when) == timedelta(hours=dst - 5)
+
+
+@pytest.mark.skipif(
+    not hasattr(os, "fork") or not hasattr(time, "tzset"),
+    reason="requires os.fork and time.tzset",
+)
+def test_fork_follows_tzset():
+    # a worker forked after import sees its own local timezone, not the parent's
+    script = textwrap.dedent(
+        """
+        import os
+        import time
+
+        from datetime import datetime
+        from datetime import timedelta
+
+        import atomic_clock
+
+        tz = atomic_clock.Tz("America/New_York")
+        assert atomic_clock.now().utcoffset() == timedelta(hours=8)
+
+        pid = os.fork()
+        if pid == 0:
+            os.environ["TZ"] = "EST+05"
+            time.tzset()
+            ok = (
+                atomic_clock.now().utcoffset() == timedelta(hours=-5)
+                and atomic_clock.LOCAL.utcoffset(None) == timedelta(hours=-5)
+                and tz.dst(datetime(2022, 7, 1)) == timedelta(hours=1)
+            )
+            os._exit(0 if ok else 1)
+
+        _, status = os.waitpid(pid, 0)
+        assert status == 0
+        assert atomic_clock.now().utcoffset() == timedelta(hours=8)
+        """
+    )
+    env = dict(os.environ, TZ="CST-08", PYTHONPATH=os.pathsep.join(sys.path))
+
+    subprocess.run([sys.executable, "-c", script], env=env, check=True)
+
+
+def test_utcoffset_of_clock():
+    winter = AtomicClock(2022, 1, 1, tzinfo="America/New_York")
+    summer = AtomicClock(2022, 7, 1, tzinfo="America/New_York")
+
+    assert winter.utcoffset() == timedelta(hours=-5)
+    assert summer.utcoffset() == timedelta(hours=-4)
+    assert winter.dst() == timedelta(0)
+    assert summer.dst() == timedelta(hours=1)
+    assert summer.datetime.utcoffset() == summer.utcoffset()
+    assert summer.tzinfo.utcoffset(summer.datetime) == summer.utcoffset()
+
+
+def test_invalid_operand():
+    with pytest.raises(TypeError):
+        Tz("UTC") < 1
+
+    with pytest.raises(TypeError):
+        Tz("UTC") - 1
+
+
+EST5EDT = "EST5EDT,M3.2.0,M11.1.0"
+
+
+@pytest.mark.parametrize(
+    "args,expected",
+    (
+        ((2022, 1, 15, 12), timedelta(hours=-5)),
+        ((2022, 7, 15, 12), timedelta(hours=-4)),
+        ((2022, 3, 13, 1, 59, 59), timedelta(hours=-5)),
+        ((2022, 3, 13, 3), timedelta(hours=-4)),
+        ((2022, 11, 6, 0, 59, 59), timedelta(hours=-4)),
+        ((2022, 11, 6, 2), timedelta(hours=-5)),
+    ),
+)
+def test_posix(args, expected):
+    assert AtomicClock(*args, tzinfo=EST5EDT).utcoffset() == expected
+
+
+def test_posix_transitions():
+    before = AtomicClock(2022, 3, 13, 6, 59, 59).to(EST5EDT)
+    after = AtomicClock(2022, 3, 13, 7).to(EST5EDT)
+
+    assert before.isoformat() == "2022-03-13T01:59:59-05:00"
+    assert after.isoformat() == "2022-03-13T03:00:00-04:00"
+    assert AtomicClock(2022, 11, 6, 1, 30, tzinfo=EST5EDT).isoformat() == (
+        "2022-11-06T01:30:00-04:00"
+    )
+    assert AtomicClock(2022, 11, 6, 1, 30, fold=1, tzinfo=EST5EDT).isoformat() == (
+        "2022-11-06T01:30:00-05:00"
+    )
+    assert AtomicClock(2022, 7, 1, tzinfo=EST5EDT).format("ZZZ", tokens=True) == "EDT"
+
+    with pytest.raises(ValueError):
+        AtomicClock(2022, 3, 13, 2, 30, tzinfo=EST5EDT)
+
+
+def test_posix_matches_iana():
+    cet = "CET-1CEST,M3.5.0,M10.5.0/3"
+    start = AtomicClock(2022, 3, 26)
+
+    for hours in range(0, 24 * 230, 7):
+        instant = start.shift(hours=hours)
+        assert instant.to(cet).isoformat() == instant.to("Europe/Paris").isoformat()
+
+
+@pytest.mark.parametrize(
+    "tz,args,expected",
+    (
+        ("UTC0", (2022, 7, 1), timedelta(0)),
+        ("<+08>-8", (2022, 7, 1), timedelta(hours=8)),
+        ("EST5EDT4,J60/1:30,304", (2022, 7, 1), timedelta(hours=-4)),
+        ("AEST-10AEDT,M10.1.0,M4.1.0/3", (2022, 1, 1), timedelta(hours=11)),
+        ("AEST-10AEDT,M10.1.0,M4.1.0/3", (2022, 7, 1), timedelta(hours=10)),
+    ),
+)
+def test_posix_variants(tz, args, expected):
+    assert AtomicClock(*args, tzinfo=tz).utcoffset() == expected
+    assert str(Tz(tz)) == tz
+
+
+def test_posix_now():
+    cet = "CET-1CEST,M3.5.0,M10.5.0/3"
+
+    assert (
+        AtomicClock.now(cet).isoformat()[-6:]
+        == AtomicClock.now("Europe/Paris").isoformat()[-6:]
+    )
+
+
+def test_posix_pickle():
+    import pickle
+
+    clock = AtomicClock(2022, 7, 1, tzinfo=EST5EDT)
+
+    result = pickle.loads(pickle.dumps(clock))
+
+    assert result == clock
+    assert result.tzinfo == Tz(EST5EDT)
+    assert repr(result) == f"<AtomicClock [2022-07-01T00:00:00-04:00 {EST5EDT}]>"
+
+
+@pytest.mark.parametrize(
+    "tz",
+    (
+        "EST5EDT,M3.2.0",
+        "EST5EDT,M13.1.0,M11.1.0",
+        "EST5EDT,M3.6.0,M11.1.0",
+        "EST5EDT,J0,J365",
+        "ES5",
+        "<+0-8",
+        "EST25",
+    ),
+)
+def test_posix_invalid(tz):
+    with pytest.raises(ValueError, match="unknown timezone"):
+        Tz(tz)
+
+
+def test_tzdata_version():
+    news = os.path.join(
+        os.path.dirname(__file__), "..", "vendor", "chrono-tz", "tz", "NEWS"
+    )
+    with open(news) as f:
+        release = next(line for line in f if line.startswith("Release "))
+
+    assert atomic_clock.tzdata_version() == release.split()[1]
+
+
+@pytest.fixture
+def x_test_zone():
+    atomic_clock.register_timezone(
+        "X-Test/Zone",
+        [
+            (datetime(2000, 1, 1, tzinfo=timezone.utc), 3600),
+            (AtomicClock(2022, 3, 1), timedelta(hours=2)),
+            (datetime(2022, 10, 1), 3600),
+        ],
+    )
+    yield Tz("X-Test/Zone")
+    if "X-Test/Zone" in atomic_clock.custom_timezones():
+        atomic_clock.unregister_timezone("X-Test/Zone")
+
+
+@pytest.mark.parametrize(
+    "utc,local",
+    (
+        (datetime(1999, 6, 1), "1999-06-01T01:00:00+01:00"),
+        (datetime(2022, 2, 28, 23, 59, 59), "2022-03-01T00:59:59+01:00"),
+        (datetime(2022, 3, 1), "2022-03-01T02:00:00+02:00"),
+        (datetime(2022, 9, 30, 23, 59, 59), "2022-10-01T01:59:59+02:00"),
+        (datetime(2022, 10, 1), "2022-10-01T01:00:00+01:00"),
+    ),
+)
+def test_register_timezone(x_test_zone, utc, local):
+    ac = AtomicClock.fromdatetime(utc, "UTC").to("X-Test/Zone")
+
+    assert ac.isoformat() == local
+    assert ac.tzinfo == x_test_zone
+    assert ac.to("UTC").naive == utc
+    assert repr(ac).endswith(" X-Test/Zone]>")
+
+
+def test_register_timezone_wall_time(x_test_zone):
+    winter = AtomicClock(2022, 2, 1, 12, tzinfo="X-Test/Zone")
+    summer = AtomicClock(2022, 6, 1, 12, tzinfo="X-Test/Zone")
+
+    assert winter.to("UTC") == AtomicClock(2022, 2, 1, 11)
+    assert summer.to("UTC") == AtomicClock(2022, 6, 1, 10)
+
+    with pytest.raises(ValueError, match="invalid datetime"):
+        AtomicClock(2022, 3, 1, 1, 30, tzinfo="X-Test/Zone")
+
+    earlier = AtomicClock(2022, 10, 1, 1, 30, tzinfo="X-Test/Zone")
+    later = AtomicClock(2022, 10, 1, 1, 30, tzinfo="X-Test/Zone", fold=1)
+
+    assert earlier.utcoffset() == timedelta(hours=2)
+    assert later.utcoffset() == timedelta(hours=1)
+    assert datetime(2022, 6, 1, tzinfo=x_test_zone).utcoffset() == timedelta(hours=2)
+    assert x_test_zone.dst(datetime(2022, 6, 1)) == timedelta(0)
+
+
+def test_register_timezone_transitions(x_test_zone):
+    march = (AtomicClock(2022, 3, 1, 2, tzinfo="X-Test/Zone"), 3600, 7200)
+    october = (AtomicClock(2022, 10, 1, 1, tzinfo="X-Test/Zone", fold=1), 7200, 3600)
+
+    assert x_test_zone.transitions(1990, 2030) == [march, october]
+    assert x_test_zone.next_transition(AtomicClock(1990, 1, 1)) == march
+    assert x_test_zone.prev_transition(AtomicClock(2030, 1, 1)) == october
+    assert x_test_zone.next_transition(october[0]) is None
+
+
+@pytest.mark.parametrize("offset", (-34200, timedelta(hours=-9, minutes=-30)))
+def test_register_timezone_fixed_offset(offset):
+    atomic_clock.register_timezone("X-Test/Fixed", offset)
+    try:
+        ac = AtomicClock(2022, 1, 1, tzinfo="X-Test/Fixed")
+
+        assert ac.isoformat() == "2022-01-01T00:00:00-09:30"
+        assert Tz("X-Test/Fixed").transitions(2022) == []
+    finally:
+        atomic_clock.unregister_timezone("X-Test/Fixed")
+
+
+def test_register_timezone_precedence():
+    atomic_clock.register_timezone("Asia/Tokyo", 10 * 3600)
+    try:
+        ac = AtomicClock(2022, 1, 1, tzinfo="Asia/Tokyo")
+
+        assert ac.utcoffset() == timedelta(hours=10)
+    finally:
+        atomic_clock.unregister_timezone("Asia/Tokyo")
+
+    assert Tz("Asia/Tokyo").utcoffset(datetime(2022, 1, 1)) == timedelta(hours=9)
+
+
+def test_unregister_timezone(x_test_zone):
+    ac = AtomicClock(2022, 6, 1, tzinfo="X-Test/Zone")
+
+    assert atomic_clock.custom_timezones() == ["X-Test/Zone"]
+
+    atomic_clock.unregister_timezone("X-Test/Zone")
+
+    assert atomic_clock.custom_timezones() == []
+    assert ac.shift(months=6).isoformat() == "2022-12-01T00:00:00+01:00"
+    with pytest.raises(atomic_clock.TimezoneError):
+        Tz("X-Test/Zone")
+    with pytest.raises(atomic_clock.TimezoneError, match="no timezone"):
+        atomic_clock.unregister_timezone("X-Test/Zone")
+
+
+@pytest.mark.parametrize(
+    "name,rules,match",
+    (
+        ("UTC", 0, "can't register"),
+        ("local", 0, "can't register"),
+        ("X-Test/Zone", 86400, "out of range"),
+        ("X-Test/Zone", timedelta(microseconds=1), "whole seconds"),
+        ("X-Test/Zone", [], "empty"),
+        (
+            "X-Test/Zone",
+            [(datetime(2001, 1, 1), 0), (datetime(2000, 1, 1), 3600)],
+            "increasing",
+        ),
+    ),
+)
+def test_register_timezone_invalid(name, rules, match):
+    with pytest.raises(ValueError, match=match):
+        atomic_clock.register_timezone(name, rules)
+
+    assert atomic_clock.custom_timezones() == []
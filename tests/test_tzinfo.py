@@ -0,0 +1,131 @@
+import copy
+import pickle
+
+from datetime import datetime
+from datetime import time
+from datetime import timedelta
+from datetime import timezone
+
+import atomic_clock
+import pytest
+
+from atomic_clock import Tz
+
+
+NEW_YORK = Tz("America/New_York")
+TOKYO = Tz("Asia/Tokyo")
+EST5EDT = Tz("EST5EDT,M3.2.0,M11.1.0")
+
+
+@pytest.mark.parametrize("tz", [NEW_YORK, EST5EDT])
+@pytest.mark.parametrize(
+    "wall, utc",
+    [
+        (datetime(2022, 1, 15, 10), datetime(2022, 1, 15, 15)),
+        (datetime(2022, 7, 15, 10), datetime(2022, 7, 15, 14)),
+        (datetime(2022, 11, 6, 1, 30), datetime(2022, 11, 6, 5, 30)),
+        (datetime(2022, 11, 6, 1, 30, fold=1), datetime(2022, 11, 6, 6, 30)),
+    ],
+)
+def test_astimezone(tz, wall, utc):
+    aware = wall.replace(tzinfo=tz)
+    utc = utc.replace(tzinfo=timezone.utc)
+
+    assert aware.astimezone(timezone.utc) == utc
+    assert aware.astimezone(timezone.utc).replace(tzinfo=None) == utc.replace(
+        tzinfo=None
+    )
+    result = utc.astimezone(tz)
+    assert result.replace(tzinfo=None) == wall
+    assert result.fold == wall.fold
+    assert result.tzinfo is tz
+
+
+def test_astimezone_between_zones():
+    aware = datetime(2022, 3, 15, 10, tzinfo=TOKYO)
+
+    result = aware.astimezone(NEW_YORK)
+
+    assert result == aware
+    assert result.replace(tzinfo=None) == datetime(2022, 3, 14, 21)
+    assert result.astimezone(TOKYO).replace(tzinfo=None) == datetime(2022, 3, 15, 10)
+
+
+def test_fromutc_foreign_datetime():
+    with pytest.raises(ValueError, match="is not self"):
+        NEW_YORK.fromutc(datetime(2022, 1, 1, tzinfo=TOKYO))
+
+
+def test_subtraction():
+    new_york = datetime(2022, 7, 15, 10, tzinfo=NEW_YORK)
+    tokyo = datetime(2022, 7, 15, 10, tzinfo=TOKYO)
+
+    assert new_york - tokyo == timedelta(hours=13)
+    assert tokyo - new_york == timedelta(hours=-13)
+    # in winter New York is an hour further from Tokyo
+    assert datetime(2022, 1, 15, 10, tzinfo=NEW_YORK) - datetime(
+        2022, 1, 15, 10, tzinfo=TOKYO
+    ) == timedelta(hours=14)
+
+
+@pytest.mark.parametrize(
+    "tz, wall, expected",
+    [
+        (NEW_YORK, datetime(2022, 1, 15), "-0500 EST"),
+        (NEW_YORK, datetime(2022, 7, 15), "-0400 EDT"),
+        (EST5EDT, datetime(2022, 7, 15), "-0400 EDT"),
+        (TOKYO, datetime(2022, 7, 15), "+0900 JST"),
+        (atomic_clock.UTC, datetime(2022, 7, 15), "+0000 UTC"),
+        (Tz("+05:30"), datetime(2022, 7, 15), "+0530 +05:30"),
+    ],
+)
+def test_strftime(tz, wall, expected):
+    aware = wall.replace(tzinfo=tz)
+
+    assert aware.strftime("%z %Z") == expected
+    assert aware.tzname() == expected.split()[1]
+
+
+def test_tzname_without_datetime():
+    assert NEW_YORK.tzname() == "America/New_York"
+    assert NEW_YORK.tzname(None) == "America/New_York"
+    assert Tz("+05:30").tzname() is None
+    assert time(10, tzinfo=TOKYO).tzname() == "Asia/Tokyo"
+
+
+@pytest.mark.parametrize(
+    "tz", [NEW_YORK, TOKYO, EST5EDT, atomic_clock.UTC, Tz("-03:30")]
+)
+def test_pickle(tz):
+    aware = datetime(2022, 11, 6, 1, 30, fold=1, tzinfo=tz)
+
+    result = pickle.loads(pickle.dumps(aware))
+
+    # an ambiguous wall time never equals one in another tzinfo object, see PEP 495
+    assert result.astimezone(timezone.utc) == aware.astimezone(timezone.utc)
+    assert result.fold == 1
+    assert result.utcoffset() == aware.utcoffset()
+    assert result.tzinfo == tz
+    assert str(result.tzinfo) == str(tz)
+
+
+def test_copy():
+    aware = datetime(2022, 7, 15, 10, tzinfo=NEW_YORK)
+
+    assert copy.deepcopy(aware) == aware
+    assert copy.copy(NEW_YORK) == NEW_YORK
+    assert copy.deepcopy(NEW_YORK).utcoffset(aware.replace(tzinfo=None)) == (
+        timedelta(hours=-4)
+    )
+
+
+def test_clock_datetime_round_trip():
+    clock = atomic_clock.AtomicClock(2022, 11, 6, 1, 30, tzinfo="America/New_York")
+    later = clock.shift(hours=1)
+
+    for value in [clock, later]:
+        result = pickle.loads(pickle.dumps(value.datetime))
+
+        assert result.fold == value.fold
+        assert atomic_clock.AtomicClock.fromdatetime(result) == value
+        assert result.astimezone(timezone.utc) == value.to("UTC")
@@ -0,0 +1,79 @@
+"""Typical usage checked with ``mypy --strict`` by ``test_typing.py``, it isn't run."""
+import datetime as dt
+
+from typing import List
+from typing import Optional
+from typing import Tuple
+
+import atomic_clock
+
+from atomic_clock import AtomicClock
+from atomic_clock import RelativeDelta
+from atomic_clock import Tz
+from atomic_clock import TzLike
+
+
+def in_zone(clock: AtomicClock, tz: TzLike) -> AtomicClock:
+    return clock.to(tz)
+
+
+def days_between(start: AtomicClock, end: AtomicClock) -> List[AtomicClock]:
+    return list(AtomicClock.range("day", start, end))
+
+
+def weeks_between(
+    start: AtomicClock, end: AtomicClock
+) -> List[Tuple[AtomicClock, AtomicClock]]:
+    return [span for span in AtomicClock.span_range("week", start, end)]
+
+
+def parse(value: str) -> Optional[AtomicClock]:
+    result: Optional[AtomicClock] = atomic_clock.try_get(value)
+    return result
+
+
+def main() -> None:
+    now: AtomicClock = atomic_clock.utcnow()
+    local: AtomicClock = atomic_clock.now("Asia/Shanghai")
+    parsed: AtomicClock = atomic_clock.get("2022-03-15T10:30:00+08:00")
+    created: AtomicClock = atomic_clock.get(2022, 3, 15, tzinfo="EST5EDT")
+    from_datetime: AtomicClock = atomic_clock.get(dt.datetime(2022, 3, 15), "UTC")
+
+    shifted: AtomicClock = now.shift(days=1, hours=-2)
+    replaced: AtomicClock = now.replace(day=1, delta=RelativeDelta(months=1))
+    floor: AtomicClock = now.floor("week", week_start=7)
+    start, end = now.span("month")
+    delta: dt.timedelta = end - start
+
+    year: int = now.year
+    offset: Optional[dt.timedelta] = local.utcoffset()
+    native: dt.datetime = parsed.datetime
+    text: str = created.format("YYYY-MM-DD HH:mm ZZ", tokens=True)
+    human: str = shifted.humanize(locale="fr")
+    tz: Tz = from_datetime.tzinfo
+    key: int = tz.key_at(now)
+    zones: List[Tz] = sorted([Tz("UTC"), Tz("Asia/Tokyo")])
+
+    print(
+        in_zone(now, Tz("Europe/Paris")),
+        in_zone(now, dt.timezone.utc),
+        days_between(start, end),
+        weeks_between(start, end),
+        parse("not a date"),
+        replaced,
+        floor,
+        delta,
+        year,
+        offset,
+        native,
+        text,
+        human,
+        key,
+        zones,
+        atomic_clock.locales(),
+        atomic_clock.Weekday.Mon,
+    )
+
+
+if __name__ == "__main__":
+    main()